@@ -1,3 +1,6 @@
+// Must stay the first import: applies GRIMOIRE_DATA_DIR / portable data-dir
+// overrides before any service module resolves app.getPath('userData').
+import './utils/dataDirOverride';
 import { app, BrowserWindow, shell, session, protocol, nativeTheme, screen } from 'electron';
 import { join, resolve } from 'path';
 import { pathToFileURL } from 'url';
@@ -5,6 +8,7 @@ import { electronApp, optimizer, is } from '@electron-toolkit/utils';
 import { SOUL_MODEL_SCHEME, registerSoulModelProtocol } from './services/soulContainerModels';
 import { HERO_POSE_SCHEME, registerHeroPoseProtocol, sweepHeroPoseCache } from './services/heroPoseModels';
 import { FOUNDRY_THUMB_SCHEME, registerFoundryThumbnailProtocol } from './services/foundryCatalog';
+import { IMAGE_CACHE_SCHEME, registerImageCacheProtocol } from './services/imageCache';
 
 // The `grimoire-soul:` and `grimoire-hero:` schemes serve GLBs (soul-container
 // models and posed hero stills) out of the user's library to the renderer's 3D
@@ -24,6 +28,11 @@ protocol.registerSchemesAsPrivileged([
         scheme: FOUNDRY_THUMB_SCHEME,
         privileges: { standard: true, secure: true, supportFetchAPI: true, stream: true },
     },
+    {
+        // Serves disk-cached GameBanana images (category icons).
+        scheme: IMAGE_CACHE_SCHEME,
+        privileges: { standard: true, secure: true, supportFetchAPI: true, stream: true },
+    },
 ]);
 
 // The app is dark-only, so pin Chromium and the OS chrome to dark regardless
@@ -417,6 +426,9 @@ if (!gotTheLock) {
         // Serve Foundry's cached texture/icon thumbnails to the browse grid.
         registerFoundryThumbnailProtocol();
 
+        // Serve disk-cached GameBanana images (category icons).
+        registerImageCacheProtocol();
+
         // Reclaim disk from stale or least-recently-used pose entries; the
         // cache is also swept after each export.
         void sweepHeroPoseCache();
@@ -440,7 +452,8 @@ if (!gotTheLock) {
                             // `grimoire-foundry:` serves Foundry's cached texture
                             // thumbnails, rendered as <img>, so they must be allowed
                             // here or the browse grid is blank under the prod CSP.
-                            "img-src 'self' data: https: blob: grimoire-foundry:; " +
+                            // `grimoire-img:` does the same for cached GameBanana icons.
+                            "img-src 'self' data: https: blob: grimoire-foundry: grimoire-img:; " +
                             // Foundry voice-line auditions are served as `data:audio/mpeg`
                             // URLs into an <audio> element (the clip MP3 is sliced out of
                             // the `.vsnd_c` in the main process), so `data:` must be allowed
@@ -5,17 +5,60 @@ import {
     conflictPairKey,
     modConflictIdentity,
     migrateIgnoredConflictKeysForMods,
+    findModsContainingPath,
+    findShadowedMods,
+    reconcileSharedTextures,
+    rankModsByOverrideWins,
+    markKnownConflict,
+    clearKnownConflict,
     type ModConflict,
 } from '../services/conflicts';
 import { scanMods } from '../services/mods';
+import type { ModOverrideStats, ModPathMatch, ShadowedMod, SharedTextureReport } from '../../../src/types/mod';
 
-// get-conflicts
-ipcMain.handle('get-conflicts', async (): Promise<ModConflict[]> => {
+// get-conflicts (served from the fingerprinted disk cache unless forceRescan)
+ipcMain.handle('get-conflicts', async (_, forceRescan?: boolean): Promise<ModConflict[]> => {
     const deadlockPath = getActiveDeadlockPath();
     if (!deadlockPath) {
         return [];
     }
-    return await detectConflicts(deadlockPath);
+    return await detectConflicts(deadlockPath, forceRescan === true);
+});
+
+// find-mods-containing-path (which installed mod ships a given in-VPK file)
+ipcMain.handle('find-mods-containing-path', async (_, query: string): Promise<ModPathMatch[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        return [];
+    }
+    return findModsContainingPath(deadlockPath, query);
+});
+
+// find-shadowed-mods (enabled mods whose every file an earlier mod overrides)
+ipcMain.handle('find-shadowed-mods', async (): Promise<ShadowedMod[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        return [];
+    }
+    return findShadowedMods(deadlockPath);
+});
+
+// rank-mods-by-override-wins (paths each enabled mod wins vs loses in load order)
+ipcMain.handle('rank-mods-by-override-wins', async (): Promise<ModOverrideStats[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        return [];
+    }
+    return rankModsByOverrideWins(deadlockPath);
+});
+
+// reconcile-shared-textures (dedupe identical texture overlaps, report the rest)
+ipcMain.handle('reconcile-shared-textures', async (): Promise<SharedTextureReport> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return reconcileSharedTextures(deadlockPath);
 });
 
 function sameKeys(a: string[], b: string[]): boolean {
@@ -202,3 +245,22 @@ ipcMain.handle('unignore-conflict-mod', async (_, identity: string): Promise<str
     }
     return next;
 });
+
+// mark-known-conflict — remember that two mods clash so enabling one while
+// the other is enabled warns. Stored in both mods' metadata.
+ipcMain.handle('mark-known-conflict', async (_, modIdA: string, modIdB: string): Promise<void> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    await markKnownConflict(deadlockPath, modIdA, modIdB);
+});
+
+// clear-known-conflict — forget a previously marked pair.
+ipcMain.handle('clear-known-conflict', async (_, modIdA: string, modIdB: string): Promise<void> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    await clearKnownConflict(deadlockPath, modIdA, modIdB);
+});
@@ -1,5 +1,6 @@
 import { ipcMain } from 'electron';
-import { buildReportText } from '../services/diagnostics';
+import { buildReportText, getAppPaths, exportSupportBundle } from '../services/diagnostics';
+import type { AppPaths } from '../../../src/types/electron';
 
 ipcMain.handle(
     'diagnostics:buildReport',
@@ -14,3 +15,19 @@ ipcMain.handle(
         );
     },
 );
+
+// diagnostics:getAppPaths (settings/metadata/profiles/cache/log locations so
+// users can attach the right files to a bug report)
+ipcMain.handle('diagnostics:getAppPaths', (): AppPaths => getAppPaths());
+
+// diagnostics:exportSupportBundle (one sanitized zip of report, logs,
+// settings and metadata; the renderer picks destPath via showSaveDialog)
+ipcMain.handle(
+    'diagnostics:exportSupportBundle',
+    (_, destPath: unknown, description: unknown): Promise<string> => {
+        if (typeof destPath !== 'string' || !destPath) {
+            throw new Error('No destination path for the support bundle');
+        }
+        return exportSupportBundle(destPath, typeof description === 'string' ? description : '');
+    },
+);
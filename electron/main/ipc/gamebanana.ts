@@ -1,26 +1,36 @@
 import { ipcMain } from 'electron';
-import { getActiveDeadlockPath } from '../services/settings';
+import { getActiveDeadlockPath, loadSettings, patchSettings, assertOnline } from '../services/settings';
 import {
     fetchSections,
     fetchCategoryTreeCached,
-    fetchSubmissions,
+    fetchTopCategories,
+    fetchSubmissionsCached,
+    clearBrowseCache,
+    rememberBrowseResult,
+    getLastBrowseResult,
+    fetchFeaturedMods,
+    fetchNewModsSince,
+    normalizeGameBananaSection,
     fetchModDetails,
     fetchModFileList,
     fetchModComments,
     fetchModUpdates,
     fetchSubmitterLinks,
+    fetchSubmitterProfile,
     fetchCollection,
     fetchCollectionItems,
     type GameBananaSection,
     type GameBananaCategoryNode,
     type GameBananaModsResponse,
+    type GameBananaNewModsResponse,
     type GameBananaModDetails,
     type GameBananaModFileList,
     type GameBananaModUpdatesResponse,
+    type GameBananaSubmitterProfile,
     type GameBananaCollection,
     type GameBananaCollectionItemsResponse,
 } from '../services/gamebanana';
-import { downloadMod, getDownloadQueue, getCurrentDownload, removeFromQueue, cancelActiveDownload, resolveSuspiciousFileDecision, resolveMultiVpkPick, type DownloadModArgs } from '../services/download';
+import { downloadMod, retryDownload, getDownloadSize, getDownloadQueue, getCurrentDownload, removeFromQueue, cancelActiveDownload, cancelDownload, resolveSuspiciousFileDecision, resolveMultiVpkPick, type DownloadModArgs } from '../services/download';
 import { getMainWindow } from '../index';
 import type {
     BrowseModsArgs,
@@ -28,15 +38,63 @@ import type {
     GetModCommentsArgs,
     GetModUpdatesArgs,
     GetCategoriesArgs,
+    DownloadModResult,
+    LastBrowseResult,
+    CachedImage,
+    DownloadSizeInfo,
+    DownloadEstimate,
 } from '../../../src/types/electron';
+import { findCachedImage, getCachedImage } from '../services/imageCache';
+import { estimateDownloadTime } from '../services/downloadThroughput';
 import { updateModNsfw } from '../services/modDatabase';
+import { checkModUpdates, type InstalledModUpdate } from '../services/modUpdates';
 
 // browse-mods
 ipcMain.handle(
     'browse-mods',
     async (_, args: BrowseModsArgs): Promise<GameBananaModsResponse> => {
-        const { page, perPage, search, section = 'Mod', categoryId, sort, submitterId } = args;
-        return fetchSubmissions(section, page, perPage, search, categoryId, sort, submitterId);
+        assertOnline();
+        const response = await fetchSubmissionsCached(args);
+        rememberBrowseResult(args, response);
+        return response;
+    }
+);
+
+// clear-browse-cache (Browse's refresh button: drop cached and remembered
+// pages so the next browse-mods goes to GameBanana)
+ipcMain.handle('clear-browse-cache', (): void => clearBrowseCache());
+
+// get-last-browse-result (pages already loaded for a query, so a remounted
+// Browse page restores them instead of re-fetching in a burst)
+ipcMain.handle(
+    'get-last-browse-result',
+    (_, args: Omit<BrowseModsArgs, 'page'>): LastBrowseResult | null => getLastBrowseResult(args)
+);
+
+// get-featured-mods (home screen: recent mods ranked by likes, cached)
+ipcMain.handle(
+    'get-featured-mods',
+    async (_, limit?: number): Promise<GameBananaModsResponse> => {
+        assertOnline();
+        return fetchFeaturedMods(limit);
+    }
+);
+
+// fetch-new-mods-since (the "new since last visit" feed). Without an explicit
+// cutoff the last check's time is used, or a week back on the first run; each
+// call then records its own time as the next cutoff.
+const NEW_MODS_FIRST_RUN_WINDOW_SECONDS = 7 * 24 * 60 * 60;
+ipcMain.handle(
+    'fetch-new-mods-since',
+    async (_, args: { since?: number; section?: string }): Promise<GameBananaNewModsResponse> => {
+        assertOnline();
+        const since =
+            args.since ??
+            loadSettings().newModsLastCheckedAt ??
+            Math.floor(Date.now() / 1000) - NEW_MODS_FIRST_RUN_WINDOW_SECONDS;
+        const result = await fetchNewModsSince(normalizeGameBananaSection(args.section), since);
+        patchSettings({ newModsLastCheckedAt: result.checkedAt });
+        return result;
     }
 );
 
@@ -44,6 +102,7 @@ ipcMain.handle(
 ipcMain.handle(
     'get-mod-details',
     async (_, args: GetModDetailsArgs): Promise<GameBananaModDetails> => {
+        assertOnline();
         const { modId, section = 'Mod', includeSubmitter } = args;
         const details = await fetchModDetails(modId, section, { includeSubmitter });
 
@@ -63,21 +122,52 @@ ipcMain.handle(
 ipcMain.handle(
     'get-mod-file-list',
     async (_, args: GetModDetailsArgs): Promise<GameBananaModFileList> => {
+        assertOnline();
         const { modId, section = 'Mod' } = args;
         return fetchModFileList(modId, section);
     }
 );
 
-// download-mod
-ipcMain.handle('download-mod', async (_, args: DownloadModArgs): Promise<void> => {
+// download-mod (short-circuits to alreadyInstalled unless args.force is set)
+ipcMain.handle('download-mod', async (_, args: DownloadModArgs): Promise<DownloadModResult> => {
+    assertOnline();
     const deadlockPath = getActiveDeadlockPath();
     if (!deadlockPath) {
         throw new Error('No Deadlock path configured');
     }
     const mainWindow = getMainWindow();
-    await downloadMod(deadlockPath, args, mainWindow);
+    const result = await downloadMod(deadlockPath, args, mainWindow);
+    return result.alreadyInstalled ? { alreadyInstalled: result.alreadyInstalled } : {};
 });
 
+// retry-download (re-queue a download that failed this session, by its ids;
+// partial files from the failed attempt are removed first)
+ipcMain.handle(
+    'retry-download',
+    async (_, args: { modId: number; fileId: number }): Promise<DownloadModResult> => {
+        assertOnline();
+        const deadlockPath = getActiveDeadlockPath();
+        if (!deadlockPath) {
+            throw new Error('No Deadlock path configured');
+        }
+        const result = await retryDownload(deadlockPath, args.modId, args.fileId, getMainWindow());
+        return result.alreadyInstalled ? { alreadyInstalled: result.alreadyInstalled } : {};
+    }
+);
+
+// get-download-size (file size and free space, for a confirm step before a
+// large download)
+ipcMain.handle(
+    'get-download-size',
+    async (_, args: { modId: number; fileId: number; section?: string }): Promise<DownloadSizeInfo> => {
+        assertOnline();
+        return getDownloadSize(getActiveDeadlockPath(), args.modId, args.fileId, args.section);
+    }
+);
+
+// estimate-download-time (ETA from the rolling average of past downloads)
+ipcMain.handle('estimate-download-time', (_, fileSize: number): DownloadEstimate => estimateDownloadTime(fileSize));
+
 // get-download-queue
 ipcMain.handle('get-download-queue', () => {
     return getDownloadQueue();
@@ -98,6 +188,11 @@ ipcMain.handle('cancel-active-download', (): boolean => {
     return cancelActiveDownload();
 });
 
+// cancel-download (cancel one download by mod and file id, queued or running)
+ipcMain.handle('cancel-download', (_, modId: number, fileId: number): boolean => {
+    return cancelDownload(modId, fileId);
+});
+
 // one-click-suspicious-response (renderer relays user's modal decision)
 ipcMain.handle(
     'one-click-suspicious-response',
@@ -118,6 +213,7 @@ ipcMain.handle(
 ipcMain.handle(
     'get-mod-comments',
     async (_, args: GetModCommentsArgs) => {
+        assertOnline();
         const { modId, section = 'Mod', page = 1 } = args;
         return fetchModComments(modId, section, page);
     }
@@ -127,23 +223,45 @@ ipcMain.handle(
 ipcMain.handle(
     'get-mod-updates',
     async (_, args: GetModUpdatesArgs): Promise<GameBananaModUpdatesResponse> => {
+        assertOnline();
         const { modId, section = 'Mod', page = 1 } = args;
         return fetchModUpdates(modId, section, page);
     }
 );
 
+// check-mod-updates (installed GameBanana mods edited upstream since download)
+ipcMain.handle('check-mod-updates', async (): Promise<InstalledModUpdate[]> => {
+    assertOnline();
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return checkModUpdates(deadlockPath);
+});
+
 // get-submitter-links — artist social/contact links from their member profile
 ipcMain.handle(
     'get-submitter-links',
     async (_, memberId: number) => {
+        assertOnline();
         return fetchSubmitterLinks(memberId);
     }
 );
 
+// fetch-submitter-profile (author page: profile, links, and their submissions)
+ipcMain.handle(
+    'fetch-submitter-profile',
+    async (_, args: { submitterId: number; section?: string }): Promise<GameBananaSubmitterProfile> => {
+        assertOnline();
+        return fetchSubmitterProfile(args.submitterId, normalizeGameBananaSection(args.section));
+    }
+);
+
 // get-gamebanana-sections
 ipcMain.handle(
     'get-gamebanana-sections',
     async (): Promise<GameBananaSection[]> => {
+        assertOnline();
         return fetchSections();
     }
 );
@@ -152,14 +270,35 @@ ipcMain.handle(
 ipcMain.handle(
     'get-gamebanana-categories',
     async (_, args: GetCategoriesArgs): Promise<GameBananaCategoryNode[]> => {
+        assertOnline();
         return fetchCategoryTreeCached(args.categoryModelName);
     }
 );
 
+// get-category-icon (disk-cached icon, served via grimoire-img: so the
+// sidebar doesn't re-fetch every icon on mount). Icons already on disk are
+// local data, so offline mode only blocks a cache miss.
+ipcMain.handle('get-category-icon', async (_, url: string): Promise<CachedImage> => {
+    const cached = await findCachedImage(url);
+    if (cached) return cached;
+    assertOnline();
+    return getCachedImage(url);
+});
+
+// get-top-categories — flattened tree sorted by item count, for the browse sidebar
+ipcMain.handle(
+    'get-top-categories',
+    async (_, args: GetCategoriesArgs & { limit?: number }): Promise<GameBananaCategoryNode[]> => {
+        assertOnline();
+        return fetchTopCategories(args.categoryModelName, args.limit);
+    }
+);
+
 // get-collection — metadata only
 ipcMain.handle(
     'get-collection',
     async (_, args: { collectionId: number }): Promise<GameBananaCollection> => {
+        assertOnline();
         return fetchCollection(args.collectionId);
     }
 );
@@ -171,6 +310,7 @@ ipcMain.handle(
         _,
         args: { collectionId: number; page?: number }
     ): Promise<GameBananaCollectionItemsResponse> => {
+        assertOnline();
         return fetchCollectionItems(args.collectionId, args.page ?? 1);
     }
 );
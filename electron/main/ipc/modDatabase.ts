@@ -1,6 +1,7 @@
 import { ipcMain } from 'electron';
 import { initDatabase, getModById, getModCount, wipeDatabase, getModsNsfwStatus, updateModNsfw, getModsDownloadCounts, updateModDownloadCount } from '../services/modDatabase';
 import { searchMods, getCategories, getSectionStats, type SearchOptions } from '../services/searchService';
+import { assertOnline, loadSettings } from '../services/settings';
 import { syncAllSections, syncSingleSection, getSyncStatus, needsSync, isSyncInProgress } from '../services/syncService';
 
 // Initialize database on module load
@@ -8,11 +9,13 @@ initDatabase();
 
 // Sync handlers
 ipcMain.handle('sync-all-mods', async () => {
+    assertOnline();
     await syncAllSections();
     return { success: true };
 });
 
 ipcMain.handle('sync-section', async (_, section: string) => {
+    assertOnline();
     await syncSingleSection(section);
     return { success: true };
 });
@@ -30,7 +33,8 @@ ipcMain.handle('get-sync-status', () => {
 });
 
 ipcMain.handle('needs-sync', () => {
-    return needsSync();
+    // Offline mode: the cached catalog is all there is, so never prompt a sync.
+    return loadSettings().offlineMode !== true && needsSync();
 });
 
 ipcMain.handle('is-sync-in-progress', () => {
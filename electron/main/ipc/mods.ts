@@ -7,30 +7,54 @@ import {
     scanMods,
     enableMod,
     disableMod,
+    toggleMod,
     deleteMod,
+    getModFiles,
+    importVpkFolder,
+    normalizeLegacyPakNames,
+    checkAddonLimits,
+    findPakCollisions,
+    fixPakCollisions,
+    quarantineMod,
+    listQuarantinedMods,
+    releaseQuarantinedMod,
+    listLibraryMods,
+    importFromLibrary,
+    moveModToLibrary,
     setModPriority,
+    enableModAt,
+    setModRelativePosition,
     reorderMods,
     swapModPriority,
     setModsEnabledBatch,
+    applyModsBatch,
     allocateEnabledVpkPath,
+    findFreeSlots,
+    resolveModDisplayName,
     type Mod,
 } from '../services/mods';
-import { metaKeyFor } from '../services/deadlock';
-import { getModMetadata, setModMetadata, setModMetadataWithHash, removeModMetadata, pruneOrphanMetadata } from '../services/metadata';
+import { metaKeyFor, wasDisabledFolderRecreated } from '../services/deadlock';
+import { recordMissingDisabledMods, getMissingDisabledMods, dismissMissingDisabledMods } from '../services/missingDisabledMods';
+import { getModMetadata, setModMetadata, setModMetadataMany, setModMetadataWithHash, removeModMetadata, pruneOrphanMetadata, repairOrphanMetadata } from '../services/metadata';
 import { inferHeroFromTitle } from '@grimoire/social-types/heroes';
-import { inferHeroFromVpk, classifyGlobalModFromVpk, GLOBAL_CLASSIFIER_VERSION, parseVpkDirectory, parseVpkDirectoriesAsync } from '../services/vpk';
+import { formatModList, modListRow, type ModListRow } from '../services/modListExport';
+import { inferHeroFromVpk, classifyGlobalModFromVpk, GLOBAL_CLASSIFIER_VERSION, affectedHeroesFromVpk, AFFECTED_HEROES_VERSION, listVpkContents, parseVpkDirectory, parseVpkDirectoriesAsync, getVpkLabel } from '../services/vpk';
 import { classifyAbilitySoundsFromVpk } from '../services/abilitySounds';
-import { migrateIgnoredConflictKeysForMods } from '../services/conflicts';
+import { migrateIgnoredConflictKeysForMods, findEnabledKnownConflicts } from '../services/conflicts';
 import { isLockerManaged } from '../services/lockerVpk';
 import {
     detectUnknownModCacheMatches,
     detectUnknownModFilters,
+    detectUnknownModFiltersBatch,
     emptyCrcMatch,
     inferHeroFromVpkTree,
+    type UnknownModBatchInput,
     type UnknownModCacheMatchInput,
     type UnknownModFilterGuess,
 } from '../services/unknownModDetection';
 import { downloadMod } from '../services/download';
+import { fetchModDetails } from '../services/gamebanana';
+import { getModThumbnail } from '../../../src/types/gamebanana';
 import { extractArchive, isArchive, type ExtractedVpk } from '../services/extract';
 import { mergeMods, unmergeMod, extractMergeSource } from '../services/modMerger';
 import { buildHeroSoundSwapVpk, cleanupHeroSoundSwapBuild } from '../services/foundryCatalog';
@@ -41,7 +65,7 @@ import { exportVpkViaDialog, exportVpkFileName } from '../services/foundryExport
 import { getMainWindow } from '../index';
 import type { ImportCustomModArgs, ImportSoulContainerGlbArgs, PreviewSoulContainerGlbArgs, SoulContainerPreview, ImportSpiritUrnGlbArgs, PreviewSpiritUrnGlbArgs, SpiritUrnPreview } from '../../../src/types/electron';
 import type { VpkExportResult, HeroSoundSwapRequest } from '../../../src/types/foundry';
-import type { AbilitySoundClassification, ApplyUnknownCustomModArgs, ApplyUnknownModMatchArgs, AssociateUnknownModArgs, EditLocalModArgs, GlobalModType, LockerHeroSource, MergeModsArgs, Mod as WireMod, SoulContainerImportInfo, SoundSwapInfo, UrnImportInfo, UnmergeModResult, ExtractMergeSourceResult, UnknownModFileList } from '../../../src/types/mod';
+import type { AbilitySoundClassification, AddonLimitCheck, VpkContents, ApplyUnknownCustomModArgs, ApplyUnknownModMatchArgs, AssociateUnknownModArgs, EditLocalModArgs, GlobalModType, LockerHeroSource, MergeModsArgs, MetadataRepair, LibraryMod, MissingDisabledModsReport, ModFile, ModListExportFormat, QuarantinedMod, VpkFolderImportResult, LegacyPakRename, PakCollision, PakRenumber, ModBatchAction, ModBatchResult, Mod as WireMod, ModNameResolution, SoulContainerImportInfo, SoundSwapInfo, UrnImportInfo, UnmergeModResult, ExtractMergeSourceResult, UnknownModFileList } from '../../../src/types/mod';
 
 const unknownDetectionControllers = new Map<string, AbortController>();
 
@@ -89,6 +113,33 @@ function resolveGlobalType(
     return classified;
 }
 
+function affectedHeroesStamp(mod: Mod): string {
+    return `${AFFECTED_HEROES_VERSION}:${mod.size}:${mod.installedAt}`;
+}
+
+/**
+ * Every hero the mod's VPK touches, for the library's "changes Abrams, Haze"
+ * line. Cached in metadata under a size/mtime stamp, so a scan only re-parses
+ * a VPK that was replaced since it was last summarized. An unreadable VPK is
+ * not cached and shows no summary.
+ */
+function resolveAffectedHeroes(
+    mod: Mod,
+    metadata: ReturnType<typeof getModMetadata>
+): string[] | undefined {
+    const stamp = affectedHeroesStamp(mod);
+    if (metadata?.affectedHeroesStamp === stamp) return metadata.affectedHeroes;
+    let heroes: string[] | null = null;
+    try {
+        heroes = affectedHeroesFromVpk(mod.path);
+    } catch (err) {
+        console.warn(`[enrichMod] Affected-heroes summary failed for ${mod.fileName}:`, err);
+    }
+    if (!heroes) return undefined;
+    setModMetadata(mod.metaKey, { affectedHeroes: heroes, affectedHeroesStamp: stamp });
+    return heroes;
+}
+
 /**
  * File-tree hero tag for UNKNOWN mods. Known mods get their hero from the
  * GameBanana category; unknown skins have no metadata, so we infer the hero
@@ -137,6 +188,7 @@ function enrichMod(mod: Mod): WireMod {
     // downloaded ones. resolveGlobalType persists the result + classifier
     // version so subsequent scans skip the parse.
     const globalType = resolveGlobalType(mod, metadata);
+    const affectedHeroes = resolveAffectedHeroes(mod, metadata);
     if (metadata) {
         let lockerHero = metadata.lockerHero;
         let lockerHeroSource = metadata.lockerHeroSource;
@@ -187,8 +239,8 @@ function enrichMod(mod: Mod): WireMod {
         }
         return {
             ...mod,
-            // Use the stored mod name from GameBanana if available
-            name: metadata.modName || mod.name,
+            // Stored GameBanana/user name when set (see resolveModDisplayName)
+            name: resolveModDisplayName(mod.fileName, metadata.modName).name,
             thumbnailUrl: metadata.thumbnailUrl,
             audioUrl: metadata.audioUrl,
             gameBananaId: metadata.gameBananaId,
@@ -214,18 +266,39 @@ function enrichMod(mod: Mod): WireMod {
             soulImport: metadata.soulImport,
             urnImport: metadata.urnImport,
             ignoreUpdates: metadata.ignoreUpdates,
+            knownConflicts: metadata.knownConflicts,
+            tags: metadata.tags,
+            affectedHeroes,
         };
     }
     // No metadata row (a VPK dropped straight into addons): still file-tree tag
     // the hero so unknown skins get their Locker chip like downloaded mods.
     const { lockerHero, lockerHeroSource } = resolveUnknownLockerHero(mod, metadata, isUnknown, globalType);
-    return { ...mod, isUnknown, globalType: globalType ?? undefined, lockerHero, lockerHeroSource };
+    return { ...mod, isUnknown, globalType: globalType ?? undefined, lockerHero, lockerHeroSource, affectedHeroes };
+}
+
+/**
+ * enrichMod plus the known-conflict warning for a mod that was just enabled.
+ * Warn, never block: the user may be enabling the pair on purpose.
+ */
+async function enrichEnabledMod(deadlockPath: string, mod: Mod, tag: string): Promise<WireMod> {
+    const enriched = enrichMod(mod);
+    if (getModMetadata(mod.metaKey)?.knownConflicts?.length) {
+        const clashes = findEnabledKnownConflicts(mod, await scanMods(deadlockPath));
+        if (clashes.length > 0) {
+            const names = clashes.map((m) => resolveModDisplayName(m.fileName, getModMetadata(m.metaKey)?.modName).name);
+            console.warn(`[${tag}] ${mod.fileName} has known conflicts with enabled mod(s): ${names.join(', ')}`);
+            enriched.enabledKnownConflicts = names;
+        }
+    }
+    return enriched;
 }
 
 /**
  * Will enrichMod crack open this mod's VPK? Mirrors (conservatively
  * over-approximates) the lazy-classification predicates above: globalType not
- * yet classified at the current version, abilitySounds never checked, a Sound
+ * yet classified at the current version, abilitySounds never checked, an
+ * affected-heroes summary missing or stamped for another file, a Sound
  * mod with no hero tag yet (the parse only happens when title inference fails,
  * which we don't pre-compute; a wasted warm parse is harmless), or an unknown
  * mod whose tree hasn't been hero-checked. Every positive persists to
@@ -237,6 +310,7 @@ function needsVpkParseForEnrich(mod: Mod): boolean {
     if (metadata?.globalType === undefined) return true;
     if (metadata.globalType === null && globalTypeStamped < GLOBAL_CLASSIFIER_VERSION) return true;
     if (metadata.abilitySounds === undefined) return true;
+    if (metadata.affectedHeroesStamp !== affectedHeroesStamp(mod)) return true;
     if (!metadata.lockerHero && metadata.sourceSection === 'Sound') return true;
     const isUnknown =
         !metadata.gameBananaId &&
@@ -260,6 +334,31 @@ function migrateIgnoredConflictKeysBeforeRenames(mods: Mod[]): void {
     }
 }
 
+// Filesystem records from the last get-mods scan, keyed by id. Lets a pure
+// metadata edit re-enrich just the touched mods (refresh-mod-metadata) without
+// rescanning every addon folder.
+let lastScannedMods = new Map<string, Mod>();
+
+// Hide Grimoire-managed Locker VPKs (hero cards + ability sounds). They're
+// driven solely through the Locker pickers and are auto-enabled + pinned to
+// the front of the load order (services/lockerVpk.ts), so surfacing them in
+// the Installed list would only let the user disable or reorder them and
+// silently break their applied cosmetics. Every handler that hands mods to the
+// renderer or takes mod ids from it goes through this filter.
+function userVisibleMods(mods: Mod[]): Mod[] {
+    return mods.filter((m) => !isLockerManaged(m.metaKey));
+}
+
+// The visible mod with this id, for single-mod handlers; a Locker-managed id
+// reads as not found, same as in runModsBatch.
+async function findUserVisibleMod(deadlockPath: string, modId: string): Promise<Mod> {
+    const mod = userVisibleMods(await scanMods(deadlockPath)).find((m) => m.id === modId);
+    if (!mod) {
+        throw new Error(`Mod not found: ${modId}`);
+    }
+    return mod;
+}
+
 // get-mods
 ipcMain.handle('get-mods', async (): Promise<Mod[]> => {
     const deadlockPath = getActiveDeadlockPath();
@@ -273,16 +372,18 @@ ipcMain.handle('get-mods', async (): Promise<Mod[]> => {
     // install's name/thumbnail/gameBananaId from the global metadata sidecar.
     const settings = loadSettings();
     if (!settings.devMode) {
+        // Give entries stranded by a half-finished rename a chance to find
+        // their VPK again before the prune deletes them.
+        await repairOrphanMetadata(mods);
+        // Note disabled mods that vanished (a Steam verify deleting .disabled)
+        // while their metadata still says they existed; the prune erases that.
+        recordMissingDisabledMods(mods, wasDisabledFolderRecreated());
         // Prune against ALL scanned files (including managed VPKs) so we don't
         // wipe their metadata before filtering them out of the list below.
         pruneOrphanMetadata(new Set(mods.map((m) => m.metaKey)));
     }
-    // Hide Grimoire-managed Locker VPKs (hero cards + ability sounds). They're
-    // driven solely through the Locker pickers and are auto-enabled + pinned to
-    // the front of the load order (services/lockerVpk.ts), so surfacing them in
-    // the Installed list would only let the user disable or reorder them and
-    // silently break their applied cosmetics.
-    const visible = mods.filter((m) => !isLockerManaged(m.metaKey));
+    const visible = userVisibleMods(mods);
+    lastScannedMods = new Map(visible.map((m) => [m.id, m]));
     // Pre-warm the VPK parse cache across the worker pool for mods whose lazy
     // classifications will parse inside enrichMod below. enrichMod stays sync;
     // its parseVpkDirectoryCached calls hit the warmed cache instead of
@@ -340,14 +441,72 @@ ipcMain.handle('get-mods', async (): Promise<Mod[]> => {
     return enriched;
 });
 
+// refresh-mod-names: re-derive every mod's display name from the current
+// metadata and filenames (metadata name > VPK tree label > filename) without
+// touching files. Mods with no stored name cost one VPK directory parse.
+ipcMain.handle('refresh-mod-names', async (): Promise<ModNameResolution[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        return [];
+    }
+    const mods = userVisibleMods(await scanMods(deadlockPath));
+    return mods.map((mod) => {
+        const modName = getModMetadata(mod.metaKey)?.modName;
+        let vpkLabel: string | null = null;
+        if (!modName?.trim()) {
+            try {
+                vpkLabel = getVpkLabel(mod.path);
+            } catch {
+                // Unreadable VPK: fall back to the filename
+            }
+        }
+        return { modId: mod.id, fileName: mod.fileName, ...resolveModDisplayName(mod.fileName, modName, vpkLabel) };
+    });
+});
+
+// refresh-mod-metadata: re-apply the metadata sidecar to specific mods after a
+// metadata-only edit (name, tags, thumbnail). Served from the last scan; only
+// falls back to a disk scan when an id is unknown or its file has since moved.
+// Ids that no longer exist are omitted from the result.
+ipcMain.handle('refresh-mod-metadata', async (_, modIds: string[]): Promise<Mod[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        return [];
+    }
+    const stale = modIds.some((id) => {
+        const cached = lastScannedMods.get(id);
+        return !cached || !existsSync(cached.path);
+    });
+    if (stale) {
+        const mods = await scanMods(deadlockPath);
+        lastScannedMods = new Map(userVisibleMods(mods).map((m) => [m.id, m]));
+    }
+    return modIds
+        .map((id) => lastScannedMods.get(id))
+        .filter((m): m is Mod => m !== undefined)
+        .map(enrichMod);
+});
+
 // enable-mod
 ipcMain.handle('enable-mod', async (_, modId: string): Promise<Mod> => {
     const deadlockPath = getActiveDeadlockPath();
     if (!deadlockPath) {
         throw new Error('No Deadlock path configured');
     }
+    await findUserVisibleMod(deadlockPath, modId);
     const mod = await enableMod(deadlockPath, modId);
-    return enrichMod(mod);
+    return enrichEnabledMod(deadlockPath, mod, 'enable-mod');
+});
+
+// toggle-mod (flips the state read under the mutation lock, not the renderer's copy)
+ipcMain.handle('toggle-mod', async (_, modId: string): Promise<Mod> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    await findUserVisibleMod(deadlockPath, modId);
+    const mod = await toggleMod(deadlockPath, modId);
+    return mod.enabled ? enrichEnabledMod(deadlockPath, mod, 'toggle-mod') : enrichMod(mod);
 });
 
 // disable-mod
@@ -356,21 +515,184 @@ ipcMain.handle('disable-mod', async (_, modId: string): Promise<Mod> => {
     if (!deadlockPath) {
         throw new Error('No Deadlock path configured');
     }
+    await findUserVisibleMod(deadlockPath, modId);
     const mod = await disableMod(deadlockPath, modId);
     return enrichMod(mod);
 });
 
+async function runModsBatch(action: ModBatchAction, modIds: string[]): Promise<ModBatchResult> {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    // Locker-managed ids are refused up front, the same as a single toggle
+    // from the Installed list could never reach them.
+    const visibleIds = new Set(userVisibleMods(await scanMods(deadlockPath)).map((m) => m.id));
+    const ids = [...new Set(modIds)];
+    const refused = ids.filter((id) => !visibleIds.has(id));
+    const failures = [
+        ...refused.map((modId) => ({ modId, error: `Mod not found: ${modId}` })),
+        ...(await applyModsBatch(deadlockPath, action, ids.filter((id) => visibleIds.has(id)))),
+    ];
+    const mods = userVisibleMods(await scanMods(deadlockPath));
+    return { mods: mods.map(enrichMod), failures };
+}
+
+// enable-mods (bulk enable: one lock and one scan, failures reported per id)
+ipcMain.handle('enable-mods', (_, modIds: string[]): Promise<ModBatchResult> => runModsBatch('enable', modIds));
+
+// disable-mods (bulk disable, same contract as enable-mods)
+ipcMain.handle('disable-mods', (_, modIds: string[]): Promise<ModBatchResult> => runModsBatch('disable', modIds));
+
+// delete-mods (bulk delete, same contract as enable-mods)
+ipcMain.handle('delete-mods', (_, modIds: string[]): Promise<ModBatchResult> => runModsBatch('delete', modIds));
+
+// get-mod-files (the dir VPK plus its numbered data chunks, with sizes)
+ipcMain.handle('get-mod-files', async (_, modId: string): Promise<ModFile[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    await findUserVisibleMod(deadlockPath, modId);
+    return getModFiles(deadlockPath, modId);
+});
+
+// list-vpk-contents (the folder tree inside a mod's VPK, with sizes, so a
+// download can be checked for skin / sound / UI content before enabling it)
+ipcMain.handle('list-vpk-contents', async (_, modId: string): Promise<VpkContents> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    const mod = await findUserVisibleMod(deadlockPath, modId);
+    return listVpkContents(mod.path);
+});
+
+// export-mod-list (CSV or Markdown table for sharing a setup; Locker-built VPKs left out)
+ipcMain.handle('export-mod-list', async (_, format: ModListExportFormat): Promise<string> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    if (format !== 'csv' && format !== 'markdown') {
+        throw new Error(`Unknown mod list format: ${String(format)}`);
+    }
+    const rows: ModListRow[] = [];
+    for (const mod of userVisibleMods(await scanMods(deadlockPath))) {
+        rows.push(modListRow(mod, getModMetadata(mod.metaKey)));
+    }
+    return formatModList(rows, format);
+});
+
+// check-addon-limits (enabled pak count vs. the addonLimit warning threshold)
+ipcMain.handle('check-addon-limits', async (): Promise<AddonLimitCheck> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return checkAddonLimits(deadlockPath);
+});
+
+// find-pak-collisions (enabled mods sharing a pakNN slot in one folder)
+ipcMain.handle('find-pak-collisions', async (): Promise<PakCollision[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        return [];
+    }
+    return findPakCollisions(deadlockPath);
+});
+
+// fix-pak-collisions (first-installed keeps the slot, the rest move to the nearest free one)
+ipcMain.handle('fix-pak-collisions', async (): Promise<PakRenumber[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return fixPakCollisions(deadlockPath);
+});
+
+// normalize-legacy-pak-names (textures-pak21_dir.vpk -> pak21_textures_dir.vpk)
+ipcMain.handle('normalize-legacy-pak-names', async (): Promise<LegacyPakRename[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return normalizeLegacyPakNames(deadlockPath);
+});
+
+// quarantine-mod (set a crash-causing mod aside, apart from disabled mods)
+ipcMain.handle('quarantine-mod', async (_, modId: string, reason: string): Promise<QuarantinedMod> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    await findUserVisibleMod(deadlockPath, modId);
+    return quarantineMod(deadlockPath, modId, typeof reason === 'string' ? reason : '');
+});
+
+// list-quarantined-mods
+ipcMain.handle('list-quarantined-mods', async (): Promise<QuarantinedMod[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        return [];
+    }
+    return listQuarantinedMods(deadlockPath);
+});
+
+// release-quarantined-mod (back to the disabled list, never straight to enabled)
+ipcMain.handle('release-quarantined-mod', async (_, fileName: string): Promise<Mod> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    const mod = await releaseQuarantinedMod(deadlockPath, fileName);
+    return enrichMod(mod);
+});
+
+// get-missing-disabled-mods (disabled mods gone from disk, e.g. after a Steam verify)
+ipcMain.handle('get-missing-disabled-mods', (): MissingDisabledModsReport | null => getMissingDisabledMods());
+
+// dismiss-missing-disabled-mods
+ipcMain.handle('dismiss-missing-disabled-mods', (): void => {
+    dismissMissingDisabledMods();
+});
+
+// list-library-mods (mods in the external library folder, installed or not)
+ipcMain.handle('list-library-mods', async (): Promise<LibraryMod[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        return [];
+    }
+    return listLibraryMods(deadlockPath);
+});
+
+// import-from-library (copy into the game as a disabled mod; the library keeps its copy)
+ipcMain.handle('import-from-library', async (_, fileName: string): Promise<Mod> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    const mod = await importFromLibrary(deadlockPath, fileName);
+    return enrichMod(mod);
+});
+
+// move-mod-to-library (out of the game entirely, kept in the library)
+ipcMain.handle('move-mod-to-library', async (_, modId: string): Promise<LibraryMod> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    await findUserVisibleMod(deadlockPath, modId);
+    return moveModToLibrary(deadlockPath, modId);
+});
+
 // reveal-mod-in-folder
 ipcMain.handle('reveal-mod-in-folder', async (_, modId: string): Promise<void> => {
     const deadlockPath = getActiveDeadlockPath();
     if (!deadlockPath) {
         throw new Error('No Deadlock path configured');
     }
-    const mods = await scanMods(deadlockPath);
-    const mod = mods.find((m) => m.id === modId);
-    if (!mod) {
-        throw new Error(`Mod not found: ${modId}`);
-    }
+    const mod = await findUserVisibleMod(deadlockPath, modId);
     shell.showItemInFolder(mod.path);
 });
 
@@ -380,6 +702,7 @@ ipcMain.handle('delete-mod', async (_, modId: string): Promise<void> => {
     if (!deadlockPath) {
         throw new Error('No Deadlock path configured');
     }
+    await findUserVisibleMod(deadlockPath, modId);
     await deleteMod(deadlockPath, modId);
 });
 
@@ -389,11 +712,7 @@ ipcMain.handle('detect-unknown-mod-filters', async (event, modId: string, reques
     if (!deadlockPath) {
         throw new Error('No Deadlock path configured');
     }
-    const mods = await scanMods(deadlockPath);
-    const mod = mods.find((m) => m.id === modId);
-    if (!mod) {
-        throw new Error(`Mod not found: ${modId}`);
-    }
+    const mod = await findUserVisibleMod(deadlockPath, modId);
     unknownDetectionControllers.get(modId)?.abort();
     const controller = new AbortController();
     unknownDetectionControllers.set(modId, controller);
@@ -456,6 +775,68 @@ ipcMain.handle(
     }
 );
 
+// match-unknown-mods (cache first, then live searches; partial results on failure)
+ipcMain.handle(
+    'match-unknown-mods',
+    async (event, requests: UnknownCacheBulkRequest[]): Promise<Record<string, UnknownModFilterGuess>> => {
+        const deadlockPath = getActiveDeadlockPath();
+        if (!deadlockPath) {
+            throw new Error('No Deadlock path configured');
+        }
+        const mods = await scanMods(deadlockPath);
+        const byId = new Map(mods.map((mod) => [mod.id, mod]));
+        const inputs: UnknownModBatchInput[] = [];
+        const controllers = new Map<string, AbortController>();
+        const results: Record<string, UnknownModFilterGuess> = {};
+
+        for (const request of requests) {
+            const mod = byId.get(request.modId);
+            if (!mod) {
+                results[request.modId] = {
+                    modId: request.modId,
+                    fileName: '',
+                    fileCount: 0,
+                    section: 'Mod',
+                    search: null,
+                    confidence: 'low',
+                    contentHints: [],
+                    reasons: [`Mod not found: ${request.modId}`],
+                    detectedHeroes: [],
+                    samplePaths: [],
+                    crcMatch: emptyCrcMatch('not-found', `Mod not found: ${request.modId}`),
+                };
+                continue;
+            }
+            // Same per-mod controllers as detect-unknown-mod-filters, so the
+            // existing cancel handler stops one entry of the batch.
+            unknownDetectionControllers.get(mod.id)?.abort();
+            const controller = new AbortController();
+            unknownDetectionControllers.set(mod.id, controller);
+            controllers.set(mod.id, controller);
+            inputs.push({
+                modId: mod.id,
+                fileName: mod.fileName,
+                vpkPath: mod.path,
+                requestId: request.requestId,
+                signal: controller.signal,
+            });
+        }
+
+        try {
+            const matched = await detectUnknownModFiltersBatch(inputs, {
+                onProgress: (progress) => event.sender.send('unknown-mod-detection-progress', progress),
+            });
+            return { ...matched, ...results };
+        } finally {
+            for (const [modId, controller] of controllers) {
+                if (unknownDetectionControllers.get(modId) === controller) {
+                    unknownDetectionControllers.delete(modId);
+                }
+            }
+        }
+    }
+);
+
 // cancel-unknown-mod-detection
 ipcMain.handle('cancel-unknown-mod-detection', async (_, modId: string): Promise<void> => {
     const controller = unknownDetectionControllers.get(modId);
@@ -485,11 +866,7 @@ ipcMain.handle(
         unknownDetectionControllers.get(modId)?.abort();
         unknownDetectionControllers.delete(modId);
 
-        const mods = await scanMods(deadlockPath);
-        const target = mods.find((m) => m.id === modId);
-        if (!target) {
-            throw new Error(`Mod not found: ${modId}`);
-        }
+        const target = await findUserVisibleMod(deadlockPath, modId);
 
         const wasEnabled = target.enabled;
         const downloadResult = await downloadMod(deadlockPath, {
@@ -498,6 +875,8 @@ ipcMain.handle(
             fileName: matchFileName,
             modName: match.modName,
             section: match.sourceSection ?? 'Mod',
+            // The replacement must land as fresh VPKs we can find by name.
+            force: true,
         }, getMainWindow());
         const installedFileNames = new Set(downloadResult.installedVpks);
 
@@ -549,11 +928,7 @@ ipcMain.handle(
             throw new Error('A name is required');
         }
 
-        const mods = await scanMods(deadlockPath);
-        const target = mods.find((m) => m.id === modId);
-        if (!target) {
-            throw new Error(`Mod not found: ${modId}`);
-        }
+        const target = await findUserVisibleMod(deadlockPath, modId);
 
         await setModMetadataWithHash(target.metaKey, {
             modName: args.name.trim(),
@@ -573,11 +948,7 @@ ipcMain.handle('list-unknown-mod-files', async (_, modId: string): Promise<Unkno
     if (!deadlockPath) {
         throw new Error('No Deadlock path configured');
     }
-    const mods = await scanMods(deadlockPath);
-    const target = mods.find((m) => m.id === modId);
-    if (!target) {
-        throw new Error(`Mod not found: ${modId}`);
-    }
+    const target = await findUserVisibleMod(deadlockPath, modId);
     const paths = parseVpkDirectory(target.path) ?? [];
     return { paths, fileCount: paths.length };
 });
@@ -597,11 +968,7 @@ ipcMain.handle(
             throw new Error('A GameBanana mod selection is required');
         }
 
-        const mods = await scanMods(deadlockPath);
-        const target = mods.find((m) => m.id === modId);
-        if (!target) {
-            throw new Error(`Mod not found: ${modId}`);
-        }
+        const target = await findUserVisibleMod(deadlockPath, modId);
 
         await setModMetadataWithHash(target.metaKey, {
             modName: args.modName.trim(),
@@ -631,11 +998,7 @@ ipcMain.handle(
             throw new Error('A name is required');
         }
 
-        const all = await scanMods(deadlockPath);
-        const target = all.find((m) => m.id === modId);
-        if (!target) {
-            throw new Error(`Mod not found: ${modId}`);
-        }
+        const target = await findUserVisibleMod(deadlockPath, modId);
         const existing = getModMetadata(target.metaKey) ?? {};
         if (typeof existing.gameBananaId === 'number' && existing.gameBananaId > 0) {
             throw new Error('Only local mods can be renamed');
@@ -651,6 +1014,68 @@ ipcMain.handle(
     }
 );
 
+// repair-metadata - re-attach metadata entries left under a stale filename
+// (matched by install hash) and drop stale GameBanana duplicates. get-mods
+// runs the same repair before pruning; this reports what it changed.
+ipcMain.handle('repair-metadata', async (): Promise<MetadataRepair[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    if (loadSettings().devMode) {
+        return [];
+    }
+    return repairOrphanMetadata(await scanMods(deadlockPath));
+});
+
+// reset-mod-metadata - "reset to source": a GameBanana mod gets its name,
+// category, thumbnail, audio and NSFW flag re-fetched from the live details; a
+// local mod drops them so the filename-derived defaults show again. Either way
+// the user's variant label and manual hero tag are cleared. Ids, file
+// provenance and classifier caches are kept.
+ipcMain.handle('reset-mod-metadata', async (_, modId: string): Promise<Mod> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    const target = await findUserVisibleMod(deadlockPath, modId);
+
+    const existing = getModMetadata(target.metaKey) ?? {};
+    const gameBananaId = existing.gameBananaId;
+    const cleared = {
+        variantLabel: undefined,
+        lockerHero: undefined,
+        lockerHeroSource: undefined,
+    };
+    if (typeof gameBananaId === 'number' && gameBananaId > 0) {
+        const section = existing.sourceSection ?? 'Mod';
+        const details = await fetchModDetails(gameBananaId, section);
+        const lockerHero = section === 'Sound' ? inferHeroFromTitle(details.name) ?? undefined : undefined;
+        setModMetadata(target.metaKey, {
+            ...cleared,
+            modName: details.name,
+            categoryId: details.category?.id,
+            categoryName: details.category?.name,
+            thumbnailUrl: getModThumbnail(details),
+            audioUrl: details.previewMedia?.metadata?.audioUrl,
+            nsfw: details.nsfw,
+            lockerHero,
+            lockerHeroSource: lockerHero ? 'download-title' : undefined,
+        });
+    } else {
+        setModMetadata(target.metaKey, {
+            ...cleared,
+            modName: undefined,
+            categoryId: undefined,
+            categoryName: undefined,
+            thumbnailUrl: undefined,
+            audioUrl: undefined,
+            nsfw: undefined,
+        });
+    }
+    return enrichMod(target);
+});
+
 // set-variant-label - user-facing rename of a single VPK (the "variant"
 // inside a grouped mod). Stored alongside the mod's other metadata so it
 // survives priority renames via migrateModMetadata. An empty string clears
@@ -662,11 +1087,7 @@ ipcMain.handle(
         if (!deadlockPath) {
             throw new Error('No Deadlock path configured');
         }
-        const all = await scanMods(deadlockPath);
-        const target = all.find((m) => m.id === modId);
-        if (!target) {
-            throw new Error(`Mod not found: ${modId}`);
-        }
+        const target = await findUserVisibleMod(deadlockPath, modId);
         const trimmed = label.trim();
         setModMetadata(target.metaKey, {
             variantLabel: trimmed.length > 0 ? trimmed : undefined,
@@ -686,11 +1107,7 @@ ipcMain.handle(
         if (!deadlockPath) {
             throw new Error('No Deadlock path configured');
         }
-        const all = await scanMods(deadlockPath);
-        const target = all.find((m) => m.id === modId);
-        if (!target) {
-            throw new Error(`Mod not found: ${modId}`);
-        }
+        const target = await findUserVisibleMod(deadlockPath, modId);
         const trimmed = heroName?.trim() ?? '';
         setModMetadata(target.metaKey, {
             lockerHero: trimmed.length > 0 ? trimmed : undefined,
@@ -717,11 +1134,7 @@ ipcMain.handle(
         if (!deadlockPath) {
             throw new Error('No Deadlock path configured');
         }
-        const all = await scanMods(deadlockPath);
-        const target = all.find((m) => m.id === modId);
-        if (!target) {
-            throw new Error(`Mod not found: ${modId}`);
-        }
+        const target = await findUserVisibleMod(deadlockPath, modId);
         setModMetadata(target.metaKey, {
             globalType,
             globalTypeClassifierVersion: GLOBAL_CLASSIFIER_VERSION,
@@ -742,11 +1155,7 @@ ipcMain.handle(
         if (!deadlockPath) {
             throw new Error('No Deadlock path configured');
         }
-        const all = await scanMods(deadlockPath);
-        const target = all.find((m) => m.id === modId);
-        if (!target) {
-            throw new Error(`Mod not found: ${modId}`);
-        }
+        const target = await findUserVisibleMod(deadlockPath, modId);
         setModMetadata(target.metaKey, {
             ignoreUpdates: ignore ? true : undefined,
         });
@@ -754,6 +1163,59 @@ ipcMain.handle(
     }
 );
 
+// set-mods-category / add-tag-to-mods / remove-tag-from-mods: bulk library organization. Ids that no
+// longer match a mod are skipped; the result holds the updated mods only.
+async function findModsById(modIds: string[]): Promise<Mod[]> {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    const wanted = new Set(modIds);
+    return userVisibleMods(await scanMods(deadlockPath)).filter((m) => wanted.has(m.id));
+}
+
+ipcMain.handle(
+    'set-mods-category',
+    async (_, modIds: string[], categoryId: number, categoryName: string): Promise<WireMod[]> => {
+        if (!Number.isInteger(categoryId) || categoryId <= 0) {
+            throw new Error(`Invalid category id: ${categoryId}`);
+        }
+        const targets = await findModsById(modIds);
+        setModMetadataMany(targets.map((m) => m.metaKey), () => ({
+            categoryId,
+            categoryName: categoryName.trim() || undefined,
+        }));
+        return targets.map(enrichMod);
+    }
+);
+
+ipcMain.handle('add-tag-to-mods', async (_, modIds: string[], tag: string): Promise<WireMod[]> => {
+    const trimmed = tag.trim();
+    if (!trimmed) {
+        throw new Error('A tag is required');
+    }
+    const targets = await findModsById(modIds);
+    setModMetadataMany(targets.map((m) => m.metaKey), (current) => {
+        const tags = current?.tags ?? [];
+        const exists = tags.some((t) => t.toLowerCase() === trimmed.toLowerCase());
+        return { tags: exists ? tags : [...tags, trimmed] };
+    });
+    return targets.map(enrichMod);
+});
+
+ipcMain.handle('remove-tag-from-mods', async (_, modIds: string[], tag: string): Promise<WireMod[]> => {
+    const trimmed = tag.trim().toLowerCase();
+    if (!trimmed) {
+        throw new Error('A tag is required');
+    }
+    const targets = await findModsById(modIds);
+    setModMetadataMany(targets.map((m) => m.metaKey), (current) => {
+        const tags = (current?.tags ?? []).filter((t) => t.toLowerCase() !== trimmed);
+        return { tags: tags.length > 0 ? tags : undefined };
+    });
+    return targets.map(enrichMod);
+});
+
 // backfill-gamebanana-file-id — heal legacy 1-click installs that were saved
 // before we recovered the file id from the archive URL. The renderer matches
 // a local variant to a GameBanana file row (by sourceFileName/fileName or by
@@ -776,11 +1238,7 @@ ipcMain.handle(
         if (!deadlockPath) {
             throw new Error('No Deadlock path configured');
         }
-        const all = await scanMods(deadlockPath);
-        const target = all.find((m) => m.id === modId);
-        if (!target) {
-            throw new Error(`Mod not found: ${modId}`);
-        }
+        const target = await findUserVisibleMod(deadlockPath, modId);
         const existing = getModMetadata(target.metaKey) ?? {};
         const patch: Record<string, unknown> = { gameBananaFileId: payload.gameBananaFileId };
         if (payload.fileDescription && !existing.fileDescription) {
@@ -806,12 +1264,64 @@ ipcMain.handle(
         if (!deadlockPath) {
             throw new Error('No Deadlock path configured');
         }
-        migrateIgnoredConflictKeysBeforeRenames(await scanMods(deadlockPath));
+        const mods = await scanMods(deadlockPath);
+        if (!userVisibleMods(mods).some((m) => m.id === modId)) {
+            throw new Error(`Mod not found: ${modId}`);
+        }
+        migrateIgnoredConflictKeysBeforeRenames(mods);
         const mod = await setModPriority(deadlockPath, modId, priority);
         return enrichMod(mod);
     }
 );
 
+// enable-mod-at (enable into a chosen base slot, swapping out its occupant)
+ipcMain.handle(
+    'enable-mod-at',
+    async (_, modId: string, priority: number): Promise<Mod> => {
+        const deadlockPath = getActiveDeadlockPath();
+        if (!deadlockPath) {
+            throw new Error('No Deadlock path configured');
+        }
+        const mods = await scanMods(deadlockPath);
+        if (!userVisibleMods(mods).some((m) => m.id === modId)) {
+            throw new Error(`Mod not found: ${modId}`);
+        }
+        migrateIgnoredConflictKeysBeforeRenames(mods);
+        const mod = await enableModAt(deadlockPath, modId, priority);
+        return enrichMod(mod);
+    }
+);
+
+// set-mod-relative-position (slider: 0 = first in load order, 1 = last)
+ipcMain.handle(
+    'set-mod-relative-position',
+    async (_, modId: string, fraction: number): Promise<Mod> => {
+        const deadlockPath = getActiveDeadlockPath();
+        if (!deadlockPath) {
+            throw new Error('No Deadlock path configured');
+        }
+        const mods = await scanMods(deadlockPath);
+        if (!userVisibleMods(mods).some((m) => m.id === modId)) {
+            throw new Error(`Mod not found: ${modId}`);
+        }
+        migrateIgnoredConflictKeysBeforeRenames(mods);
+        const mod = await setModRelativePosition(deadlockPath, modId, fraction);
+        return enrichMod(mod);
+    }
+);
+
+// find-free-slots (free pakNN numbers in a priority range, base addons folder)
+ipcMain.handle(
+    'find-free-slots',
+    async (_, min: number, max: number, count: number): Promise<number[]> => {
+        const deadlockPath = getActiveDeadlockPath();
+        if (!deadlockPath) {
+            throw new Error('No Deadlock path configured');
+        }
+        return findFreeSlots(deadlockPath, min, max, count);
+    }
+);
+
 // reorder-mods
 ipcMain.handle(
     'reorder-mods',
@@ -1019,6 +1529,23 @@ ipcMain.handle(
     }
 );
 
+// import-vpk-folder (bulk-adopt a hand-managed folder of loose VPKs)
+// Each `_dir.vpk` plus its chunks becomes its own enabled mod; the result is
+// per file so the UI can list what failed without aborting the batch.
+ipcMain.handle(
+    'import-vpk-folder',
+    async (_, folderPath: string, move?: boolean): Promise<VpkFolderImportResult[]> => {
+        const deadlockPath = getActiveDeadlockPath();
+        if (!deadlockPath) {
+            throw new Error('No Deadlock path configured');
+        }
+        if (!folderPath || !existsSync(folderPath)) {
+            throw new Error('Folder not found');
+        }
+        return importVpkFolder(deadlockPath, folderPath, !!move);
+    }
+);
+
 // foundry:swapSound
 // Build a hero sound-swap addon VPK (drop your own MP3 onto a hero gameplay
 // sound event) and install it as a tracked local mod, mirroring
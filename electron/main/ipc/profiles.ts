@@ -6,11 +6,16 @@ import {
     createProfileFromGameBananaIds,
     updateProfile,
     applyProfile,
+    validateProfile,
+    diffProfile,
     deleteProfile,
     renameProfile,
+    migrateProfileIdentities,
     type Profile,
     type ProfileCrosshairSettings,
     type ApplyProfileResult,
+    type ProfileValidationResult,
+    type ProfileDiff,
 } from '../services/profiles';
 import {
     buildPortableProfile,
@@ -24,8 +29,17 @@ import type {
     PortableResolvedMod,
 } from '../../../src/types/portableProfile';
 
-// get-profiles
-ipcMain.handle('get-profiles', (): Profile[] => {
+// get-profiles (older profiles get stable mod ids backfilled on first read,
+// like the ignored-conflict key migration)
+ipcMain.handle('get-profiles', async (): Promise<Profile[]> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (deadlockPath) {
+        try {
+            await migrateProfileIdentities(deadlockPath);
+        } catch (err) {
+            console.warn('[Profiles] Failed to backfill stable mod ids:', err);
+        }
+    }
     return loadProfiles();
 });
 
@@ -96,6 +110,24 @@ ipcMain.handle('apply-profile', async (_, profileId: string): Promise<ApplyProfi
     return result;
 });
 
+// validate-profile (dry run of apply-profile; read-only, so no snapshot)
+ipcMain.handle('validate-profile', async (_, profileId: string): Promise<ProfileValidationResult> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return validateProfile(deadlockPath, profileId);
+});
+
+// diff-profile (per-mod action plan for a confirm-before-apply screen; read-only)
+ipcMain.handle('diff-profile', async (_, profileId: string): Promise<ProfileDiff> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return diffProfile(deadlockPath, profileId);
+});
+
 // delete-profile
 ipcMain.handle('delete-profile', (_, profileId: string): void => {
     deleteProfile(profileId);
@@ -1,7 +1,10 @@
 import { ipcMain } from 'electron';
-import { loadSettings, saveSettings, type AppSettings } from '../services/settings';
+import { loadSettings, saveSettings, patchSettings, getActiveDeadlockPath, type AppSettings } from '../services/settings';
 import { detectDeadlockPath, looksLikeDeadlockPath } from '../services/deadlock';
 import { ensureDevDeadlockPath } from '../services/dev';
+import { normalizeExtraExtensions } from '../services/extract';
+import { exportConfigBundle, inspectConfigBundle, importConfigBundle, type ConfigBundleManifest } from '../services/configBundle';
+import { backupAddons, restoreAddons, type AddonsBackupManifest } from '../services/addonsBackup';
 
 // detect-deadlock
 ipcMain.handle('detect-deadlock', (): string | null => {
@@ -29,3 +32,87 @@ ipcMain.handle('get-settings', (): AppSettings => {
 ipcMain.handle('set-settings', (_, settings: AppSettings): void => {
     saveSettings(settings);
 });
+
+// patch-settings: merge only the changed fields in main, so concurrent edits
+// to different fields don't overwrite each other.
+ipcMain.handle('patch-settings', (_, partial: Partial<AppSettings>): AppSettings => {
+    return patchSettings(partial);
+});
+
+// set-load-order-locked: patched in main so a stale renderer settings object
+// can't clobber other fields while toggling the lock.
+ipcMain.handle('set-load-order-locked', (_, locked: boolean): boolean => {
+    return patchSettings({ loadOrderLocked: locked === true }).loadOrderLocked === true;
+});
+
+// set-read-only-mode: patched in main, like set-load-order-locked.
+ipcMain.handle('set-read-only-mode', (_, enabled: boolean): boolean => {
+    return patchSettings({ readOnlyMode: enabled === true }).readOnlyMode === true;
+});
+
+// set-extra-extract-extensions: validated here (no executables, no vpk) so
+// the extractor can trust the stored list.
+ipcMain.handle('set-extra-extract-extensions', (_, extensions: string[]): string[] => {
+    const normalized = normalizeExtraExtensions(Array.isArray(extensions) ? extensions : []);
+    patchSettings({ extraExtractExtensions: normalized });
+    return normalized;
+});
+
+// export-config-bundle (settings, metadata, profiles and snapshots in one zip;
+// the renderer picks destPath via showSaveDialog)
+ipcMain.handle('export-config-bundle', (_, destPath: unknown): Promise<ConfigBundleManifest> => {
+    if (typeof destPath !== 'string' || !destPath) {
+        throw new Error('No destination path for the config bundle');
+    }
+    return exportConfigBundle(destPath);
+});
+
+// inspect-config-bundle (validate a bundle and return its manifest, for the
+// restore confirmation; writes nothing)
+ipcMain.handle('inspect-config-bundle', (_, srcPath: unknown): ConfigBundleManifest => {
+    if (typeof srcPath !== 'string' || !srcPath) {
+        throw new Error('No config bundle selected');
+    }
+    return inspectConfigBundle(srcPath);
+});
+
+// import-config-bundle (overwrites the current config; refuses unless the
+// user confirmed)
+ipcMain.handle(
+    'import-config-bundle',
+    (_, args: { srcPath: string; confirmOverwrite: boolean }): Promise<ConfigBundleManifest> => {
+        if (typeof args?.srcPath !== 'string' || !args.srcPath) {
+            throw new Error('No config bundle selected');
+        }
+        return importConfigBundle(args.srcPath, args.confirmOverwrite === true);
+    }
+);
+
+// backup-addons (every addon folder, .disabled included, plus mod metadata in
+// one zip; the renderer picks destPath via showSaveDialog)
+ipcMain.handle('backup-addons', (_, destPath: unknown): Promise<AddonsBackupManifest> => {
+    if (typeof destPath !== 'string' || !destPath) {
+        throw new Error('No destination path for the addons backup');
+    }
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return backupAddons(deadlockPath, destPath);
+});
+
+// restore-addons (replaces every installed mod; refuses unless the user
+// confirmed, and leaves everything untouched when the backup is invalid)
+ipcMain.handle(
+    'restore-addons',
+    (_, args: { srcPath: string; confirmOverwrite: boolean }): Promise<AddonsBackupManifest> => {
+        if (typeof args?.srcPath !== 'string' || !args.srcPath) {
+            throw new Error('No addons backup selected');
+        }
+        const deadlockPath = getActiveDeadlockPath();
+        if (!deadlockPath) {
+            throw new Error('No Deadlock path configured');
+        }
+        return restoreAddons(deadlockPath, args.srcPath, args.confirmOverwrite === true);
+    }
+);
@@ -4,15 +4,36 @@ import { getActiveDeadlockPath } from '../services/settings';
 import {
     getGameinfoStatus,
     fixGameinfo,
+    previewGameinfoFix,
+    restoreGameinfo,
     cleanupAddons,
     type GameinfoStatus,
     type CleanupResult,
 } from '../services/system';
 import { healLockerVpks } from '../services/lockerVpk';
+import { checkModsEffective, checkGameinfoCoverage } from '../services/mods';
+import { findIncompleteInstalls, cleanupIncompleteInstalls, type IncompleteInstall } from '../services/installJournal';
 import { readFileSync } from 'fs';
 import { fileURLToPath } from 'url';
 import { getAddonsPath, getCitadelPath } from '../services/deadlock';
-import type { OpenDialogOptions, SaveDialogOptions } from '../../../src/types/electron';
+import type { OpenDialogOptions, SaveDialogOptions, ModEffectivenessReport, GameinfoCoverageReport } from '../../../src/types/electron';
+
+/**
+ * fixGameinfo, then move any Locker-managed VPKs out of addons into
+ * citadel/grimoire now that its search path is in place, so applied cards /
+ * sounds relocate without needing an app restart.
+ */
+async function fixGameinfoAndHealLocker(deadlockPath: string): Promise<GameinfoStatus> {
+    const status = fixGameinfo(deadlockPath);
+    if (status.configured) {
+        try {
+            await healLockerVpks(deadlockPath);
+        } catch (err) {
+            console.error('[system] Locker migration after fix-gameinfo failed:', err);
+        }
+    }
+    return status;
+}
 
 async function loadClipboardImage(source: string): Promise<Electron.NativeImage> {
     if (!source) {
@@ -103,6 +124,49 @@ ipcMain.handle('cleanup-addons', (): CleanupResult => {
     return cleanupAddons(deadlockPath);
 });
 
+// find-incomplete-installs (VPKs from an install interrupted by a crash/suspend)
+ipcMain.handle('find-incomplete-installs', (): IncompleteInstall[] => {
+    return findIncompleteInstalls();
+});
+
+// cleanup-incomplete-installs
+ipcMain.handle('cleanup-incomplete-installs', async (_, paths: string[]): Promise<number> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return cleanupIncompleteInstalls(deadlockPath, paths);
+});
+
+// check-mods-effective ("why isn't my mod showing up in game")
+ipcMain.handle('check-mods-effective', async (): Promise<ModEffectivenessReport> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return checkModsEffective(deadlockPath);
+});
+
+// get-gameinfo-coverage (gameinfo status plus the enabled mods it leaves
+// unloaded; checked on startup so patch-day resets name the affected mods)
+ipcMain.handle('get-gameinfo-coverage', async (): Promise<GameinfoCoverageReport> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return checkGameinfoCoverage(deadlockPath);
+});
+
+// fix-gameinfo-coverage (fix-gameinfo, then re-check which mods still won't load)
+ipcMain.handle('fix-gameinfo-coverage', async (): Promise<GameinfoCoverageReport> => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    await fixGameinfoAndHealLocker(deadlockPath);
+    return checkGameinfoCoverage(deadlockPath);
+});
+
 // get-gameinfo-status
 ipcMain.handle('get-gameinfo-status', (): GameinfoStatus => {
     const deadlockPath = getActiveDeadlockPath();
@@ -145,6 +209,24 @@ ipcMain.handle('get-always-on-top', (): boolean => {
     return win ? win.isAlwaysOnTop() : false;
 });
 
+// preview-gameinfo-fix (unified diff of what fix-gameinfo would write)
+ipcMain.handle('preview-gameinfo-fix', (): string => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return previewGameinfoFix(deadlockPath);
+});
+
+// restore-gameinfo (put the newest pre-fix backup of gameinfo.gi back)
+ipcMain.handle('restore-gameinfo', (): GameinfoStatus => {
+    const deadlockPath = getActiveDeadlockPath();
+    if (!deadlockPath) {
+        throw new Error('No Deadlock path configured');
+    }
+    return restoreGameinfo(deadlockPath);
+});
+
 // fix-gameinfo
 ipcMain.handle('fix-gameinfo', async (): Promise<GameinfoStatus> => {
     const deadlockPath = getActiveDeadlockPath();
@@ -156,16 +238,5 @@ ipcMain.handle('fix-gameinfo', async (): Promise<GameinfoStatus> => {
             candidates: [],
         };
     }
-    const status = fixGameinfo(deadlockPath);
-    // Now that the grimoire search path is in place, migrate any Locker-managed
-    // VPKs out of addons into citadel/grimoire immediately, so applied cards /
-    // sounds relocate without needing an app restart.
-    if (status.configured) {
-        try {
-            await healLockerVpks(deadlockPath);
-        } catch (err) {
-            console.error('[system] Locker migration after fix-gameinfo failed:', err);
-        }
-    }
-    return status;
+    return fixGameinfoAndHealLocker(deadlockPath);
 });
@@ -0,0 +1,218 @@
+import { existsSync, readFileSync, readdirSync } from 'fs';
+import { promises as fs } from 'fs';
+import { basename, extname, join } from 'path';
+import { tmpdir } from 'os';
+import { app } from 'electron';
+import { getAddonFolderPaths, getCitadelPath } from './deadlock';
+import { loadMetadata, saveMetadata, type ModMetadataMap } from './metadata';
+import { run7z } from './extract';
+import { runExclusiveModMutation } from './mods';
+import { isDeadlockRunning } from './launch';
+// AddonsBackupManifest is single-sourced in src/types/electron.ts; re-exported
+// for the IPC layer.
+import type { AddonsBackupManifest } from '../../../src/types/electron';
+export type { AddonsBackupManifest };
+
+/**
+ * Addons backup: one zip of every addon folder (citadel/addons with its
+ * .disabled and .quarantine, plus overflow addons1..N) and this install's mod
+ * metadata, taken before a game or app update so the whole working setup can
+ * be put back. The config bundle covers settings and profiles; this covers
+ * the VPKs. 7z does the zipping because a mod library easily runs to several
+ * GB, which adm-zip would have to hold in memory.
+ */
+
+const BACKUP_FORMAT = 'grimoire-addons-backup';
+const BACKUP_VERSION = 1;
+const MANIFEST_NAME = 'grimoire-backup.json';
+const METADATA_NAME = 'mod-metadata.json';
+
+// The base folder and the overflow folders; nothing else may come out of a backup.
+const ADDON_FOLDER_NAME = /^addons\d*$/i;
+
+// Zipping or unpacking a large library on a slow disk takes well past
+// runCommand's five-minute default.
+const BACKUP_TIMEOUT_MS = 60 * 60 * 1000;
+
+/**
+ * Write a backup of every addon folder plus mod metadata to `destPath` (a .zip
+ * the renderer picked with a save dialog). Holds the mod mutation lock so no
+ * enable, disable or download moves files while they are being read, and
+ * writes to a temp name first so a failed backup never replaces a good one.
+ */
+export async function backupAddons(deadlockPath: string, destPath: string): Promise<AddonsBackupManifest> {
+    if (extname(destPath).toLowerCase() !== '.zip') {
+        throw new Error('Addons backup must be saved as a .zip file');
+    }
+    return runExclusiveModMutation(async () => {
+        const folders = getAddonFolderPaths(deadlockPath);
+        const metadata = loadMetadata();
+        const manifest: AddonsBackupManifest = {
+            format: BACKUP_FORMAT,
+            version: BACKUP_VERSION,
+            createdAt: new Date().toISOString(),
+            appVersion: app.getVersion(),
+            folders: folders.map((folder) => basename(folder)),
+            metadataEntries: Object.keys(metadata).length,
+        };
+
+        const workDir = await fs.mkdtemp(join(tmpdir(), 'grimoire-backup-'));
+        const partialPath = `${destPath}.partial.zip`;
+        try {
+            const manifestPath = join(workDir, MANIFEST_NAME);
+            const metadataPath = join(workDir, METADATA_NAME);
+            await fs.writeFile(manifestPath, JSON.stringify(manifest, null, 2), 'utf-8');
+            await fs.writeFile(metadataPath, JSON.stringify(metadata, null, 2), 'utf-8');
+            // `a` appends to an existing archive, so never point it at a stale file.
+            await fs.rm(partialPath, { force: true });
+            // -mx=0: VPKs barely compress, and storing keeps a multi-GB backup fast.
+            await run7z(['a', '-tzip', '-mx=0', '-y', partialPath, manifestPath, metadataPath, ...folders], BACKUP_TIMEOUT_MS);
+            await fs.rename(partialPath, destPath);
+        } catch (err) {
+            await fs.rm(partialPath, { force: true }).catch(() => { });
+            throw err;
+        } finally {
+            await fs.rm(workDir, { recursive: true, force: true }).catch(() => { });
+        }
+        console.log(`[addonsBackup] Backed up ${manifest.folders.join(', ')} to ${destPath}`);
+        return manifest;
+    });
+}
+
+/**
+ * Check an unpacked backup before anything live is touched: the manifest must
+ * be ours and no newer than this build, the metadata must parse, and every
+ * top-level entry must be the manifest, the metadata or a listed addon folder.
+ */
+function readStagedBackup(stageDir: string): { manifest: AddonsBackupManifest; metadata: ModMetadataMap } {
+    const manifestPath = join(stageDir, MANIFEST_NAME);
+    if (!existsSync(manifestPath)) {
+        throw new Error(`Not a Grimoire addons backup (no ${MANIFEST_NAME})`);
+    }
+    let manifest: AddonsBackupManifest;
+    try {
+        manifest = JSON.parse(readFileSync(manifestPath, 'utf-8')) as AddonsBackupManifest;
+    } catch {
+        throw new Error('Addons backup manifest is not valid JSON');
+    }
+    if (manifest?.format !== BACKUP_FORMAT || !Array.isArray(manifest.folders)) {
+        throw new Error('Not a Grimoire addons backup');
+    }
+    if (typeof manifest.version !== 'number' || manifest.version > BACKUP_VERSION) {
+        throw new Error(
+            `This addons backup was made by a newer Grimoire (format v${manifest.version}); update before restoring it`
+        );
+    }
+
+    const metadataPath = join(stageDir, METADATA_NAME);
+    if (!existsSync(metadataPath)) {
+        throw new Error(`Addons backup is missing ${METADATA_NAME}`);
+    }
+    let metadata: ModMetadataMap;
+    try {
+        metadata = JSON.parse(readFileSync(metadataPath, 'utf-8')) as ModMetadataMap;
+    } catch {
+        throw new Error(`${METADATA_NAME} in the addons backup is not valid JSON`);
+    }
+    if (!metadata || typeof metadata !== 'object' || Array.isArray(metadata)) {
+        throw new Error(`${METADATA_NAME} in the addons backup is not a metadata map`);
+    }
+
+    if (!manifest.folders.some((name) => name.toLowerCase() === 'addons')) {
+        throw new Error('Addons backup does not contain the addons folder');
+    }
+    for (const name of manifest.folders) {
+        if (typeof name !== 'string' || !ADDON_FOLDER_NAME.test(name)) {
+            throw new Error(`Addons backup lists an unexpected folder: ${String(name)}`);
+        }
+        if (!existsSync(join(stageDir, name))) {
+            throw new Error(`Addons backup is missing ${name}`);
+        }
+    }
+    const allowed = new Set([MANIFEST_NAME, METADATA_NAME, ...manifest.folders]);
+    for (const entry of readdirSync(stageDir)) {
+        if (!allowed.has(entry)) {
+            throw new Error(`Addons backup contains an unexpected entry: ${entry}`);
+        }
+    }
+    return { manifest, metadata };
+}
+
+/**
+ * Replace every addon folder and this install's mod metadata with a backup's
+ * copies. The caller must pass `confirmOverwrite` once the user has agreed.
+ *
+ * The backup is unpacked into a staging folder next to the addon folders and
+ * validated in full first, so a bad archive changes nothing. The live folders
+ * are then moved aside and the staged ones renamed into place; both are
+ * same-volume renames, and a failure part way renames everything back. The
+ * moved-aside copies are only deleted once the new folders are in.
+ */
+export async function restoreAddons(
+    deadlockPath: string,
+    srcPath: string,
+    confirmOverwrite: boolean
+): Promise<AddonsBackupManifest> {
+    if (!existsSync(srcPath)) {
+        throw new Error(`Addons backup not found: ${srcPath}`);
+    }
+    if (await isDeadlockRunning()) {
+        throw new Error('Close Deadlock before restoring an addons backup');
+    }
+    return runExclusiveModMutation(async () => {
+        const citadelPath = getCitadelPath(deadlockPath);
+        const stamp = Date.now();
+        const stageDir = join(citadelPath, `.grimoire-restore-${stamp}`);
+        const asideDir = join(citadelPath, `.grimoire-pre-restore-${stamp}`);
+
+        let manifest: AddonsBackupManifest;
+        let metadata: ModMetadataMap;
+        try {
+            await run7z(['x', '-y', `-o${stageDir}`, srcPath], BACKUP_TIMEOUT_MS);
+            ({ manifest, metadata } = readStagedBackup(stageDir));
+            if (!confirmOverwrite) {
+                throw new Error('Restoring an addons backup replaces every installed mod; confirm first');
+            }
+        } catch (err) {
+            await fs.rm(stageDir, { recursive: true, force: true }).catch(() => { });
+            throw err;
+        }
+
+        const current = getAddonFolderPaths(deadlockPath).map((folder) => basename(folder));
+        const movedAside: string[] = [];
+        const placed: string[] = [];
+        try {
+            await fs.mkdir(asideDir, { recursive: true });
+            for (const name of current) {
+                await fs.rename(join(citadelPath, name), join(asideDir, name));
+                movedAside.push(name);
+            }
+            for (const name of manifest.folders) {
+                await fs.rename(join(stageDir, name), join(citadelPath, name));
+                placed.push(name);
+            }
+        } catch (err) {
+            for (const name of placed.reverse()) {
+                await fs.rename(join(citadelPath, name), join(stageDir, name)).catch(() => { });
+            }
+            for (const name of movedAside.reverse()) {
+                await fs.rename(join(asideDir, name), join(citadelPath, name)).catch((rollbackErr) =>
+                    console.error(`[addonsBackup] Failed to put ${name} back from ${asideDir}:`, rollbackErr)
+                );
+            }
+            await fs.rm(stageDir, { recursive: true, force: true }).catch(() => { });
+            await fs.rmdir(asideDir).catch(() => { });
+            throw new Error(
+                `Restore failed and your previous addons were put back: ${err instanceof Error ? err.message : String(err)}`
+            );
+        }
+
+        saveMetadata(metadata);
+        await fs.rm(asideDir, { recursive: true, force: true }).catch((err) =>
+            console.warn(`[addonsBackup] Failed to remove the pre-restore copy at ${asideDir}:`, err)
+        );
+        await fs.rm(stageDir, { recursive: true, force: true }).catch(() => { });
+        console.log(`[addonsBackup] Restored ${manifest.folders.join(', ')} from ${srcPath}`);
+        return manifest;
+    });
+}
@@ -1,5 +1,6 @@
 import * as fs from 'fs';
 import * as path from 'path';
+import { assertNotReadOnly } from './settings';
 
 // Section markers for autoexec
 export const CROSSHAIR_START = '// === CROSSHAIR SETTINGS (Mod Manager) ===';
@@ -147,6 +148,7 @@ export function readAutoexec(gamePath: string): AutoexecData {
  * Uses write-to-temp-then-rename pattern to prevent corruption on crash
  */
 export function writeAutoexec(gamePath: string, data: AutoexecData): void {
+    assertNotReadOnly();
     const autoexecPath = getAutoexecPath(gamePath);
     const tempPath = `${autoexecPath}.tmp`;
     const cfgDir = path.dirname(autoexecPath);
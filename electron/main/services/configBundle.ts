@@ -0,0 +1,202 @@
+import { existsSync, readFileSync, readdirSync } from 'fs';
+import { promises as fs } from 'fs';
+import { extname, join } from 'path';
+import { app } from 'electron';
+import AdmZip from 'adm-zip';
+import { getUserDataPath } from '../utils/paths';
+import { writeFileAtomicSync } from '../utils/atomicWrite';
+import { invalidateMetadataCache } from './metadata';
+// ConfigBundleManifest is single-sourced in src/types/electron.ts; re-exported
+// for the IPC layer.
+import type { ConfigBundleManifest } from '../../../src/types/electron';
+export type { ConfigBundleManifest };
+
+/**
+ * Config bundle: one zip of everything Grimoire tracks about a setup (settings,
+ * per-install mod metadata, profiles, snapshots), for backing up before a
+ * risky change or carrying a setup across an OS reinstall. No VPKs. Entries
+ * keep their userData-relative paths so a restore writes them straight back.
+ */
+
+const BUNDLE_FORMAT = 'grimoire-config-bundle';
+const BUNDLE_VERSION = 1;
+const MANIFEST_NAME = 'manifest.json';
+
+// Written next to the live files before a restore overwrites them.
+const PRE_RESTORE_BACKUP = 'config-before-restore.zip';
+
+// The only entries a bundle may carry. Restore refuses anything else, so a
+// crafted zip can't write outside these files (or outside userData at all).
+const BUNDLE_ENTRY_PATTERNS: RegExp[] = [
+    /^settings\.json$/,
+    /^profiles\.json$/,
+    /^mod-metadata\.json$/,
+    /^mod-metadata\/[a-f0-9]{16}\.json$/,
+    /^snapshots\/[A-Za-z0-9_.-]+\.json$/,
+];
+
+function isBundleEntry(name: string): boolean {
+    return BUNDLE_ENTRY_PATTERNS.some((pattern) => pattern.test(name));
+}
+
+/** userData-relative names of the config files present right now. */
+function listConfigFiles(): string[] {
+    const root = getUserDataPath();
+    const names = ['settings.json', 'profiles.json', 'mod-metadata.json'].filter((name) =>
+        existsSync(join(root, name))
+    );
+    for (const folder of ['mod-metadata', 'snapshots']) {
+        const dir = join(root, folder);
+        if (!existsSync(dir)) continue;
+        for (const file of readdirSync(dir)) {
+            if (extname(file).toLowerCase() === '.json') names.push(`${folder}/${file}`);
+        }
+    }
+    return names.filter(isBundleEntry);
+}
+
+function bundleManifest(files: string[]): ConfigBundleManifest {
+    return {
+        format: BUNDLE_FORMAT,
+        version: BUNDLE_VERSION,
+        createdAt: new Date().toISOString(),
+        appVersion: app.getVersion(),
+        files,
+    };
+}
+
+/**
+ * Write the config bundle to `destPath` (a .zip the renderer picked with a
+ * save dialog). Every file is read and parsed first, so a corrupt store fails
+ * the export instead of landing in a backup that can't be restored.
+ */
+export async function exportConfigBundle(destPath: string): Promise<ConfigBundleManifest> {
+    if (extname(destPath).toLowerCase() !== '.zip') {
+        throw new Error('Config bundle must be saved as a .zip file');
+    }
+    const root = getUserDataPath();
+    const zip = new AdmZip();
+    const files = listConfigFiles();
+    for (const name of files) {
+        const text = readFileSync(join(root, name), 'utf-8');
+        try {
+            JSON.parse(text);
+        } catch {
+            throw new Error(`${name} is not valid JSON; fix or remove it before backing up`);
+        }
+        zip.addFile(name, Buffer.from(text, 'utf-8'));
+    }
+    const manifest = bundleManifest(files);
+    zip.addFile(MANIFEST_NAME, Buffer.from(JSON.stringify(manifest, null, 2), 'utf-8'));
+    await fs.writeFile(destPath, zip.toBuffer());
+    return manifest;
+}
+
+/**
+ * Open and validate a bundle without touching anything: the manifest must be
+ * ours and no newer than this build understands, every listed file must be
+ * present, allowed and valid JSON. Returns the manifest plus the file texts.
+ */
+function readConfigBundle(srcPath: string): { manifest: ConfigBundleManifest; contents: Map<string, string> } {
+    let zip: AdmZip;
+    try {
+        zip = new AdmZip(srcPath);
+    } catch (err) {
+        throw new Error(`Not a readable config bundle: ${err instanceof Error ? err.message : String(err)}`);
+    }
+    const manifestEntry = zip.getEntry(MANIFEST_NAME);
+    if (!manifestEntry) {
+        throw new Error('Not a Grimoire config bundle (no manifest.json)');
+    }
+    let manifest: ConfigBundleManifest;
+    try {
+        manifest = JSON.parse(manifestEntry.getData().toString('utf-8')) as ConfigBundleManifest;
+    } catch {
+        throw new Error('Config bundle manifest is not valid JSON');
+    }
+    if (manifest?.format !== BUNDLE_FORMAT || !Array.isArray(manifest.files)) {
+        throw new Error('Not a Grimoire config bundle');
+    }
+    if (typeof manifest.version !== 'number' || manifest.version > BUNDLE_VERSION) {
+        throw new Error(
+            `This config bundle was made by a newer Grimoire (format v${manifest.version}); update before restoring it`
+        );
+    }
+
+    const contents = new Map<string, string>();
+    for (const name of manifest.files) {
+        if (typeof name !== 'string' || !isBundleEntry(name)) {
+            throw new Error(`Config bundle lists an unexpected file: ${String(name)}`);
+        }
+        const entry = zip.getEntry(name);
+        if (!entry) {
+            throw new Error(`Config bundle is missing ${name}`);
+        }
+        const text = entry.getData().toString('utf-8');
+        try {
+            JSON.parse(text);
+        } catch {
+            throw new Error(`${name} in the config bundle is not valid JSON`);
+        }
+        contents.set(name, text);
+    }
+    return { manifest, contents };
+}
+
+/**
+ * Zip the current config files byte for byte, without the JSON check
+ * exportConfigBundle does: a restore is most needed exactly when one of them
+ * is corrupt, so this copy must not refuse it.
+ */
+function backupRawConfigFiles(destPath: string): void {
+    const root = getUserDataPath();
+    const zip = new AdmZip();
+    const files = listConfigFiles();
+    for (const name of files) {
+        zip.addFile(name, readFileSync(join(root, name)));
+    }
+    zip.addFile(MANIFEST_NAME, Buffer.from(JSON.stringify(bundleManifest(files), null, 2), 'utf-8'));
+    writeFileAtomicSync(destPath, zip.toBuffer());
+}
+
+/** Validate a bundle and describe it, for the restore confirmation. */
+export function inspectConfigBundle(srcPath: string): ConfigBundleManifest {
+    return readConfigBundle(srcPath).manifest;
+}
+
+/**
+ * Restore a bundle over the current config. Overwrites settings, metadata,
+ * profiles and snapshots with the bundle's copies, so the caller must pass
+ * `confirmOverwrite` once the user has agreed. The bundle is validated in
+ * full before the first write, and the current config (as-is, even if
+ * corrupt) is saved to userData/config-before-restore.zip first so the restore
+ * can be undone. Files the bundle doesn't carry are left alone. Settings and
+ * profiles are read from disk on every access; the metadata cache is dropped
+ * so the restored files are what the app serves and saves from here on.
+ */
+export async function importConfigBundle(srcPath: string, confirmOverwrite: boolean): Promise<ConfigBundleManifest> {
+    const { manifest, contents } = readConfigBundle(srcPath);
+    if (!confirmOverwrite) {
+        throw new Error('Restoring a config bundle overwrites your current settings, metadata and profiles; confirm first');
+    }
+
+    const root = getUserDataPath();
+    backupRawConfigFiles(join(root, PRE_RESTORE_BACKUP));
+
+    try {
+        for (const [name, text] of contents) {
+            try {
+                writeFileAtomicSync(join(root, name), text);
+            } catch (err) {
+                throw new Error(
+                    `Failed to restore ${name} (your previous config is in ${PRE_RESTORE_BACKUP}): ${err instanceof Error ? err.message : String(err)}`
+                );
+            }
+        }
+    } finally {
+        // Even a partial restore changed files under the cache.
+        invalidateMetadataCache();
+    }
+    console.log(`[configBundle] Restored ${contents.size} file(s) from ${srcPath}`);
+    return manifest;
+}
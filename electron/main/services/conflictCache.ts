@@ -0,0 +1,57 @@
+import { existsSync, readFileSync } from 'fs';
+import { join } from 'path';
+import { writeFileAtomicSync } from '../utils/atomicWrite';
+import { getUserDataPath } from '../utils/paths';
+import type { ModConflict } from './conflicts';
+
+/**
+ * Last conflict report, persisted to userData and keyed by a fingerprint of
+ * the enabled-mod set. Parsing every enabled VPK made each visit to the
+ * Conflicts page (and the Sidebar badge) pay the full scan again even when
+ * nothing had changed since the last one. Only one report is kept: it is
+ * either still valid for the current library or useless.
+ */
+
+interface CachedConflictReport {
+    fingerprint: string;
+    conflicts: ModConflict[];
+    at: number;
+}
+
+let cached: CachedConflictReport | null = null;
+
+function getConflictCachePath(): string {
+    return join(getUserDataPath(), 'conflict-cache.json');
+}
+
+function loadReport(): CachedConflictReport | null {
+    if (cached) return cached;
+    try {
+        const path = getConflictCachePath();
+        if (!existsSync(path)) return null;
+        const raw = JSON.parse(readFileSync(path, 'utf-8')) as Partial<CachedConflictReport>;
+        if (typeof raw.fingerprint === 'string' && Array.isArray(raw.conflicts)) {
+            cached = { fingerprint: raw.fingerprint, conflicts: raw.conflicts, at: raw.at ?? 0 };
+        }
+    } catch (err) {
+        console.warn('[conflictCache] Failed to read cached report, rescanning:', err);
+    }
+    return cached;
+}
+
+/** The cached report when it was taken for `fingerprint`, else null. */
+export function getCachedConflicts(fingerprint: string): ModConflict[] | null {
+    const report = loadReport();
+    return report && report.fingerprint === fingerprint ? report.conflicts : null;
+}
+
+/** Replace the cached report with `conflicts`, taken for `fingerprint`. */
+export function setCachedConflicts(fingerprint: string, conflicts: ModConflict[]): void {
+    cached = { fingerprint, conflicts, at: Date.now() };
+    try {
+        writeFileAtomicSync(getConflictCachePath(), JSON.stringify(cached));
+    } catch (err) {
+        // Best-effort: a failed write only costs a rescan next time.
+        console.warn('[conflictCache] Failed to write cached report:', err);
+    }
+}
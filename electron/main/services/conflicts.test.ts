@@ -4,7 +4,7 @@
  * four-layer ignore system (per-file / global / whole-mod / pair) are pure
  * except for a getModMetadata read, so we mock the whole service dep-chain and
  * exercise them directly. Mocking the deps keeps this off the electron/sqlite
- * graph (conflicts.ts only imports these four modules).
+ * graph (conflicts.ts only imports these five modules).
  */
 import { describe, it, expect } from 'vitest';
 import { vi } from 'vitest';
@@ -18,11 +18,17 @@ const h = vi.hoisted(() => ({
 }));
 
 vi.mock('./metadata', () => ({ getModMetadata: (k: string) => h.metaMap[k] }));
-vi.mock('./mods', () => ({ scanMods: vi.fn() }));
+vi.mock('./mods', () => ({
+  scanMods: vi.fn(),
+  resolveModDisplayName: (fileName: string, modName?: string) => ({ name: modName ?? fileName, source: 'file' }),
+}));
 vi.mock('./vpk', () => ({ parseVpkDirectoriesAsync: vi.fn() }));
-vi.mock('./settings', () => ({ loadSettings: vi.fn(() => ({})) }));
+vi.mock('./settings', () => ({ loadSettings: vi.fn(() => ({})), patchSettings: vi.fn() }));
+vi.mock('./conflictCache', () => ({ getCachedConflicts: vi.fn(() => null), setCachedConflicts: vi.fn() }));
 
-import { conflictPairKey, modConflictIdentity, migrateIgnoredConflictKeysForMods } from './conflicts';
+import { conflictPairKey, modConflictIdentity, migrateIgnoredConflictKeysForMods, findShadowedMods } from './conflicts';
+import { scanMods } from './mods';
+import { parseVpkDirectoriesAsync } from './vpk';
 
 function mod(over: Partial<Mod> & { id: string }): Mod {
   return {
@@ -94,3 +100,28 @@ describe('migrateIgnoredConflictKeysForMods', () => {
     expect(migrateIgnoredConflictKeysForMods(['ghost1::ghost2'], [])).toEqual(['ghost1::ghost2']);
   });
 });
+
+describe('findShadowedMods', () => {
+  it('flags an enabled mod only when earlier mods cover every one of its files', async () => {
+    const winner = mod({ id: 'winner', priority: 1 });
+    const fullyCovered = mod({ id: 'covered', priority: 2 });
+    const partlyCovered = mod({ id: 'partial', priority: 3 });
+    const disabled = mod({ id: 'off', priority: 4, enabled: false });
+    vi.mocked(scanMods).mockResolvedValue([winner, fullyCovered, partlyCovered, disabled]);
+    vi.mocked(parseVpkDirectoriesAsync).mockResolvedValue(
+      new Map([
+        [winner.path, ['models/a.vmdl_c', 'models/b.vmdl_c']],
+        [fullyCovered.path, ['models/a.vmdl_c', 'readme.txt']],
+        [partlyCovered.path, ['models/b.vmdl_c', 'models/c.vmdl_c']],
+      ])
+    );
+
+    const result = await findShadowedMods('/deadlock');
+    expect(result).toHaveLength(1);
+    expect(result[0]).toMatchObject({
+      modId: 'covered',
+      fileCount: 1,
+      shadowedBy: [{ modId: 'winner', modName: 'winner.vpk' }],
+    });
+  });
+});
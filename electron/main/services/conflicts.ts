@@ -1,7 +1,10 @@
-import { scanMods, type Mod } from './mods';
-import { parseVpkDirectoriesAsync, type VpkParseStats } from './vpk';
-import { loadSettings } from './settings';
-import { getModMetadata } from './metadata';
+import { createHash } from 'crypto';
+import { scanMods, resolveModDisplayName, type Mod } from './mods';
+import { parseVpkDirectoriesAsync, readVpkEntryFingerprints, type VpkParseStats } from './vpk';
+import { loadSettings, patchSettings } from './settings';
+import { getModMetadata, setModMetadata } from './metadata';
+import { getCachedConflicts, setCachedConflicts } from './conflictCache';
+import type { ModOverrideStats, ModPathMatch, ShadowedMod, SharedTexturePair, SharedTextureReport } from '../../../src/types/mod';
 
 /**
  * Build a stable order-independent key for a pair of mod ids or identities.
@@ -72,6 +75,15 @@ export interface ModConflict {
      *  (after subtracting any individually ignored files). Drives the per-file
      *  ignore UI. Undefined for `priority` conflicts. */
     files?: string[];
+    /** pakNN slot and addon folder ('addons', 'addons1', ...) of each side. */
+    modAPriority: number;
+    modAFolder: string;
+    modBPriority: number;
+    modBFolder: string;
+    /** For `file` conflicts: id of the mod whose copy the game loads (earlier
+     *  folder, then lower pakNN). Undefined for `priority` conflicts, where
+     *  the two share a slot and the order between them is undefined. */
+    winner?: string;
 }
 
 function normalizeIdentityPart(value: string): string {
@@ -140,12 +152,29 @@ export function migrateIgnoredConflictKeysForMods(keys: string[], mods: Mod[]):
     return Array.from(new Set(migrated));
 }
 
+/**
+ * Enabled mods minus the Locker cosmetics and sound VPKs. Those deliberately
+ * override the paths of the mods they pulled from (that's how a chosen card /
+ * sound wins), so every override scan below leaves them out.
+ */
+function enabledUnmanagedMods(mods: Mod[]): Mod[] {
+    return mods.filter((m) => {
+        const meta = getModMetadata(m.metaKey);
+        return m.enabled && !meta?.lockerCosmetics && !meta?.lockerSounds;
+    });
+}
+
+function modDisplayName(mod: Mod): string {
+    return resolveModDisplayName(mod.fileName, getModMetadata(mod.metaKey)?.modName).name;
+}
+
 function createConflict(
     modA: Mod,
     modB: Mod,
     conflictType: ModConflict['conflictType'],
     details: string,
-    files?: string[]
+    files?: string[],
+    winner?: Mod
 ): ModConflict {
     const modAIdentity = modConflictIdentity(modA);
     const modBIdentity = modConflictIdentity(modB);
@@ -160,14 +189,55 @@ function createConflict(
         conflictType,
         details,
         files,
+        modAPriority: modA.priority,
+        modAFolder: folderOf(modA),
+        modBPriority: modB.priority,
+        modBFolder: folderOf(modB),
+        winner: winner?.id,
     };
 }
 
+// Bump when detectConflicts' output changes shape or meaning, so reports
+// cached by an older build are not served.
+const CONFLICT_CACHE_VERSION = 2;
+
+/**
+ * Fingerprint of everything a conflict report depends on: each enabled mod's
+ * file (path, size, mtime), slot, display name and ignore identity, plus the
+ * user's ignore settings. Any file swapped, re-enabled, renumbered or
+ * re-tagged, or any ignore added or removed, changes it.
+ */
+function conflictFingerprint(
+    deadlockPath: string,
+    enabledMods: Mod[],
+    settings: ReturnType<typeof loadSettings>
+): string {
+    const mods = enabledMods
+        .map((m) => [m.id, m.path, m.size, m.installedAt, m.priority, m.name, modConflictIdentity(m)])
+        .sort((a, b) => String(a[0]).localeCompare(String(b[0])));
+    return createHash('sha256')
+        .update(JSON.stringify({
+            version: CONFLICT_CACHE_VERSION,
+            deadlockPath,
+            mods,
+            ignoredConflicts: settings.ignoredConflicts ?? [],
+            ignoredConflictFiles: settings.ignoredConflictFiles ?? {},
+            ignoredConflictFilesGlobal: settings.ignoredConflictFilesGlobal ?? [],
+            ignoredConflictMods: settings.ignoredConflictMods ?? [],
+            ignoreConflictsByDefault: settings.ignoreConflictsByDefault ?? false,
+        }))
+        .digest('hex');
+}
+
 /**
  * Detect conflicts between installed mods
  * Two mods conflict if they have overlapping file paths.
+ *
+ * The report is cached on disk by conflictFingerprint, so an unchanged library
+ * is answered without parsing a single VPK. `forceRescan` skips the cache (the
+ * result still replaces it).
  */
-export async function detectConflicts(deadlockPath: string): Promise<ModConflict[]> {
+export async function detectConflicts(deadlockPath: string, forceRescan = false): Promise<ModConflict[]> {
     // Track scan duration + cache hit rate so user-supplied diagnostic
     // reports tell us whether the conflict scan is actually the thing
     // freezing the main process on their machine. Without this we can
@@ -175,15 +245,11 @@ export async function detectConflicts(deadlockPath: string): Promise<ModConflict
     const scanStart = Date.now();
     const vpkStats: VpkParseStats = { hits: 0, misses: 0 };
 
+    // scanMods returns mods in global load order, earliest winner first.
     const mods = await scanMods(deadlockPath);
-    // The Locker cosmetics VPK and the Locker sound VPK deliberately override
-    // the paths of the mods they pulled from (that's how a chosen card / sound
-    // wins), so they would otherwise report a file conflict against every
-    // source. Exclude them.
-    const enabledMods = mods.filter(m => {
-        const meta = getModMetadata(m.metaKey);
-        return m.enabled && !meta?.lockerCosmetics && !meta?.lockerSounds;
-    });
+    const loadOrder = new Map(mods.map((mod, index) => [mod.id, index]));
+    // Locker VPKs would otherwise report a file conflict against every source.
+    const enabledMods = enabledUnmanagedMods(mods);
     const conflicts: ModConflict[] = [];
 
     if (enabledMods.length < 2) {
@@ -191,6 +257,21 @@ export async function detectConflicts(deadlockPath: string): Promise<ModConflict
         return [];
     }
 
+    // Settings are read once here: they feed the fingerprint as well as the
+    // per-file (in-loop) and whole-pair (end) filtering below.
+    const settings = loadSettings();
+    const fingerprint = conflictFingerprint(deadlockPath, enabledMods, settings);
+    if (!forceRescan) {
+        const cachedConflicts = getCachedConflicts(fingerprint);
+        if (cachedConflicts) {
+            console.log(
+                `[detectConflicts] enabled=${enabledMods.length} pairs=${cachedConflicts.length} ` +
+                `took=${Date.now() - scanStart}ms (cached)`
+            );
+            return cachedConflicts;
+        }
+    }
+
     // Priority conflicts (same pak number). Track which pairs are already
     // reported so the later file-conflict pass skips them in O(1).
     const reportedPairs = new Set<string>();
@@ -239,11 +320,9 @@ export async function detectConflicts(deadlockPath: string): Promise<ModConflict
         }
     }
 
-    // Load settings once for both per-file (in-loop) and whole-pair (end)
-    // filtering. Per-file ignores are keyed by the same stable identity pair
-    // key as whole-pair ignores, so resolve each enabled mod's identity up
-    // front and reuse it.
-    const settings = loadSettings();
+    // Per-file ignores are keyed by the same stable identity pair key as
+    // whole-pair ignores, so resolve each enabled mod's identity up front and
+    // reuse it.
     const ignoredFilesByKey = new Map<string, Set<string>>();
     for (const [key, files] of Object.entries(settings.ignoredConflictFiles ?? {})) {
         if (Array.isArray(files) && files.length > 0) {
@@ -299,7 +378,8 @@ export async function detectConflicts(deadlockPath: string): Promise<ModConflict
                         modB,
                         'file',
                         fileConflictDetail(remaining),
-                        remaining
+                        remaining,
+                        loadOrder.get(modA.id)! <= loadOrder.get(modB.id)! ? modA : modB
                     ));
                     markReported(modA, modB);
                 }
@@ -311,6 +391,7 @@ export async function detectConflicts(deadlockPath: string): Promise<ModConflict
     // the end rather than inside the loops so the ignored list stays a clean
     // post-filter: easy to reason about and easy to disable later.
     if (settings.ignoreConflictsByDefault) {
+        setCachedConflicts(fingerprint, []);
         return [];
     }
     const ignored = new Set(settings.ignoredConflicts ?? []);
@@ -330,5 +411,270 @@ export async function detectConflicts(deadlockPath: string): Promise<ModConflict
         `vpkCache=${vpkStats.hits}/${vpkStats.hits + vpkStats.misses} ` +
         `took=${Date.now() - scanStart}ms`
     );
+    setCachedConflicts(fingerprint, filtered);
     return filtered;
 }
+
+/** Per-mod cap on returned paths; a broad query like "materials/" would
+ *  otherwise ship thousands of strings over IPC. */
+const PATH_SEARCH_MAX_PER_MOD = 50;
+
+/**
+ * Find every installed mod (enabled or not) whose VPK contains a file path
+ * matching `query` (case-insensitive substring, either slash style). The
+ * debugging companion to detectConflicts: "which mod ships
+ * models/heroes/abrams/abrams.vmdl?". Uses the same parse cache.
+ */
+export async function findModsContainingPath(
+    deadlockPath: string,
+    query: string
+): Promise<ModPathMatch[]> {
+    const needle = query.trim().replace(/\\/g, '/').toLowerCase();
+    if (!needle) return [];
+
+    const mods = await scanMods(deadlockPath);
+    const parsed = await parseVpkDirectoriesAsync(mods.map((mod) => mod.path));
+    const matches: ModPathMatch[] = [];
+    for (const mod of mods) {
+        const hits = (parsed.get(mod.path) ?? []).filter((p) => p.toLowerCase().includes(needle));
+        if (hits.length === 0) continue;
+        matches.push({
+            modId: mod.id,
+            modName: modDisplayName(mod),
+            fileName: mod.fileName,
+            enabled: mod.enabled,
+            priority: mod.priority,
+            paths: hits.slice(0, PATH_SEARCH_MAX_PER_MOD),
+            totalMatches: hits.length,
+        });
+    }
+    // Enabled mods first in load order, so the winner of a clobber reads first.
+    return matches.sort((a, b) => Number(b.enabled) - Number(a.enabled) || a.priority - b.priority);
+}
+
+/**
+ * Enabled mods that are dead weight: every game file they ship is also in a
+ * mod that loads earlier (lower folder, then lower pakNN), so none of their
+ * content ever reaches the game. Locker-managed VPKs are skipped for the same
+ * reason detectConflicts skips them. Results are in load order.
+ */
+export async function findShadowedMods(deadlockPath: string): Promise<ShadowedMod[]> {
+    // scanMods returns mods sorted by global load order, earliest winner first.
+    const mods = await scanMods(deadlockPath);
+    const enabledMods = enabledUnmanagedMods(mods);
+    if (enabledMods.length < 2) return [];
+
+    const parsed = await parseVpkDirectoriesAsync(enabledMods.map((mod) => mod.path));
+
+    // Game path -> the first (winning) mod that ships it.
+    const owners = new Map<string, Mod>();
+    const shadowed: ShadowedMod[] = [];
+    for (const mod of enabledMods) {
+        const files = (parsed.get(mod.path) ?? []).filter((file) => !shouldIgnoreFile(file));
+        if (files.length === 0) continue;
+
+        const winners = new Map<string, Mod>();
+        let covered = true;
+        for (const file of files) {
+            const owner = owners.get(file);
+            if (!owner) {
+                covered = false;
+                owners.set(file, mod);
+            } else {
+                winners.set(owner.id, owner);
+            }
+        }
+        if (!covered) continue;
+
+        shadowed.push({
+            modId: mod.id,
+            modName: modDisplayName(mod),
+            fileName: mod.fileName,
+            priority: mod.priority,
+            fileCount: files.length,
+            shadowedBy: [...winners.values()].map((w) => ({ modId: w.id, modName: modDisplayName(w) })),
+        });
+    }
+    return shadowed;
+}
+
+/**
+ * Every enabled mod with the number of game paths it wins (its copy is the
+ * one the game loads) and loses (an earlier mod overrides it), most wins
+ * first, ties in load order. A mod with zero wins is inert; findShadowedMods
+ * lists exactly those. Same Locker exclusions and ignored files as the
+ * conflict scan.
+ */
+export async function rankModsByOverrideWins(deadlockPath: string): Promise<ModOverrideStats[]> {
+    // scanMods returns mods sorted by global load order, earliest winner first.
+    const mods = await scanMods(deadlockPath);
+    const enabledMods = enabledUnmanagedMods(mods);
+    if (enabledMods.length === 0) return [];
+
+    const parsed = await parseVpkDirectoriesAsync(enabledMods.map((mod) => mod.path));
+    const claimed = new Set<string>();
+    const stats: ModOverrideStats[] = [];
+    for (const mod of enabledMods) {
+        const files = new Set((parsed.get(mod.path) ?? []).filter((file) => !shouldIgnoreFile(file)));
+        let wins = 0;
+        for (const file of files) {
+            if (claimed.has(file)) continue;
+            claimed.add(file);
+            wins++;
+        }
+        stats.push({
+            modId: mod.id,
+            modName: modDisplayName(mod),
+            fileName: mod.fileName,
+            priority: mod.priority,
+            fileCount: files.size,
+            wins,
+            losses: files.size - wins,
+        });
+    }
+    // Array.prototype.sort is stable, so equal win counts keep load order.
+    return stats.sort((a, b) => b.wins - a.wins);
+}
+
+/** Compiled texture under materials/, the files skin packs tend to co-ship. */
+function isTexturePath(filePath: string): boolean {
+    const lower = filePath.toLowerCase();
+    return lower.startsWith('materials/') && lower.endsWith('.vtex_c') && !shouldIgnoreFile(lower);
+}
+
+/**
+ * Sort out texture files shared between enabled mods. Skin packs often bundle
+ * the same base textures (the Mina variants are the well-known case), which
+ * shows up as a file conflict even though only the earlier-loading copy is
+ * ever used. When every shared texture in a pair is byte-identical (same VPK
+ * entry CRC and length), the overlap is harmless: those paths are added to
+ * the pair's per-file conflict ignores. Pairs with a differing texture are
+ * reported so the user can reorder or pick one.
+ */
+export async function reconcileSharedTextures(deadlockPath: string): Promise<SharedTextureReport> {
+    // scanMods returns mods sorted by global load order, earliest winner first.
+    const mods = await scanMods(deadlockPath);
+    const enabledMods = enabledUnmanagedMods(mods);
+    const report: SharedTextureReport = { reconciled: [], conflicting: [] };
+    if (enabledMods.length < 2) return report;
+
+    const textures = new Map<string, Map<string, string>>();
+    for (const mod of enabledMods) {
+        const fingerprints = readVpkEntryFingerprints(mod.path);
+        if (!fingerprints) continue;
+        const own = new Map<string, string>();
+        for (const [path, fingerprint] of fingerprints) {
+            if (isTexturePath(path)) own.set(path, fingerprint);
+        }
+        if (own.size > 0) textures.set(mod.id, own);
+    }
+
+    const withTextures = enabledMods.filter((m) => textures.has(m.id));
+    // Pair key -> texture paths to add to that pair's ignores.
+    const additions = new Map<string, string[]>();
+
+    for (let i = 0; i < withTextures.length; i++) {
+        for (let j = i + 1; j < withTextures.length; j++) {
+            const winner = withTextures[i];
+            const loser = withTextures[j];
+            const winnerTextures = textures.get(winner.id)!;
+            const loserTextures = textures.get(loser.id)!;
+            const shared: string[] = [];
+            const differing: string[] = [];
+            for (const [path, fingerprint] of winnerTextures) {
+                const other = loserTextures.get(path);
+                if (other === undefined) continue;
+                shared.push(path);
+                if (other !== fingerprint) differing.push(path);
+            }
+            if (shared.length === 0) continue;
+
+            const pair: SharedTexturePair = {
+                winnerId: winner.id,
+                winnerName: modDisplayName(winner),
+                loserId: loser.id,
+                loserName: modDisplayName(loser),
+                files: differing.length > 0 ? differing : shared,
+            };
+            if (differing.length > 0) {
+                report.conflicting.push(pair);
+                continue;
+            }
+            report.reconciled.push(pair);
+            additions.set(conflictPairKey(modConflictIdentity(winner), modConflictIdentity(loser)), shared);
+        }
+    }
+
+    // Merge into the ignores as stored now and patch only that setting.
+    const ignoredFiles = { ...(loadSettings().ignoredConflictFiles ?? {}) };
+    let ignoresChanged = false;
+    for (const [key, shared] of additions) {
+        const existing = ignoredFiles[key] ?? [];
+        const added = shared.filter((path) => !existing.includes(path));
+        if (added.length > 0) {
+            ignoredFiles[key] = [...existing, ...added];
+            ignoresChanged = true;
+        }
+    }
+    if (ignoresChanged) {
+        patchSettings({ ignoredConflictFiles: ignoredFiles });
+    }
+    console.log(
+        `[reconcileSharedTextures] reconciled=${report.reconciled.length} conflicting=${report.conflicting.length}`
+    );
+    return report;
+}
+
+/** Resolve two installed mods by id for a known-conflict edit. */
+async function resolveConflictPair(deadlockPath: string, modIdA: string, modIdB: string): Promise<[Mod, Mod]> {
+    if (modIdA === modIdB) {
+        throw new Error('A mod cannot conflict with itself');
+    }
+    const mods = await scanMods(deadlockPath);
+    const modA = mods.find((m) => m.id === modIdA);
+    const modB = mods.find((m) => m.id === modIdB);
+    if (!modA) throw new Error(`Mod not found: ${modIdA}`);
+    if (!modB) throw new Error(`Mod not found: ${modIdB}`);
+    return [modA, modB];
+}
+
+/**
+ * Remember that two mods conflict (e.g. both ship the same vsndevts_c) so
+ * enabling one while the other is enabled warns. Stored on both metadata
+ * entries by stable identity, so it survives priority renames and reinstalls
+ * of the same GameBanana file. Idempotent.
+ */
+export async function markKnownConflict(deadlockPath: string, modIdA: string, modIdB: string): Promise<void> {
+    const [modA, modB] = await resolveConflictPair(deadlockPath, modIdA, modIdB);
+    for (const [mod, other] of [[modA, modB], [modB, modA]] as const) {
+        const current = getModMetadata(mod.metaKey)?.knownConflicts ?? [];
+        const otherIdentity = modConflictIdentity(other);
+        if (!current.includes(otherIdentity)) {
+            setModMetadata(mod.metaKey, { knownConflicts: [...current, otherIdentity] });
+        }
+    }
+}
+
+/** Forget a known conflict between two mods. Removes both directions. */
+export async function clearKnownConflict(deadlockPath: string, modIdA: string, modIdB: string): Promise<void> {
+    const [modA, modB] = await resolveConflictPair(deadlockPath, modIdA, modIdB);
+    for (const [mod, other] of [[modA, modB], [modB, modA]] as const) {
+        const current = getModMetadata(mod.metaKey)?.knownConflicts ?? [];
+        const otherIdentity = modConflictIdentity(other);
+        const next = current.filter((identity) => identity !== otherIdentity);
+        if (next.length !== current.length) {
+            setModMetadata(mod.metaKey, { knownConflicts: next.length > 0 ? next : undefined });
+        }
+    }
+}
+
+/**
+ * Enabled mods (other than `target`) that the user has marked as conflicting
+ * with `target`. Used to warn after an enable; never blocks it.
+ */
+export function findEnabledKnownConflicts(target: Mod, mods: Mod[]): Mod[] {
+    const known = getModMetadata(target.metaKey)?.knownConflicts;
+    if (!known || known.length === 0) return [];
+    const wanted = new Set(known);
+    return mods.filter((m) => m.enabled && m.id !== target.id && wanted.has(modConflictIdentity(m)));
+}
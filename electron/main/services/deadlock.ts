@@ -5,19 +5,29 @@ import { execFileSync } from 'child_process';
 
 const DEADLOCK_APP_ID = '1422450';
 
+// The registry lookups spawn `reg` twice; the answer doesn't change while the
+// app runs, so ask once.
+let windowsSteamInstallPaths: string[] | null = null;
+
 /**
- * Steam install locations to probe (the directory that contains steamapps/),
- * in priority order. On Windows we ask the registry first so users with
- * Steam installed off the C: default are handled correctly.
+ * Steam install locations to probe (the directory that contains steamapps/,
+ * config/ and userdata/), in priority order. On Windows we ask the registry
+ * first so users with Steam installed off the C: default are handled
+ * correctly; on Linux ~/.steam/root is the link the Steam client keeps
+ * pointed at its real install, wherever that is. The other Linux entries are
+ * the usual dotfile locations, several of them symlinks to one another.
+ * Every Steam folder lookup (config, userdata, appcache) derives from this.
  */
-function getSteamInstallPaths(): string[] {
+export function getSteamInstallPaths(): string[] {
     const home = homedir();
 
     if (process.platform === 'linux') {
         return [
+            join(home, '.steam/root'),
             join(home, '.steam/steam'),
             join(home, '.local/share/Steam'),
             join(home, '.var/app/com.valvesoftware.Steam/.steam/steam'),
+            join(home, '.var/app/com.valvesoftware.Steam/.local/share/Steam'),
         ];
     }
 
@@ -26,6 +36,7 @@ function getSteamInstallPaths(): string[] {
     }
 
     if (process.platform === 'win32') {
+        if (windowsSteamInstallPaths) return windowsSteamInstallPaths;
         const paths: string[] = [];
         const push = (p: string | null) => {
             if (!p) return;
@@ -38,6 +49,7 @@ function getSteamInstallPaths(): string[] {
         push(queryWindowsRegistry('HKCU\\SOFTWARE\\Valve\\Steam', 'SteamPath'));
         push('C:\\Program Files (x86)\\Steam');
         push('C:\\Program Files\\Steam');
+        windowsSteamInstallPaths = paths;
         return paths;
     }
 
@@ -162,6 +174,11 @@ export function getAddonsPath(deadlockPath: string): string {
     return addonsPath;
 }
 
+// Set when getDisabledPath had to create .disabled this session. On an
+// existing install that usually means a Steam "verify integrity" removed it
+// (it lives inside addons), taking every disabled mod with it.
+let disabledFolderRecreated = false;
+
 /**
  * Get the disabled mods folder path, creating it if necessary
  */
@@ -170,11 +187,32 @@ export function getDisabledPath(deadlockPath: string): string {
 
     if (!existsSync(disabledPath)) {
         mkdirSync(disabledPath, { recursive: true });
+        disabledFolderRecreated = true;
     }
 
     return disabledPath;
 }
 
+/** Whether .disabled was missing and had to be created this session. */
+export function wasDisabledFolderRecreated(): boolean {
+    return disabledFolderRecreated;
+}
+
+/**
+ * Get the quarantine folder path, creating it if necessary. Mods the user has
+ * set aside as "this broke my game" live here, outside both the addon folders
+ * and .disabled, so no scan lists them as ordinary mods.
+ */
+export function getQuarantinePath(deadlockPath: string): string {
+    const quarantinePath = join(deadlockPath, 'game', 'citadel', 'addons', '.quarantine');
+
+    if (!existsSync(quarantinePath)) {
+        mkdirSync(quarantinePath, { recursive: true });
+    }
+
+    return quarantinePath;
+}
+
 /**
  * Get the Grimoire-managed addon folder path, creating it if necessary.
  *
@@ -285,6 +323,44 @@ export function metaKeyFor(vpkPath: string): string {
     return OVERFLOW_FOLDER_RE.test(parentName) ? `${parentName}/${fileName}` : fileName;
 }
 
+/**
+ * The VPK a scanned-folder metadata key (see metaKeyFor) points at when it is
+ * still on disk, else null. A bare key can be in the base addons folder or in
+ * .disabled; synthetic keys (quarantine:, library:, locker:) never resolve.
+ * Lets callers check one metadata entry without a full scanMods.
+ */
+export function installedPathForMetaKey(deadlockPath: string, metaKey: string): string | null {
+    const citadel = join(deadlockPath, 'game', 'citadel');
+    const parts = metaKey.split('/');
+    let candidates: string[];
+    if (parts.length === 2 && OVERFLOW_FOLDER_RE.test(parts[0]) && basename(parts[1]) === parts[1]) {
+        candidates = [join(citadel, parts[0], parts[1])];
+    } else if (parts.length === 1 && !metaKey.includes(':') && basename(metaKey) === metaKey) {
+        candidates = [join(citadel, 'addons', metaKey), join(citadel, 'addons', '.disabled', metaKey)];
+    } else {
+        return null;
+    }
+    return candidates.find((path) => existsSync(path)) ?? null;
+}
+
+/** Metadata prefix for quarantined VPKs. Synthetic like `locker:*`, so the
+ *  orphan prune (which only sees scanned folders) leaves these entries alone. */
+export const QUARANTINE_META_PREFIX = 'quarantine:';
+
+/** Metadata key of a VPK in the quarantine folder. */
+export function quarantineMetaKey(fileName: string): string {
+    return `${QUARANTINE_META_PREFIX}${fileName}`;
+}
+
+/** Metadata prefix for VPKs in the external mod library (see
+ *  AppSettings.modLibraryPath). Synthetic for the same reason as quarantine. */
+export const LIBRARY_META_PREFIX = 'library:';
+
+/** Metadata key of a VPK in the mod library. */
+export function libraryMetaKey(fileName: string): string {
+    return `${LIBRARY_META_PREFIX}${fileName}`;
+}
+
 // ── Deadworks custom-server content ──────────────────────────────────────────
 //
 // Deadworks dedicated servers ship downloadable content (maps + addon VPKs) the
@@ -11,7 +11,17 @@ import log from 'electron-log';
 import { app } from 'electron';
 import { promises as fs } from 'fs';
 import os from 'os';
+import { extname } from 'path';
+import AdmZip from 'adm-zip';
 import { getInstallSource } from './updater';
+import {
+    getUserDataPath,
+    getSettingsPath,
+    getProfilesPath,
+    getModCachePath,
+} from '../utils/paths';
+import { getActiveMetadataPath } from './metadata';
+import type { AppPaths } from '../../../src/types/electron';
 
 // Tail size for the diagnostic report. 256 KB is ~3-5k log lines: plenty of
 // context for the typical "I just hit a bug" report without ballooning the
@@ -47,6 +57,20 @@ function getLogFilePath(): string {
     return log.transports.file.getFile().path;
 }
 
+/** Where this install keeps its files, so a user filing an issue can find
+ *  the ones to attach. Unredacted: these are the user's own paths, shown to
+ *  them locally. The diagnostic report carries a sanitized copy. */
+export function getAppPaths(): AppPaths {
+    return {
+        dataDir: getUserDataPath(),
+        settings: getSettingsPath(),
+        metadata: getActiveMetadataPath(),
+        profiles: getProfilesPath(),
+        modCache: getModCachePath(),
+        logFile: getLogFilePath(),
+    };
+}
+
 // Redaction rules applied to every report body and to the saved .txt file.
 // Order matters: more-specific patterns (Authorization headers) run before
 // generic ones (bearer tokens). All replacements use opaque placeholders so a
@@ -122,6 +146,8 @@ export async function buildReportText(
         `Electron:     ${process.versions.electron}`,
         `Chrome:       ${process.versions.chrome}`,
         `Node:         ${process.versions.node}`,
+        `Data dir:     ${sanitize(getUserDataPath())}`,
+        `Log file:     ${sanitize(logPath)}`,
     ];
 
     const parts = [headerLines.join('\n')];
@@ -135,6 +161,42 @@ export async function buildReportText(
     return parts.join('\n\n');
 }
 
+/**
+ * Write a single zip a user can attach to an issue: the full-log report
+ * (header, description, main.log), the rotated main.old.log when present,
+ * settings.json and the active install's metadata file. Every text entry goes
+ * through sanitize(). Returns the path written.
+ */
+export async function exportSupportBundle(destPath: string, description: string): Promise<string> {
+    if (extname(destPath).toLowerCase() !== '.zip') {
+        throw new Error('Support bundle must be saved as a .zip file');
+    }
+    const zip = new AdmZip();
+    const addText = (name: string, text: string) => zip.addFile(name, Buffer.from(sanitize(text), 'utf8'));
+
+    addText('report.txt', await buildReportText(description, { includeFullLog: true }));
+
+    const oldLogPath = getLogFilePath().replace(/\.log$/i, '.old.log');
+    const oldLog = await fs.readFile(oldLogPath, 'utf8').catch(() => null);
+    if (oldLog !== null) addText('main.old.log', oldLog);
+
+    // Missing files are normal (fresh install, no mods yet); note them
+    // instead of failing the export.
+    const files: Array<[string, string]> = [
+        ['settings.json', getSettingsPath()],
+        ['mod-metadata.json', getActiveMetadataPath()],
+    ];
+    for (const [name, path] of files) {
+        const text = await fs.readFile(path, 'utf8').catch(
+            (err: unknown) => `<could not read ${name}: ${err instanceof Error ? err.message : String(err)}>`
+        );
+        addText(name, text);
+    }
+
+    await fs.writeFile(destPath, zip.toBuffer());
+    return destPath;
+}
+
 async function readFullLog(path: string): Promise<string> {
     try {
         return await fs.readFile(path, 'utf8');
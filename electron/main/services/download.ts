@@ -3,21 +3,28 @@ import { promises as fs, statSync } from 'fs';
 import { join, basename, extname, resolve } from 'path';
 import { tmpdir } from 'os';
 import { BrowserWindow } from 'electron';
-import { getDisabledPath } from './deadlock';
-import { extractArchive, isArchive, checkOneClickOptOut, scanSuspiciousFiles, type ExtractedVpk } from './extract';
+import { getDisabledPath, getCitadelPath, installedPathForMetaKey } from './deadlock';
+import { extractArchiveWithReport, ExtractionToolError, isArchive, checkOneClickOptOut, scanSuspiciousFiles, removeInvalidExtractedVpks, EXTRAS_DIR_NAME, type ExtractedVpk } from './extract';
 import { buildVpkIndexBySize } from './vpkVariantIndex';
 import { randomUUID } from 'crypto';
-import { setModMetadataWithHash, getModMetadata } from './metadata';
+import { setModMetadata, setModMetadataWithHash, getModMetadata, loadMetadata } from './metadata';
+import { fingerprintFile } from './fileMatch';
 import { inferHeroFromTitle } from '@grimoire/social-types/heroes';
-import { fetchModDetails, type GameBananaModDetails } from './gamebanana';
-import { makeDisabledFileName, scanMods, disableMod, enableMod } from './mods';
-import { validateDownloadUrl, validateFileSize } from './security';
-import { loadSettings } from './settings';
+import { fetchModDetails, normalizeGameBananaSection, type GameBananaModDetails } from './gamebanana';
+import { getModThumbnail } from '../../../src/types/gamebanana';
+import { makeDisabledFileName, generateModId, resolveModDisplayName, scanMods, disableMod, enableMod, enableModAt, reorderMods, countFreeEnableSlots, assertPrioritySlotFree } from './mods';
+import { validateDownloadUrl, validateFileSize, validateFileMd5 } from './security';
+import { loadSettings, assertNotReadOnly, assertOnline } from './settings';
 import { getVpkLabels, inferHeroFromVpk } from './vpk';
+import { beginInstallWrite, completeInstallWrite } from './installJournal';
+import { recordDownloadThroughput } from './downloadThroughput';
+import { GRIMOIRE_USER_AGENT } from './userAgent';
+import { getModExtrasPath } from '../utils/paths';
+import { toLongPath, toWindowsSafeFileName } from '../utils/windowsPaths';
 import type { LockerHeroSource } from '../../../src/types/mod';
 // DownloadModArgs is single-sourced in src/types/electron.ts; re-exported
 // because ipc/gamebanana.ts imports it from this service.
-import type { DownloadModArgs } from '../../../src/types/electron';
+import type { AlreadyInstalledMod, DownloadCancelledData, DownloadDuplicateData, DownloadErrorData, DownloadModArgs, DownloadSizeInfo, InstallPhaseData } from '../../../src/types/electron';
 export type { DownloadModArgs };
 import https from 'https';
 import http from 'http';
@@ -31,6 +38,7 @@ interface DownloadQueueItem {
 
 export interface DownloadInstallResult {
     installedVpks: string[];
+    alreadyInstalled?: AlreadyInstalledMod;
 }
 
 export interface OneClickInstallArgs {
@@ -108,6 +116,22 @@ async function cleanupDownloadWorkDir(workDir: string): Promise<void> {
     }
 }
 
+/**
+ * Move the auxiliary files the extractor kept (extraExtractExtensions) out of
+ * the work dir before it is cleaned up, into the mod's extras folder with
+ * their archive paths intact. Best-effort: the VPK install already succeeded.
+ */
+async function saveExtractedExtras(workDir: string, modId: number, extras: string[]): Promise<void> {
+    if (extras.length === 0) return;
+    const dest = getModExtrasPath(modId);
+    try {
+        await fs.cp(join(workDir, EXTRAS_DIR_NAME), dest, { recursive: true, force: true });
+        console.log(`[download] Kept ${extras.length} auxiliary file(s) in ${dest}`);
+    } catch (err) {
+        console.warn(`[download] Failed to keep auxiliary files for mod ${modId}:`, err);
+    }
+}
+
 function normalizePathForCompare(filePath: string): string {
     const normalized = resolve(filePath);
     return process.platform === 'win32' ? normalized.toLowerCase() : normalized;
@@ -146,15 +170,17 @@ async function moveFileWithoutOverwrite(sourcePath: string, destinationPath: str
         throw new Error(`Refusing to overwrite existing mod file: ${basename(destinationPath)}`);
     }
 
+    const from = toLongPath(sourcePath);
+    const to = toLongPath(destinationPath);
     try {
-        await fs.rename(sourcePath, destinationPath);
+        await fs.rename(from, to);
     } catch (err) {
         if ((err as NodeJS.ErrnoException).code !== 'EXDEV') {
             throw err;
         }
 
-        await fs.copyFile(sourcePath, destinationPath, constants.COPYFILE_EXCL);
-        await fs.unlink(sourcePath);
+        await fs.copyFile(from, to, constants.COPYFILE_EXCL);
+        await fs.unlink(from);
     }
 }
 
@@ -179,6 +205,26 @@ let currentDownloadInfo: DownloadQueueItem | null = null;
 // at a time, so a single slot is enough.
 let currentCancelHandler: (() => void) | null = null;
 
+// Disabled-folder writes the current queue item has started but not finished.
+// The queue runs one item at a time, so like currentCancelHandler one set is
+// enough; a failure hands whatever is left to its failedDownloads entry.
+const activeInstallWrites = new Set<string>();
+
+interface FailedDownload {
+    args: DownloadModArgs;
+    directUrl?: string;
+    enrichedDetails?: GameBananaModDetails;
+    partialPaths: string[];
+}
+
+// Failed downloads by `${modId}:${fileId}`, so retryDownload can re-queue one
+// from its ids alone. In memory only: a restart starts with a clean list.
+const failedDownloads = new Map<string, FailedDownload>();
+
+function downloadKey(modId: number, fileId: number): string {
+    return `${modId}:${fileId}`;
+}
+
 /**
  * Get the current download queue state for UI display
  */
@@ -212,6 +258,31 @@ export function removeFromQueue(modId: number): boolean {
     return false;
 }
 
+/**
+ * Cancel one download by its (modId, fileId), whether it is still queued or
+ * is the one running. A queued entry is dropped and its caller rejected; the
+ * running one goes through cancelActiveDownload, whose handler stops the
+ * stream between chunks and deletes the partial file. The queue runs one item
+ * at a time, so the key picks between the queue and the single active cancel
+ * handler. Emits download-cancelled; false when nothing matched or the active
+ * phase can't be cancelled (extracting, writing metadata).
+ */
+export function cancelDownload(modId: number, fileId: number): boolean {
+    const key = downloadKey(modId, fileId);
+    const index = downloadQueue.findIndex((item) => downloadKey(item.args.modId, item.args.fileId) === key);
+    if (index !== -1) {
+        const [removed] = downloadQueue.splice(index, 1);
+        removed.mainWindow?.webContents.send('download-cancelled', { modId, fileId } satisfies DownloadCancelledData);
+        removed.reject(new Error('Cancelled by user'));
+        emitQueueUpdate();
+        return true;
+    }
+    if (currentDownloadInfo && downloadKey(currentDownloadInfo.modId, currentDownloadInfo.fileId) === key) {
+        return cancelActiveDownload();
+    }
+    return false;
+}
+
 /**
  * Emit queue update event to all windows
  */
@@ -227,15 +298,94 @@ function emitQueueUpdate(): void {
     }
 }
 
+/** Free bytes on the volume holding `path`, or null when it can't be read. */
+async function freeDiskBytes(path: string): Promise<number | null> {
+    try {
+        const stats = await fs.statfs(path);
+        return stats.bavail * stats.bsize;
+    } catch {
+        return null;
+    }
+}
+
+/**
+ * Size of a GameBanana file before downloading it, plus the free space on the
+ * Deadlock drive. The archive and its extracted VPKs sit side by side until
+ * install finishes, so `enoughSpace` asks for twice the file size.
+ */
+export async function getDownloadSize(
+    deadlockPath: string | null,
+    modId: number,
+    fileId: number,
+    section = 'Mod'
+): Promise<DownloadSizeInfo> {
+    const details = await fetchModDetails(modId, section);
+    const file = details.files?.find((f) => f.id === fileId);
+    if (!file) {
+        throw new Error(`File ${fileId} not found for mod ${modId}`);
+    }
+    const freeBytes = deadlockPath ? await freeDiskBytes(getCitadelPath(deadlockPath)) : null;
+    return {
+        fileName: file.fileName,
+        fileSize: file.fileSize,
+        freeBytes,
+        enoughSpace: freeBytes === null ? null : freeBytes >= file.fileSize * 2,
+    };
+}
+
+/**
+ * Find an installed mod whose metadata points at this exact GameBanana file.
+ * Reads the metadata sidecar and checks only the matching entries' files, so
+ * a download request never pays for a full scan. Synthetic (non-positive) ids
+ * from 1-Click installs never match.
+ */
+export async function findInstalledGameBananaFile(
+    deadlockPath: string,
+    modId: number,
+    fileId: number
+): Promise<AlreadyInstalledMod | null> {
+    if (modId <= 0 || fileId <= 0) return null;
+    for (const [metaKey, meta] of Object.entries(loadMetadata())) {
+        if (meta.gameBananaId !== modId || meta.gameBananaFileId !== fileId) continue;
+        const path = installedPathForMetaKey(deadlockPath, metaKey);
+        if (!path) continue;
+        const fileName = basename(path);
+        return {
+            id: generateModId(metaKey),
+            name: resolveModDisplayName(fileName, meta.modName).name,
+            fileName,
+        };
+    }
+    return null;
+}
+
 /**
  * Add a download to the queue (public API)
  * Prevents duplicate mods from being queued
  */
-export function downloadMod(
+export async function downloadMod(
     deadlockPath: string,
     args: DownloadModArgs,
     mainWindow: BrowserWindow | null
 ): Promise<DownloadInstallResult> {
+    assertNotReadOnly();
+    assertOnline();
+    // Re-clicking install on a file that is already on disk would otherwise
+    // pull the archive again and land a second disabled copy. Callers that
+    // really want a fresh copy (repairs, replacements) pass force.
+    if (!args.force) {
+        const existing = await findInstalledGameBananaFile(deadlockPath, args.modId, args.fileId);
+        if (existing) {
+            console.log(`[downloadMod] Mod ${args.modId} file ${args.fileId} already installed as ${existing.fileName}, skipping`);
+            return { installedVpks: [], alreadyInstalled: existing };
+        }
+    }
+    // Check a requested pak number up front so a taken slot fails before the
+    // archive is pulled; installAtTargetPriority checks again once it lands.
+    if (args.targetPriority !== undefined) {
+        await assertPrioritySlotFree(deadlockPath, args.targetPriority);
+    }
+
     // Dedup at (modId, fileId), not modId alone: a single submission can have
     // multiple files (Gold/Silver variants, lite/HD versions) and profile
     // imports legitimately queue several of them back-to-back. Also check the
@@ -245,14 +395,14 @@ export function downloadMod(
         q.args.modId === args.modId && q.args.fileId === args.fileId;
     if (downloadQueue.some(sameTarget)) {
         console.log(`[downloadMod] Mod ${args.modId} file ${args.fileId} already queued, skipping`);
-        return Promise.resolve({ installedVpks: [] });
+        return { installedVpks: [] };
     }
     if (
         currentDownloadInfo?.modId === args.modId &&
         currentDownloadInfo?.fileId === args.fileId
     ) {
         console.log(`[downloadMod] Mod ${args.modId} file ${args.fileId} already downloading, skipping`);
-        return Promise.resolve({ installedVpks: [] });
+        return { installedVpks: [] };
     }
 
     return new Promise((resolve, reject) => {
@@ -273,6 +423,7 @@ export function downloadModFromUrl(
     oneClick: OneClickInstallArgs,
     mainWindow: BrowserWindow | null
 ): Promise<DownloadInstallResult> {
+    assertNotReadOnly();
     validateDownloadUrl(oneClick.archiveUrl);
 
     const fileName = deriveFileNameFromUrl(oneClick.archiveUrl);
@@ -312,6 +463,52 @@ export function downloadModFromUrl(
     });
 }
 
+/**
+ * Re-queue a download that failed earlier in this session, by its ids alone.
+ * Any VPK the failed attempt was still moving into the disabled folder is
+ * deleted first (the staging folder itself is always cleaned up), then the
+ * original request runs again as a fresh download or 1-Click install.
+ */
+export async function retryDownload(
+    deadlockPath: string,
+    modId: number,
+    fileId: number,
+    mainWindow: BrowserWindow | null
+): Promise<DownloadInstallResult> {
+    const key = downloadKey(modId, fileId);
+    const failed = failedDownloads.get(key);
+    if (!failed) {
+        throw new Error(`No failed download to retry for mod ${modId} file ${fileId}`);
+    }
+    assertNotReadOnly();
+    assertOnline();
+
+    for (const partialPath of failed.partialPaths) {
+        try {
+            await fs.unlink(toLongPath(partialPath));
+            console.log(`[retryDownload] Removed partial file ${partialPath}`);
+        } catch (err) {
+            if ((err as NodeJS.ErrnoException).code !== 'ENOENT') throw err;
+        }
+        completeInstallWrite(partialPath);
+    }
+    failedDownloads.delete(key);
+
+    if (failed.directUrl) {
+        return downloadModFromUrl(
+            deadlockPath,
+            {
+                archiveUrl: failed.directUrl,
+                modType: failed.args.section,
+                modId: failed.args.modId,
+                enrichedDetails: failed.enrichedDetails,
+            },
+            mainWindow
+        );
+    }
+    return downloadMod(deadlockPath, failed.args, mainWindow);
+}
+
 function deriveFileNameFromUrl(url: string): string {
     try {
         const parsed = new URL(url);
@@ -326,6 +523,89 @@ function deriveFileNameFromUrl(url: string): string {
     }
 }
 
+/** A failure whose category is known where it is thrown. */
+class DownloadFailure extends Error {
+    constructor(readonly errorCode: DownloadErrorData['errorCode'], message: string) {
+        super(message);
+        this.name = 'DownloadFailure';
+    }
+}
+
+const NETWORK_ERROR_CODES = new Set([
+    'ECONNRESET', 'ECONNREFUSED', 'ETIMEDOUT', 'ENOTFOUND', 'EAI_AGAIN', 'ENETUNREACH', 'EHOSTUNREACH', 'EPIPE',
+]);
+const DISK_ERROR_CODES = new Set(['ENOSPC', 'EDQUOT', 'EROFS', 'EACCES', 'EPERM']);
+
+// Set once the active queue item has already told the renderer why it failed,
+// so processQueue doesn't send a second, generic download-error for it.
+let downloadErrorEmitted = false;
+
+function emitDownloadError(mainWindow: BrowserWindow | null, data: DownloadErrorData): void {
+    downloadErrorEmitted = true;
+    mainWindow?.webContents.send('download-error', data);
+}
+
+/**
+ * Verify what an archive extracted before anything is installed: zero-length
+ * VPKs and directory VPKs without the VPK magic are deleted and reported back
+ * as "name (reason)". Fails the install when the archive had VPKs but none
+ * survived, since that is a corrupt archive or a partial extraction.
+ */
+function verifyExtractedVpks(extracted: ExtractedVpk[], logTag: string): { vpks: ExtractedVpk[]; invalid: string[] } {
+    const { valid, invalid } = removeInvalidExtractedVpks(extracted);
+    if (invalid.length === 0) return { vpks: valid, invalid: [] };
+    const labels = invalid.map((i) => `${i.fileName} (${i.reason})`);
+    console.warn(`[${logTag}] Dropped ${invalid.length} invalid extracted VPK(s):`, labels);
+    if (valid.length === 0) {
+        throw new DownloadFailure(
+            'EXTRACTION_FAILED',
+            `The archive extracted, but none of its VPKs are valid (${labels.join(', ')}). ` +
+            'The upload may be corrupt; retry the download or check the mod page.'
+        );
+    }
+    return { vpks: valid, invalid: labels };
+}
+
+/**
+ * Report which install step a download has reached. Sent alongside the older
+ * download-progress/extracting/complete events so the UI can show a step
+ * indicator and a stalled install shows where it stopped.
+ */
+function emitInstallPhase(mainWindow: BrowserWindow | null, data: InstallPhaseData): void {
+    console.log(`[install] ${data.modId}/${data.fileId}: ${data.phase}${data.detail ? ` (${data.detail})` : ''}`);
+    mainWindow?.webContents.send('install-phase', data);
+}
+
+/**
+ * Map a download failure to a category and a message with targeted advice,
+ * so the UI can say "check your connection" or "free up disk space" instead
+ * of a blanket "download failed".
+ */
+export function classifyDownloadError(err: Error): Pick<DownloadErrorData, 'errorCode' | 'message'> {
+    if (err instanceof DownloadFailure) {
+        return { errorCode: err.errorCode, message: err.message };
+    }
+    const code = (err as NodeJS.ErrnoException).code;
+    if (code && DISK_ERROR_CODES.has(code)) {
+        return {
+            errorCode: 'DISK_ERROR',
+            message: code === 'ENOSPC' || code === 'EDQUOT'
+                ? 'Not enough disk space to finish installing this mod. Free up space and try again.'
+                : `Couldn't write the mod to disk (${code}). Check that the game folder is writable and try again.`,
+        };
+    }
+    if (
+        (code && NETWORK_ERROR_CODES.has(code)) ||
+        /Download failed with status|timed out|stalled|socket hang up/i.test(err.message)
+    ) {
+        return {
+            errorCode: 'NETWORK',
+            message: `Network error while downloading: ${err.message}. Check your connection and retry.`,
+        };
+    }
+    return { errorCode: 'UNKNOWN', message: err.message };
+}
+
 /**
  * Process the download queue one at a time
  */
@@ -346,6 +626,9 @@ async function processQueue(): Promise<void> {
             modName: item.args.modName,
         };
         emitQueueUpdate(); // Notify UI that queue changed and current download started
+        downloadErrorEmitted = false;
+        activeInstallWrites.clear();
+        const key = downloadKey(item.args.modId, item.args.fileId);
         try {
             const result = item.directUrl
                 ? await executeOneClickDownload(
@@ -356,19 +639,38 @@ async function processQueue(): Promise<void> {
                     item.mainWindow
                 )
                 : await executeDownload(item.deadlockPath, item.args, item.mainWindow);
+            failedDownloads.delete(key);
             item.resolve(result);
         } catch (error) {
             const err = error instanceof Error ? error : new Error(String(error));
+            if (err.message !== 'CANCELLED_BY_USER') {
+                failedDownloads.set(key, {
+                    args: item.args,
+                    directUrl: item.directUrl,
+                    enrichedDetails: item.enrichedDetails,
+                    partialPaths: [...activeInstallWrites],
+                });
+            }
             // Surface user-cancellation so the renderer can clear the row.
             // The multi-VPK-picker cancel path already emits download-error
             // itself; this only covers HTTP-phase cancels which don't.
             if (err.message === 'CANCELLED_BY_USER') {
-                item.mainWindow?.webContents.send('download-error', {
+                item.mainWindow?.webContents.send('download-cancelled', {
+                    modId: item.args.modId,
+                    fileId: item.args.fileId,
+                } satisfies DownloadCancelledData);
+                emitDownloadError(item.mainWindow, {
                     modId: item.args.modId,
                     fileId: item.args.fileId,
                     errorCode: 'CANCELLED_BY_USER',
                     message: 'Download cancelled.',
                 });
+            } else if (!downloadErrorEmitted) {
+                emitDownloadError(item.mainWindow, {
+                    modId: item.args.modId,
+                    fileId: item.args.fileId,
+                    ...classifyDownloadError(err),
+                });
             }
             item.reject(err);
         }
@@ -380,6 +682,15 @@ async function processQueue(): Promise<void> {
     emitQueueUpdate(); // Final update when queue is empty
 }
 
+// One keep-alive pool per protocol for every mod download. Each download
+// follows one or more GameBanana redirects (/dl -> mirror), and a default
+// per-request agent reopened the TLS connection on every hop and every queued
+// file. API calls go through fetch, whose dispatcher already pools.
+const downloadAgents = {
+    https: new https.Agent({ keepAlive: true, maxSockets: 4 }),
+    http: new http.Agent({ keepAlive: true, maxSockets: 4 }),
+};
+
 /**
  * Download a file with progress reporting
  * Includes timeouts to prevent indefinite hangs (P1 fix #5)
@@ -409,7 +720,10 @@ async function downloadFile(
             else resolve();
         };
 
-        const request = protocol.get(url, (response) => {
+        const request = protocol.get(url, {
+            agent: url.startsWith('https') ? downloadAgents.https : downloadAgents.http,
+            headers: { 'User-Agent': GRIMOIRE_USER_AGENT },
+        }, (response) => {
             // Clear connection timeout once we get a response
             clearTimeout(connectionTimeoutId);
 
@@ -417,6 +731,8 @@ async function downloadFile(
             if (response.statusCode === 301 || response.statusCode === 302) {
                 const redirectUrl = response.headers.location;
                 if (redirectUrl) {
+                    // Drain the redirect body so its socket returns to the pool.
+                    response.resume();
                     // Validate redirect URL (security: must be HTTPS and trusted domain)
                     try {
                         validateDownloadUrl(redirectUrl);
@@ -436,6 +752,7 @@ async function downloadFile(
             }
 
             if (response.statusCode !== 200) {
+                response.resume();
                 finalize(new Error(`Download failed with status ${response.statusCode}`));
                 return;
             }
@@ -450,9 +767,13 @@ async function downloadFile(
                 if (parsed) onResponseFilename(parsed);
             }
 
-            const totalSize = parseInt(response.headers['content-length'] || '0', 10);
+            // 0 when the server didn't send a usable Content-Length: progress is
+            // then indeterminate and there is no length to verify against.
+            const declaredSize = parseInt(response.headers['content-length'] ?? '', 10);
+            const totalSize = Number.isFinite(declaredSize) && declaredSize > 0 ? declaredSize : 0;
             let downloadedSize = 0;
             let lastProgressTime = Date.now();
+            const responseStartedAt = lastProgressTime;
 
             fileStream = createWriteStream(destPath);
             const stream = fileStream;
@@ -476,10 +797,22 @@ async function downloadFile(
 
             response.pipe(stream);
 
-            stream.on('finish', () => {
+            stream.on('finish', async () => {
                 clearInterval(checkStall);
                 stream.close();
                 currentCancelHandler = null;
+                // A connection dropped mid-body still ends the pipe cleanly, so
+                // without this a short file went on to extraction as if complete.
+                if (totalSize > 0 && downloadedSize !== totalSize && !userCancelled) {
+                    if (existsSync(destPath)) {
+                        await fs.unlink(destPath).catch(() => { });
+                    }
+                    finalize(new Error(
+                        `Download truncated: received ${downloadedSize} of ${totalSize} bytes`
+                    ));
+                    return;
+                }
+                if (!userCancelled) recordDownloadThroughput(downloadedSize, Date.now() - responseStartedAt);
                 finalize(null);
             });
 
@@ -590,7 +923,13 @@ async function renameVpksToAvoidConflicts(
             console.log(`[renameVpks] Installing ${fileName} as ${finalFileName} (disabled)`);
         }
 
-        await moveFileWithoutOverwrite(vpkPath, join(targetPath, finalFileName));
+        // Journal the write so a crash mid-copy is detectable on next launch.
+        const destinationPath = join(targetPath, finalFileName);
+        beginInstallWrite(destinationPath);
+        activeInstallWrites.add(destinationPath);
+        await moveFileWithoutOverwrite(vpkPath, destinationPath);
+        completeInstallWrite(destinationPath);
+        activeInstallWrites.delete(destinationPath);
         renamedFiles.push({ fileName: finalFileName, archiveFolder, sourceFileName: fileName });
     }
 
@@ -619,15 +958,78 @@ async function enableInstalledVpks(
     if (installedVpks.length === 0) return;
 
     const refreshed = await scanMods(deadlockPath);
-    for (const vpkFileName of installedVpks) {
+    const toEnable = installedVpks.filter((vpkFileName) =>
+        refreshed.some((m) => m.fileName === vpkFileName && !m.enabled)
+    );
+    // All-or-nothing: a multi-VPK archive that only partly fits would leave
+    // some variants enabled and the rest stranded in .disabled. The files are
+    // already installed (disabled), so refusing here loses nothing.
+    const free = await countFreeEnableSlots(deadlockPath);
+    if (toEnable.length > free) {
+        throw new Error(
+            `Not enough free mod slots to enable all ${toEnable.length} VPKs from this download ` +
+                `(${free} free). They were installed disabled.`
+        );
+    }
+    const enabledIds: string[] = [];
+    for (const vpkFileName of toEnable) {
         const newMod = refreshed.find((m) => m.fileName === vpkFileName);
-        if (!newMod || newMod.enabled) continue;
+        if (!newMod) continue;
         try {
-            await enableMod(deadlockPath, newMod.id);
+            enabledIds.push((await enableMod(deadlockPath, newMod.id)).id);
         } catch (err) {
             console.warn(`[download] Failed to auto-enable ${vpkFileName} (${context}):`, err);
         }
     }
+
+    // enableMod already took the lowest free slot ('next-free'). A locked
+    // load order never renumbers, so the policy only applies unlocked. The
+    // archive's VPKs move as one block in archive order, in a single reorder.
+    const { installPriorityPolicy, loadOrderLocked } = loadSettings();
+    if (enabledIds.length === 0 || loadOrderLocked === true) return;
+    if (installPriorityPolicy !== 'highest' && installPriorityPolicy !== 'lowest') return;
+    try {
+        const placed = new Set(enabledIds);
+        const others = (await scanMods(deadlockPath))
+            .filter((m) => m.enabled && !placed.has(m.id))
+            .map((m) => m.id);
+        const order = installPriorityPolicy === 'highest' ? [...enabledIds, ...others] : [...others, ...enabledIds];
+        await reorderMods(deadlockPath, order);
+    } catch (err) {
+        console.warn(`[download] Failed to apply install priority ${installPriorityPolicy} (${context}):`, err);
+    }
+}
+
+/**
+ * Enable a freshly installed VPK straight into the pak number the download
+ * asked for (DownloadModArgs.targetPriority). Refuses archives that produced
+ * more than one VPK, since they can't share a slot, and refuses a slot that
+ * was taken while the download ran. enableModAt migrates the metadata to the
+ * new pakNN_dir.vpk key. Returns the installed file names after the move.
+ */
+async function installAtTargetPriority(
+    deadlockPath: string,
+    installedVpks: string[],
+    priority: number
+): Promise<string[]> {
+    if (installedVpks.length === 0) return installedVpks;
+    if (installedVpks.length > 1) {
+        throw new Error(
+            `This download contains ${installedVpks.length} VPKs and can't be placed at priority ${priority}. ` +
+                `They were installed disabled.`
+        );
+    }
+    const [vpkFileName] = installedVpks;
+    try {
+        await assertPrioritySlotFree(deadlockPath, priority, vpkFileName);
+    } catch (err) {
+        throw new Error(`${err instanceof Error ? err.message : String(err)}. ${vpkFileName} was installed disabled.`);
+    }
+    const mod = (await scanMods(deadlockPath)).find((m) => m.fileName === vpkFileName && !m.enabled);
+    if (!mod) return installedVpks;
+    const placed = await enableModAt(deadlockPath, mod.id, priority);
+    console.log(`[download] Installed ${vpkFileName} at priority ${priority} as ${placed.fileName}`);
+    return [placed.fileName];
 }
 
 async function disableSiblingVariants(
@@ -661,6 +1063,72 @@ async function disableSiblingVariants(
     return disabledPeers;
 }
 
+/**
+ * Drop freshly installed VPKs that are byte-identical to a mod already on disk
+ * (the same file downloaded twice, or one the user already has under another
+ * pak number), emitting download-duplicate for each. Only existing mods of the
+ * same size are compared, and their SHA-256 comes from the metadata sidecar,
+ * hashing (and caching) it there when missing, so an install never re-reads
+ * the whole library. Returns the VPKs that are still installed.
+ */
+async function skipDuplicateVpks(
+    deadlockPath: string,
+    targetPath: string,
+    installedVpks: string[],
+    modId: number,
+    fileId: number,
+    mainWindow: BrowserWindow | null
+): Promise<string[]> {
+    if (installedVpks.length === 0) return installedVpks;
+
+    const freshPaths = new Set(installedVpks.map((vpk) => normalizePathForCompare(join(targetPath, vpk))));
+    const existing = (await scanMods(deadlockPath)).filter((m) => !freshPaths.has(normalizePathForCompare(m.path)));
+    const existingHashes = new Map<string, string>();
+    const hashOf = async (mod: (typeof existing)[number]): Promise<string> => {
+        let sha = existingHashes.get(mod.id) ?? getModMetadata(mod.metaKey)?.sha256?.toLowerCase();
+        if (!sha) {
+            sha = (await fingerprintFile(mod.path)).sha256;
+            setModMetadata(mod.metaKey, { sha256: sha });
+        }
+        existingHashes.set(mod.id, sha);
+        return sha;
+    };
+
+    const kept: string[] = [];
+    for (const vpk of installedVpks) {
+        const vpkPath = join(targetPath, vpk);
+        let duplicateOf: (typeof existing)[number] | undefined;
+        try {
+            const fresh = await fingerprintFile(vpkPath);
+            for (const mod of existing) {
+                if (mod.size === fresh.size && (await hashOf(mod)) === fresh.sha256) {
+                    duplicateOf = mod;
+                    break;
+                }
+            }
+        } catch (err) {
+            // A failed comparison must not fail the install; keep the VPK.
+            console.warn(`[download] Duplicate check failed for ${vpk}:`, err);
+        }
+        if (!duplicateOf) {
+            kept.push(vpk);
+            continue;
+        }
+        console.log(`[download] ${vpk} is identical to installed ${duplicateOf.fileName}; skipping`);
+        await fs.unlink(vpkPath).catch(() => { });
+        const data: DownloadDuplicateData = {
+            modId,
+            fileId,
+            fileName: vpk,
+            existingModId: duplicateOf.id,
+            existingModName: resolveModDisplayName(duplicateOf.fileName, getModMetadata(duplicateOf.metaKey)?.modName).name,
+            existingFileName: duplicateOf.fileName,
+        };
+        mainWindow?.webContents.send('download-duplicate', data);
+    }
+    return kept;
+}
+
 /**
  * Execute the actual download (internal, called from queue)
  */
@@ -669,7 +1137,10 @@ async function executeDownload(
     args: DownloadModArgs,
     mainWindow: BrowserWindow | null
 ): Promise<DownloadInstallResult> {
-    const { modId, fileId, fileName, section = 'Mod' } = args;
+    const { modId, fileId, fileName } = args;
+    // Canonical model name (Mod, Sound, Wip, ...) so the details fetch and the
+    // persisted sourceSection agree with what Browse and update checks use.
+    const section = normalizeGameBananaSection(args.section);
 
     console.log(`[downloadMod] Starting download: modId=${modId}, fileId=${fileId}, fileName=${fileName}`);
 
@@ -677,7 +1148,8 @@ async function executeDownload(
     const details: GameBananaModDetails = await fetchModDetails(modId, section);
 
     if (!details.files || details.files.length === 0) {
-        throw new Error(
+        throw new DownloadFailure(
+            'FILE_NOT_FOUND',
             'This mod has no downloadable files on GameBanana. It may have been ' +
             'revoked (copyright, moderation) or the author removed the file. ' +
             'Open the mod page in Browse to check for a current version.'
@@ -686,7 +1158,8 @@ async function executeDownload(
 
     const file = details.files.find((f) => f.id === fileId);
     if (!file) {
-        throw new Error(
+        throw new DownloadFailure(
+            'FILE_NOT_FOUND',
             'The specific file Grimoire had cached for this mod is no longer on ' +
             'GameBanana (likely revoked or replaced). Open the mod page in Browse ' +
             'and pick a current file, or refresh the catalog.'
@@ -707,34 +1180,39 @@ async function executeDownload(
 
     // Download with progress
     const expectedSize = file.fileSize || 0;
+    emitInstallPhase(mainWindow, { modId, fileId, phase: 'download', detail: file.fileName });
     await downloadFile(file.downloadUrl, downloadPath, (downloaded, total) => {
         mainWindow?.webContents.send('download-progress', {
             modId,
             fileId,
             downloaded,
             total,
+            indeterminate: total <= 0,
         });
     });
 
     // Verify file size after download (P0 security fix)
+    emitInstallPhase(mainWindow, { modId, fileId, phase: 'verify' });
     try {
         const actualSize = statSync(downloadPath).size;
         validateFileSize(expectedSize, actualSize);
+        await validateFileMd5(downloadPath, file.md5);
     } catch (sizeError) {
         // Clean up failed download
         if (existsSync(downloadPath)) {
             await fs.unlink(downloadPath).catch(() => { });
         }
-        throw sizeError;
+        throw new DownloadFailure(
+            'CHECKSUM',
+            `The downloaded file failed verification: ${sizeError instanceof Error ? sizeError.message : String(sizeError)}. Retry the download.`
+        );
     }
 
     console.log(`[downloadMod] Download complete, checking for archive...`);
 
-    // Get metadata for later
-    const thumbnail = details.previewMedia?.images?.[0];
-    const thumbnailUrl = thumbnail
-        ? `${thumbnail.baseUrl}/${thumbnail.file530 || thumbnail.file}`
-        : undefined;
+    // Get metadata for later. Same resolution as Browse cards, including the
+    // category-icon/avatar fallback for mods uploaded without screenshots.
+    const thumbnailUrl = getModThumbnail(details);
 
     // GameBanana lets mod authors label each file (e.g. "Gold w/ alt candle").
     // Persist that header so the variant picker can show meaningful names by
@@ -760,6 +1238,7 @@ async function executeDownload(
         modName: details.name,  // Store the actual mod name from GameBanana
         gameBananaId: modId,
         gameBananaFileId: fileId,  // Store which specific file was downloaded
+        remoteDateModified: details.dateModified,  // Baseline for check-mod-updates
         categoryId: details.category?.id,  // Get category from mod details, not filter
         categoryName: details.category?.name,  // Also store category name for display
         thumbnailUrl,
@@ -774,33 +1253,50 @@ async function executeDownload(
     };
 
     let installedVpks: string[] = [];
+    // Variants the user left unticked in the multi-VPK picker.
+    let discardedVpks: string[] = [];
+    // Non-VPK archive entries the extractor dropped; surfaced on completion.
+    let skippedFiles: string[] = [];
+    // Auxiliary files kept per the extraExtractExtensions setting.
+    let extraFiles: string[] = [];
+    // Extracted VPKs dropped as empty or corrupt; surfaced on completion.
+    let invalidVpks: string[] = [];
     let vpkIndexByFile = new Map<string, number>();
     // Final disabled filename -> prettified variant folder, for multi-variant
     // archives (e.g. Tailed_mod vs Tailed_mod_Beard). Drives the picker label
     // and the persisted variantLabel.
     const variantByFile = new Map<string, string>();
+    // Final disabled filename -> archive VPK name, only for names that had to
+    // be remapped to extract on Windows (persisted as archiveFileName).
+    const archiveFileNameByFile = new Map<string, string>();
 
     // Extract if archive
     if (isArchive(downloadPath)) {
         console.log(`[downloadMod] Extracting archive...`);
         mainWindow?.webContents.send('download-extracting', { modId, fileId });
+        emitInstallPhase(mainWindow, { modId, fileId, phase: 'extract', detail: basename(downloadPath) });
 
         let extractedVpks: ExtractedVpk[];
         try {
-            extractedVpks = await extractArchive(downloadPath, workDir);
+            const extraction = await extractArchiveWithReport(downloadPath, workDir, {
+                extraExtensions: loadSettings().extraExtractExtensions,
+            });
+            extractedVpks = extraction.vpks;
+            skippedFiles = extraction.skipped;
+            extraFiles = extraction.extras;
         } catch (extractError) {
             const errorMsg = extractError instanceof Error ? extractError.message : String(extractError);
 
             // The bundled extractors should handle virtually all archives; if they
             // failed, the archive is likely corrupt or uses an exotic format. We
             // still surface 7-Zip as a fallback users can try.
-            const is7zError = errorMsg.includes("7z") ||
-                errorMsg.includes("7-Zip") ||
-                errorMsg.includes("p7zip") ||
-                errorMsg.includes("unrar") ||
-                errorMsg.includes("RAR extraction failed");
+            // A tool that ran and rejected the archive is a bad archive, not a
+            // missing 7-Zip; only "nothing could start" gets the install advice.
+            const is7zError = extractError instanceof ExtractionToolError
+                ? extractError.noToolAvailable
+                : errorMsg.includes("7-Zip") || errorMsg.includes("p7zip");
 
-            mainWindow?.webContents.send('download-error', {
+            emitDownloadError(mainWindow, {
                 modId,
                 fileId,
                 errorCode: is7zError ? 'MISSING_7ZIP' : 'EXTRACTION_FAILED',
@@ -818,6 +1314,7 @@ async function executeDownload(
             throw extractError;
         }
         console.log(`[downloadMod] Extracted ${extractedVpks.length} VPK files:`, extractedVpks);
+        ({ vpks: extractedVpks, invalid: invalidVpks } = verifyExtractedVpks(extractedVpks, 'downloadMod'));
 
         // Rename VPKs to avoid conflicts
         const renamed = await renameVpksToAvoidConflicts(deadlockPath, targetPath, extractedVpks, details.name);
@@ -826,6 +1323,9 @@ async function executeDownload(
             if (r.archiveFolder) variantByFile.set(r.fileName, prettifyVariant(r.archiveFolder));
             // Archive folder + original basename: stable across machines/redownloads.
             stableKeyByFile.set(r.fileName, `${r.archiveFolder ?? ''}\u0000${r.sourceFileName}`);
+            if (toWindowsSafeFileName(r.sourceFileName) !== r.sourceFileName) {
+                archiveFileNameByFile.set(r.fileName, r.sourceFileName);
+            }
         }
         installedVpks = renamed.map((r) => r.fileName);
 
@@ -871,7 +1371,7 @@ async function executeDownload(
                     await fs.unlink(downloadPath).catch(() => { });
                 }
                 installedVpks = [];
-                mainWindow?.webContents.send('download-error', {
+                emitDownloadError(mainWindow, {
                     modId,
                     fileId,
                     errorCode: 'CANCELLED_BY_USER',
@@ -891,6 +1391,7 @@ async function executeDownload(
                     }
                 }
             }
+            discardedVpks = installedVpks.filter((vpk) => !selectedSet.has(vpk));
             installedVpks = installedVpks.filter((vpk) => selectedSet.has(vpk));
         }
         // Single-VPK case: nothing to do, keep as-is.
@@ -910,7 +1411,10 @@ async function executeDownload(
         installedVpks = renamed.map((r) => r.fileName);
     }
 
+    installedVpks = await skipDuplicateVpks(deadlockPath, targetPath, installedVpks, modId, fileId, mainWindow);
+
     // Save metadata for each installed VPK
+    emitInstallPhase(mainWindow, { modId, fileId, phase: 'place', detail: installedVpks.join(', ') });
     console.log(`[downloadMod] Saving metadata for ${installedVpks.length} VPKs`);
     for (const vpkFileName of installedVpks) {
         console.log(`[downloadMod] Saving metadata for: ${vpkFileName}`);
@@ -918,10 +1422,17 @@ async function executeDownload(
         const base = stampVpkLockerHero(metadata, section, vpkPath);
         const variantLabel = variantByFile.get(vpkFileName);
         const vpkIndex = vpkIndexByFile.get(vpkFileName);
-        const perVpkMetadata = { ...base, variantLabel, vpkIndex };
+        const archiveFileName = archiveFileNameByFile.get(vpkFileName);
+        const perVpkMetadata = { ...base, variantLabel, vpkIndex, archiveFileName };
         await setModMetadataWithHash(vpkFileName, perVpkMetadata, vpkPath);
     }
 
+    // A requested pak number enables the mod now, so the sibling and
+    // auto-enable passes below find it already enabled under its new name.
+    if (args.targetPriority !== undefined) {
+        installedVpks = await installAtTargetPriority(deadlockPath, installedVpks, args.targetPriority);
+    }
+
     // Switching variants: when the user installs a different file of a mod they
     // already have enabled, disable the previously-enabled sibling so only the
     // new pick is active. Avoids file-conflict warnings between sibling variants
@@ -970,7 +1481,16 @@ async function executeDownload(
 
     // Notify completion
     console.log(`[downloadMod] Sending download-complete event`);
-    mainWindow?.webContents.send('download-complete', { modId, fileId });
+    await saveExtractedExtras(workDir, modId, extraFiles);
+    mainWindow?.webContents.send('download-complete', {
+        modId,
+        fileId,
+        skippedFiles: skippedFiles.length > 0 ? skippedFiles : undefined,
+        extraFiles: extraFiles.length > 0 ? extraFiles : undefined,
+        invalidVpks: invalidVpks.length > 0 ? invalidVpks : undefined,
+        installedVpks,
+        discardedVpks: discardedVpks.length > 0 ? discardedVpks : undefined,
+    });
     return { installedVpks };
     } finally {
         await cleanupDownloadWorkDir(workDir);
@@ -1109,7 +1629,10 @@ async function executeOneClickDownload(
     enrichedDetails: GameBananaModDetails | undefined,
     mainWindow: BrowserWindow | null
 ): Promise<DownloadInstallResult> {
-    const { modId, fileId, fileName, section = 'Mod' } = args;
+    const { modId, fileId, fileName } = args;
+    // Canonical model name (Mod, Sound, Wip, ...) so the details fetch and the
+    // persisted sourceSection agree with what Browse and update checks use.
+    const section = normalizeGameBananaSection(args.section);
 
     console.log(
         `[oneClickInstall] Starting: url=${archiveUrl}, modType=${section}, modId=${modId}`
@@ -1129,6 +1652,7 @@ async function executeOneClickDownload(
     // mod's file list.
     let responseFilename: string | undefined;
 
+    emitInstallPhase(mainWindow, { modId, fileId, phase: 'download', detail: archiveUrl });
     await downloadFile(
         archiveUrl,
         downloadPath,
@@ -1138,6 +1662,7 @@ async function executeOneClickDownload(
                 fileId,
                 downloaded,
                 total,
+                indeterminate: total <= 0,
             });
         },
         undefined,
@@ -1147,6 +1672,7 @@ async function executeOneClickDownload(
         }
     );
 
+    emitInstallPhase(mainWindow, { modId, fileId, phase: 'verify' });
     try {
         const actualSize = statSync(downloadPath).size;
         validateFileSize(0, actualSize);
@@ -1154,7 +1680,10 @@ async function executeOneClickDownload(
         if (existsSync(downloadPath)) {
             await fs.unlink(downloadPath).catch(() => { });
         }
-        throw sizeError;
+        throw new DownloadFailure(
+            'CHECKSUM',
+            `The downloaded file failed verification: ${sizeError instanceof Error ? sizeError.message : String(sizeError)}. Retry the download.`
+        );
     }
 
     // GameBanana's /dl/<id> URLs hide the real filename, so the extension we
@@ -1180,7 +1709,7 @@ async function executeOneClickDownload(
             if (existsSync(downloadPath)) {
                 await fs.unlink(downloadPath).catch(() => { });
             }
-            mainWindow?.webContents.send('download-error', {
+            emitDownloadError(mainWindow, {
                 modId,
                 fileId,
                 errorCode: 'DISABLED_BY_AUTHOR',
@@ -1208,7 +1737,7 @@ async function executeOneClickDownload(
                 if (existsSync(downloadPath)) {
                     await fs.unlink(downloadPath).catch(() => { });
                 }
-                mainWindow?.webContents.send('download-error', {
+                emitDownloadError(mainWindow, {
                     modId,
                     fileId,
                     errorCode: 'CANCELLED_BY_USER',
@@ -1231,10 +1760,7 @@ async function executeOneClickDownload(
         }
     }
 
-    const thumbnail = enriched?.previewMedia?.images?.[0];
-    const thumbnailUrl = thumbnail
-        ? `${thumbnail.baseUrl}/${thumbnail.file530 || thumbnail.file}`
-        : undefined;
+    const thumbnailUrl = enriched ? getModThumbnail(enriched) : undefined;
 
     const realModId = args.modId !== undefined && args.modId > 0 ? args.modId : undefined;
     // GameBanana's grimoire:// protocol only carries the archive URL + mod id,
@@ -1281,6 +1807,7 @@ async function executeOneClickDownload(
         modName: oneClickModName,
         gameBananaId: realModId,
         gameBananaFileId: resolvedFileId,
+        remoteDateModified: enriched?.dateModified,
         categoryId: enriched?.category?.id,
         categoryName: enriched?.category?.name,
         thumbnailUrl,
@@ -1298,26 +1825,39 @@ async function executeOneClickDownload(
     };
 
     let installedVpks: string[] = [];
+    // Variants the user left unticked in the multi-VPK picker.
+    let discardedVpks: string[] = [];
+    // Non-VPK archive entries the extractor dropped; surfaced on completion.
+    let skippedFiles: string[] = [];
+    // Auxiliary files kept per the extraExtractExtensions setting.
+    let extraFiles: string[] = [];
+    // Extracted VPKs dropped as empty or corrupt; surfaced on completion.
+    let invalidVpks: string[] = [];
     let vpkIndexByFile = new Map<string, number>();
     const variantByFile = new Map<string, string>();
+    const archiveFileNameByFile = new Map<string, string>();
 
     if (isArchive(downloadPath)) {
         mainWindow?.webContents.send('download-extracting', { modId, fileId });
+        emitInstallPhase(mainWindow, { modId, fileId, phase: 'extract', detail: basename(downloadPath) });
 
         let extractedVpks: ExtractedVpk[];
         try {
-            extractedVpks = await extractArchive(downloadPath, workDir);
+            const extraction = await extractArchiveWithReport(downloadPath, workDir, {
+                extraExtensions: loadSettings().extraExtractExtensions,
+            });
+            extractedVpks = extraction.vpks;
+            skippedFiles = extraction.skipped;
+            extraFiles = extraction.extras;
         } catch (extractError) {
             const errorMsg =
                 extractError instanceof Error ? extractError.message : String(extractError);
             const is7zError =
-                errorMsg.includes('7z') ||
-                errorMsg.includes('7-Zip') ||
-                errorMsg.includes('p7zip') ||
-                errorMsg.includes('unrar') ||
-                errorMsg.includes('RAR extraction failed');
+                extractError instanceof ExtractionToolError
+                    ? extractError.noToolAvailable
+                    : errorMsg.includes('7-Zip') || errorMsg.includes('p7zip');
 
-            mainWindow?.webContents.send('download-error', {
+            emitDownloadError(mainWindow, {
                 modId,
                 fileId,
                 errorCode: is7zError ? 'MISSING_7ZIP' : 'EXTRACTION_FAILED',
@@ -1332,6 +1872,7 @@ async function executeOneClickDownload(
             }
             throw extractError;
         }
+        ({ vpks: extractedVpks, invalid: invalidVpks } = verifyExtractedVpks(extractedVpks, 'oneClickInstall'));
 
         const renamed = await renameVpksToAvoidConflicts(deadlockPath, targetPath, extractedVpks, oneClickModName);
         const stableKeyByFile = new Map<string, string>();
@@ -1339,6 +1880,9 @@ async function executeOneClickDownload(
             if (r.archiveFolder) variantByFile.set(r.fileName, prettifyVariant(r.archiveFolder));
             // Archive folder + original basename: stable across machines/redownloads.
             stableKeyByFile.set(r.fileName, `${r.archiveFolder ?? ''}\u0000${r.sourceFileName}`);
+            if (toWindowsSafeFileName(r.sourceFileName) !== r.sourceFileName) {
+                archiveFileNameByFile.set(r.fileName, r.sourceFileName);
+            }
         }
         installedVpks = renamed.map((r) => r.fileName);
 
@@ -1379,7 +1923,7 @@ async function executeOneClickDownload(
                     await fs.unlink(downloadPath).catch(() => { });
                 }
                 installedVpks = [];
-                mainWindow?.webContents.send('download-error', {
+                emitDownloadError(mainWindow, {
                     modId,
                     fileId,
                     errorCode: 'CANCELLED_BY_USER',
@@ -1399,6 +1943,7 @@ async function executeOneClickDownload(
                     }
                 }
             }
+            discardedVpks = installedVpks.filter((vpk) => !selectedSet.has(vpk));
             installedVpks = installedVpks.filter((vpk) => selectedSet.has(vpk));
         }
 
@@ -1415,15 +1960,23 @@ async function executeOneClickDownload(
         installedVpks = renamed.map((r) => r.fileName);
     }
 
+    installedVpks = await skipDuplicateVpks(deadlockPath, targetPath, installedVpks, modId, fileId, mainWindow);
+
+    emitInstallPhase(mainWindow, { modId, fileId, phase: 'place', detail: installedVpks.join(', ') });
     for (const vpkFileName of installedVpks) {
         const vpkPath = join(targetPath, vpkFileName);
         const base = stampVpkLockerHero(metadata, section, vpkPath);
         const variantLabel = variantByFile.get(vpkFileName);
         const vpkIndex = vpkIndexByFile.get(vpkFileName);
-        const perVpkMetadata = { ...base, variantLabel, vpkIndex };
+        const archiveFileName = archiveFileNameByFile.get(vpkFileName);
+        const perVpkMetadata = { ...base, variantLabel, vpkIndex, archiveFileName };
         await setModMetadataWithHash(vpkFileName, perVpkMetadata, vpkPath);
     }
 
+    if (args.targetPriority !== undefined) {
+        installedVpks = await installAtTargetPriority(deadlockPath, installedVpks, args.targetPriority);
+    }
+
     const settings = loadSettings();
     let enabledInstalledVpks = false;
     if (settings.autoDisableSiblingVariants !== false) {
@@ -1459,7 +2012,16 @@ async function executeOneClickDownload(
         }
     }
 
-    mainWindow?.webContents.send('download-complete', { modId, fileId });
+    await saveExtractedExtras(workDir, modId, extraFiles);
+    mainWindow?.webContents.send('download-complete', {
+        modId,
+        fileId,
+        skippedFiles: skippedFiles.length > 0 ? skippedFiles : undefined,
+        extraFiles: extraFiles.length > 0 ? extraFiles : undefined,
+        invalidVpks: invalidVpks.length > 0 ? invalidVpks : undefined,
+        installedVpks,
+        discardedVpks: discardedVpks.length > 0 ? discardedVpks : undefined,
+    });
     return { installedVpks };
     } finally {
         await cleanupDownloadWorkDir(workDir);
@@ -0,0 +1,77 @@
+import { existsSync, readFileSync } from 'fs';
+import { join } from 'path';
+import { writeFileAtomicSync } from '../utils/atomicWrite';
+import { getUserDataPath } from '../utils/paths';
+import type { DownloadEstimate } from '../../../src/types/electron';
+
+/**
+ * Rolling record of how fast recent downloads actually ran, so a download can
+ * be given an ETA before it starts (slow Deck storage and Wi-Fi make a 2 GB
+ * skin pack a real commitment). Persisted to userData so the first download
+ * of a session already has an estimate.
+ */
+
+interface ThroughputSample {
+    bytes: number;
+    ms: number;
+    at: number;
+}
+
+const MAX_SAMPLES = 10;
+// Small files finish inside connection setup and say nothing about bandwidth.
+const MIN_SAMPLE_BYTES = 1024 * 1024;
+
+let samples: ThroughputSample[] | null = null;
+
+function getThroughputPath(): string {
+    return join(getUserDataPath(), 'download-throughput.json');
+}
+
+function loadSamples(): ThroughputSample[] {
+    if (samples) return samples;
+    try {
+        const path = getThroughputPath();
+        const raw = existsSync(path) ? JSON.parse(readFileSync(path, 'utf-8')) : [];
+        samples = Array.isArray(raw)
+            ? (raw as ThroughputSample[]).filter((s) => s && s.bytes > 0 && s.ms > 0)
+            : [];
+    } catch (err) {
+        console.warn('[throughput] Failed to read samples, starting empty:', err);
+        samples = [];
+    }
+    return samples;
+}
+
+/** Record a finished transfer of `bytes` that took `ms` of wall time. */
+export function recordDownloadThroughput(bytes: number, ms: number): void {
+    if (bytes < MIN_SAMPLE_BYTES || ms <= 0) return;
+    const next = [...loadSamples(), { bytes, ms, at: Date.now() }].slice(-MAX_SAMPLES);
+    samples = next;
+    try {
+        writeFileAtomicSync(getThroughputPath(), JSON.stringify(next, null, 2));
+    } catch (err) {
+        // Best-effort: losing a sample only makes the next estimate staler.
+        console.warn('[throughput] Failed to write samples:', err);
+    }
+}
+
+/**
+ * Expected time to download `fileSize` bytes at the recent average rate
+ * (total bytes over total time, so one long download outweighs a short
+ * burst). Null fields when there is no history yet.
+ */
+export function estimateDownloadTime(fileSize: number): DownloadEstimate {
+    const history = loadSamples();
+    const bytes = history.reduce((sum, s) => sum + s.bytes, 0);
+    const ms = history.reduce((sum, s) => sum + s.ms, 0);
+    if (bytes === 0 || ms === 0) {
+        return { bytesPerSecond: null, seconds: null, samples: 0 };
+    }
+    const bytesPerSecond = bytes / (ms / 1000);
+    const size = Number.isFinite(fileSize) && fileSize > 0 ? fileSize : 0;
+    return {
+        bytesPerSecond: Math.round(bytesPerSecond),
+        seconds: Math.ceil(size / bytesPerSecond),
+        samples: history.length,
+    };
+}
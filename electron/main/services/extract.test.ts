@@ -11,11 +11,12 @@
  * extractArchive with no mocking.
  */
 import { describe, it, expect, beforeAll } from 'vitest';
-import { mkdtempSync, mkdirSync, readFileSync } from 'fs';
+import { existsSync, mkdtempSync, mkdirSync, readFileSync, readdirSync, writeFileSync } from 'fs';
 import { tmpdir } from 'os';
 import { join } from 'path';
 import AdmZip from 'adm-zip';
-import { extractArchive } from './extract';
+import { archiveEntryFileName, extractArchive, extractArchiveWithReport, extractSingleEntry, findLooseSource2Files, normalizeExtraExtensions, removeInvalidExtractedVpks, ExtractionToolError, EXTRAS_DIR_NAME } from './extract';
+import { readVpkEntries } from './vpk';
 
 describe('extractArchive (multi-variant VPK folders)', () => {
   let extracted: Awaited<ReturnType<typeof extractArchive>>;
@@ -68,3 +69,218 @@ describe('extractArchive (multi-variant VPK folders)', () => {
     expect(pak83).toHaveLength(2);
   });
 });
+
+describe('extractArchive (Windows-unsafe names)', () => {
+  it('writes VPKs under a Windows-safe name but reports the archive name', async () => {
+    const root = mkdtempSync(join(tmpdir(), 'extract-test-'));
+    const dest = join(root, 'out');
+    mkdirSync(dest, { recursive: true });
+    const zip = new AdmZip();
+    zip.addFile('skin:v2?_dir.vpk', Buffer.from('SKIN'));
+    zip.addFile('con.vpk', Buffer.from('CON'));
+    const zipPath = join(root, 'unsafe.zip');
+    zip.writeZip(zipPath);
+
+    const extracted = await extractArchive(zipPath, dest);
+    expect(extracted.map((e) => e.fileName).sort()).toEqual(['con.vpk', 'skin:v2?_dir.vpk']);
+    expect(readdirSync(dest).sort()).toEqual(['_con.vpk', 'skin_v2__dir.vpk']);
+  });
+});
+
+describe('archiveEntryFileName', () => {
+  it('splits backslash-separated entry names on every platform', () => {
+    expect(archiveEntryFileName('Variant A\\pak01_dir.vpk')).toBe('pak01_dir.vpk');
+    expect(archiveEntryFileName('a/b\\c/pak02_dir.vpk')).toBe('pak02_dir.vpk');
+  });
+
+  it('decodes percent-encoded names and leaves malformed escapes alone', () => {
+    expect(archiveEntryFileName('skins/pak01%20hd_dir.vpk')).toBe('pak01 hd_dir.vpk');
+    expect(archiveEntryFileName('100%_dir.vpk')).toBe('100%_dir.vpk');
+    expect(archiveEntryFileName('bad%E0%A4_dir.vpk')).toBe('bad%E0%A4_dir.vpk');
+  });
+});
+
+describe('extractArchive (encoded entry names)', () => {
+  it('decodes the VPK name and its variant folder', async () => {
+    const root = mkdtempSync(join(tmpdir(), 'extract-test-'));
+    const dest = join(root, 'out');
+    mkdirSync(dest, { recursive: true });
+    const zip = new AdmZip();
+    zip.addFile('No%20Beard/pak83%20hd_dir.vpk', Buffer.from('NOBEARD'));
+    const zipPath = join(root, 'encoded.zip');
+    zip.writeZip(zipPath);
+
+    const extracted = await extractArchive(zipPath, dest);
+    expect(extracted.map((e) => [e.fileName, e.archiveFolder])).toEqual([['pak83 hd_dir.vpk', 'No Beard']]);
+    expect(readdirSync(dest)).toEqual(['pak83 hd_dir.vpk']);
+  });
+});
+
+describe('extractArchiveWithReport (skipped entries)', () => {
+  it('lists the non-VPK files the VPK-only filter dropped', async () => {
+    const root = mkdtempSync(join(tmpdir(), 'extract-test-'));
+    const dest = join(root, 'out');
+    mkdirSync(dest, { recursive: true });
+
+    const zip = new AdmZip();
+    zip.addFile('pak01_dir.vpk', Buffer.from('VPK'));
+    zip.addFile('readme.txt', Buffer.from('hi'));
+    zip.addFile('cfg/autoexec.cfg', Buffer.from('bind x y'));
+    const zipPath = join(root, 'mixed.zip');
+    zip.writeZip(zipPath);
+
+    const result = await extractArchiveWithReport(zipPath, dest);
+    expect(result.vpks.map((v) => v.fileName)).toEqual(['pak01_dir.vpk']);
+    expect(result.skipped.sort()).toEqual(['cfg/autoexec.cfg', 'readme.txt']);
+    expect(result.extras).toEqual([]);
+  });
+
+  it('keeps opted-in extensions under the extras folder with their archive paths', async () => {
+    const root = mkdtempSync(join(tmpdir(), 'extract-test-'));
+    const dest = join(root, 'out');
+    mkdirSync(dest, { recursive: true });
+
+    const zip = new AdmZip();
+    zip.addFile('pak01_dir.vpk', Buffer.from('VPK'));
+    zip.addFile('readme.txt', Buffer.from('hi'));
+    zip.addFile('cfg/autoexec.cfg', Buffer.from('bind x y'));
+    const zipPath = join(root, 'mixed.zip');
+    zip.writeZip(zipPath);
+
+    const result = await extractArchiveWithReport(zipPath, dest, { extraExtensions: ['cfg', 'exe'] });
+    expect(result.extras).toEqual(['cfg/autoexec.cfg']);
+    expect(result.skipped).toEqual(['readme.txt']);
+    expect(readFileSync(join(dest, EXTRAS_DIR_NAME, 'cfg', 'autoexec.cfg'), 'utf8')).toBe('bind x y');
+  });
+});
+
+describe('normalizeExtraExtensions', () => {
+  it('lowercases, strips dots and dedupes', () => {
+    expect(normalizeExtraExtensions(['.CFG', 'cfg', ' txt ', ''])).toEqual(['cfg', 'txt']);
+  });
+
+  it('rejects VPKs, executables and malformed entries', () => {
+    expect(() => normalizeExtraExtensions(['vpk'])).toThrow(/Unsupported/);
+    expect(() => normalizeExtraExtensions(['exe'])).toThrow(/Unsupported/);
+    expect(() => normalizeExtraExtensions(['c/fg'])).toThrow(/Unsupported/);
+  });
+});
+
+describe('extractArchive (zip fallback)', () => {
+  it('hands a zip adm-zip rejects to the 7z binaries instead of throwing its error', async () => {
+    const root = mkdtempSync(join(tmpdir(), 'extract-test-'));
+    const dest = join(root, 'out');
+    mkdirSync(dest, { recursive: true });
+
+    // Local-file signature followed by garbage: adm-zip throws while parsing.
+    const zipPath = join(root, 'broken.zip');
+    writeFileSync(zipPath, Buffer.concat([Buffer.from('PK\x03\x04'), Buffer.alloc(64, 0xff)]));
+
+    // 7z can't read it either, so the error is the binary path's, proving the
+    // fallback ran, and nothing half-written is left behind.
+    await expect(extractArchive(zipPath, dest)).rejects.toThrow(/7-Zip|7z/);
+    expect(readdirSync(dest)).toEqual([]);
+  });
+});
+
+describe('ExtractionToolError', () => {
+  it('tells a missing tool apart from one that rejected the archive', () => {
+    const missing = new ExtractionToolError('RAR', [
+      { tool: '7z', outcome: 'not-found' },
+      { tool: 'unrar', outcome: 'not-found' },
+    ]);
+    expect(missing.noToolAvailable).toBe(true);
+    expect(missing.message).toMatch(/No extraction tool available .*tried 7z, unrar.*Install 7-Zip/);
+
+    const failed = new ExtractionToolError('RAR', [
+      { tool: '7za', outcome: 'failed', detail: '7za failed with code 2: Headers Error' },
+      { tool: 'unrar', outcome: 'not-found' },
+    ]);
+    expect(failed.noToolAvailable).toBe(false);
+    expect(failed.message).toContain('7za: 7za failed with code 2: Headers Error; unrar: not found');
+    expect(failed.message).toMatch(/damaged/);
+  });
+});
+
+describe('extractSingleEntry', () => {
+  it('writes only the requested zip entry to the destination path', async () => {
+    const root = mkdtempSync(join(tmpdir(), 'extract-test-'));
+    const zip = new AdmZip();
+    zip.addFile('Variant_A/pak83_dir.vpk', Buffer.from('A-CONTENT'));
+    zip.addFile('Variant_B/pak83_dir.vpk', Buffer.from('B-CONTENT'));
+    const zipPath = join(root, 'variants.zip');
+    zip.writeZip(zipPath);
+
+    const destPath = join(root, 'out', 'picked.vpk');
+    await expect(extractSingleEntry(zipPath, 'Variant_B\\pak83_dir.vpk', destPath)).resolves.toBe(destPath);
+    expect(readFileSync(destPath, 'utf8')).toBe('B-CONTENT');
+    expect(readdirSync(join(root, 'out'))).toEqual(['picked.vpk']);
+  });
+});
+
+describe('removeInvalidExtractedVpks', () => {
+  it('deletes empty and non-VPK files and keeps valid directory and chunk VPKs', () => {
+    const dir = mkdtempSync(join(tmpdir(), 'extract-validate-'));
+    const header = Buffer.alloc(12);
+    header.writeUInt32LE(0x55aa1234, 0);
+    const files: Record<string, Buffer> = {
+      'good_dir.vpk': header,
+      'good_000.vpk': Buffer.from('raw chunk data'),
+      'empty_dir.vpk': Buffer.alloc(0),
+      'html_dir.vpk': Buffer.from('<html>error page</html>'),
+    };
+    for (const [name, data] of Object.entries(files)) writeFileSync(join(dir, name), data);
+    const vpks = Object.keys(files).map((fileName) => ({ path: join(dir, fileName), fileName }));
+
+    const { valid, invalid } = removeInvalidExtractedVpks(vpks);
+
+    expect(valid.map((v) => v.fileName).sort()).toEqual(['good_000.vpk', 'good_dir.vpk']);
+    expect(invalid).toEqual([
+      { fileName: 'empty_dir.vpk', reason: 'empty' },
+      { fileName: 'html_dir.vpk', reason: 'bad-signature' },
+    ]);
+    expect(existsSync(join(dir, 'empty_dir.vpk'))).toBe(false);
+    expect(existsSync(join(dir, 'html_dir.vpk'))).toBe(false);
+  });
+});
+
+describe('loose-file mods', () => {
+  it('finds compiled files under a game folder and picks the biggest tree', () => {
+    const loose = findLooseSource2Files([
+      'readme.txt',
+      'Blue/materials/haze/body.vmat_c',
+      'Blue/materials/haze/body_color.vtex_c',
+      'Red\\materials\\haze\\body.vmat_c',
+      'Blue/materials/haze/body.vmat',
+    ]);
+    expect(loose?.root).toBe('Blue');
+    expect(loose?.files.map((f) => f.gamePath)).toEqual([
+      'materials/haze/body.vmat_c',
+      'materials/haze/body_color.vtex_c',
+    ]);
+    expect(findLooseSource2Files(['readme.txt', 'preview.png'])).toBeNull();
+  });
+
+  it('packs a loose Source 2 tree into a readable pak01_dir.vpk', async () => {
+    const root = mkdtempSync(join(tmpdir(), 'extract-loose-'));
+    const dest = join(root, 'out');
+    mkdirSync(dest, { recursive: true });
+    const zip = new AdmZip();
+    zip.addFile('MyMod/materials/haze/body.vmat_c', Buffer.from('MATERIAL'));
+    zip.addFile('MyMod/sounds/haze/a1.vsnd_c', Buffer.from('SOUND-DATA'));
+    zip.addFile('MyMod/readme.txt', Buffer.from('hi'));
+    const zipPath = join(root, 'loose.zip');
+    zip.writeZip(zipPath);
+
+    const result = await extractArchiveWithReport(zipPath, dest);
+
+    expect(result.vpks.map((v) => v.fileName)).toEqual(['pak01_dir.vpk']);
+    expect(result.skipped).toEqual(['MyMod/readme.txt']);
+    const { version, entries } = readVpkEntries(result.vpks[0].path);
+    expect(version).toBe(2);
+    expect(entries.map((e) => [e.path, e.size]).sort()).toEqual([
+      ['materials/haze/body.vmat_c', 8],
+      ['sounds/haze/a1.vsnd_c', 10],
+    ]);
+  });
+});
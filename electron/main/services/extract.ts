@@ -1,10 +1,12 @@
 import { existsSync, mkdirSync, readdirSync, copyFileSync, unlinkSync, writeFileSync, readFileSync, rmdirSync } from 'fs';
-import { join, extname, basename, dirname } from 'path';
+import { join, extname, basename, dirname, relative, sep, posix } from 'path';
 import { randomBytes } from 'crypto';
 import AdmZip from 'adm-zip';
 import { spawn } from 'child_process';
 import { createExtractorFromData } from 'node-unrar-js';
 import { path7za as bundled7zaPath } from '7zip-bin';
+import { toLongPath, toWindowsSafeFileName } from '../utils/windowsPaths';
+import { checkVpkHeader, writeVpkDirectory, type VpkHeaderProblem, type VpkWriteFile } from './vpk';
 
 /**
  * Resolve a node_modules binary path to its asar.unpacked location when packaged.
@@ -44,6 +46,68 @@ export function find7zPath(): string[] {
     return candidates;
 }
 
+/** How one external extractor fared, for the error when all of them fail. */
+export interface ExtractionToolAttempt {
+    /** Executable name (basename of the path that was tried). */
+    tool: string;
+    outcome: 'not-found' | 'failed';
+    detail?: string;
+}
+
+/**
+ * Every external extractor failed. `noToolAvailable` separates "install
+ * 7-Zip" (nothing could even be started) from "a tool ran and rejected the
+ * archive" (most likely damaged or an unsupported format), which used to
+ * collapse into the last tool's error.
+ */
+export class ExtractionToolError extends Error {
+    readonly attempts: ExtractionToolAttempt[];
+    readonly noToolAvailable: boolean;
+
+    constructor(kind: string, attempts: ExtractionToolAttempt[]) {
+        const noToolAvailable = attempts.every((a) => a.outcome === 'not-found');
+        const tried = attempts.map((a) => a.tool).join(', ');
+        super(
+            noToolAvailable
+                ? `No extraction tool available for this ${kind} archive (tried ${tried}). ` +
+                      'Install 7-Zip from https://7-zip.org and try again.'
+                : `${kind} extraction failed. ` +
+                      attempts
+                          .map((a) => (a.outcome === 'not-found' ? `${a.tool}: not found` : `${a.tool}: ${a.detail ?? 'failed'}`))
+                          .join('; ') +
+                      '. The archive may be damaged or in an unsupported format; try downloading it again.'
+        );
+        this.name = 'ExtractionToolError';
+        this.attempts = attempts;
+        this.noToolAvailable = noToolAvailable;
+    }
+}
+
+/**
+ * Run `run` with each tool in order until one succeeds, recording why each
+ * earlier one didn't. A tool whose process can't be spawned (ENOENT) counts
+ * as not found; anything else it throws counts as a failed extraction.
+ */
+async function tryExtractionTools<T>(
+    kind: string,
+    tools: string[],
+    run: (tool: string) => Promise<T>
+): Promise<T> {
+    const attempts: ExtractionToolAttempt[] = [];
+    for (const tool of tools) {
+        try {
+            return await run(tool);
+        } catch (err) {
+            const notFound = (err as NodeJS.ErrnoException).code === 'ENOENT';
+            const detail = err instanceof Error ? err.message.split(/\r?\n/)[0].slice(0, 200) : String(err);
+            attempts.push({ tool: basename(tool), outcome: notFound ? 'not-found' : 'failed', detail: notFound ? undefined : detail });
+        }
+    }
+    const error = new ExtractionToolError(kind, attempts);
+    console.warn(`[extract] ${error.message}`);
+    throw error;
+}
+
 /**
  * Check if a file is an archive that needs extraction
  */
@@ -70,7 +134,7 @@ export async function checkOneClickOptOut(
     }
 
     for (const entry of entries) {
-        const name = basename(entry).toLowerCase();
+        const name = archiveEntryFileName(entry).toLowerCase();
         if (name === '.disable_gb1click_grimoire') {
             return { disabled: true, reason: 'The mod author disabled Grimoire 1-Click for this mod.' };
         }
@@ -116,14 +180,131 @@ export interface ExtractedVpk {
     /** Absolute path of the extracted VPK in the destination directory. */
     path: string;
     /** The VPK's original basename inside the archive, used to derive its
-     *  installed name. Differs from basename(path) only when a duplicate
-     *  basename was suffixed to keep both variants on disk. */
+     *  installed name. Differs from basename(path) when a duplicate basename
+     *  was suffixed to keep both variants on disk, or when the name had
+     *  characters Windows rejects (see toWindowsSafeFileName). */
     fileName: string;
     /** Immediate parent folder inside the archive, when present. Multi-variant
      *  mods use these folders as the per-variant label. */
     archiveFolder?: string;
 }
 
+/** Extraction outcome: the VPKs that were kept plus every other file entry
+ *  that the VPK-only filter dropped, so the UI can warn about them. */
+export interface ExtractionResult {
+    vpks: ExtractedVpk[];
+    /** Archive-relative paths of non-VPK files that were not installed. */
+    skipped: string[];
+    /** Archive-relative paths of auxiliary files kept because their extension
+     *  is in `extraExtensions`. Written under join(destDir, EXTRAS_DIR_NAME)
+     *  with their archive folder structure intact. */
+    extras: string[];
+}
+
+export interface ExtractOptions {
+    /** Non-VPK extensions (no dot, lowercase) to keep alongside the VPKs.
+     *  Entries failing isValidExtraExtension are ignored. */
+    extraExtensions?: string[];
+}
+
+/** Subfolder of the extraction destination that holds auxiliary files. */
+export const EXTRAS_DIR_NAME = '.extras';
+
+/**
+ * Whether `ext` may be kept as an auxiliary file: a short alphanumeric
+ * extension, never a VPK (those go through the normal path) and never one
+ * of the executable/script types scanSuspiciousFiles warns about.
+ */
+export function isValidExtraExtension(ext: string): boolean {
+    return (
+        /^[a-z0-9]{1,10}$/.test(ext) &&
+        ext !== 'vpk' &&
+        !SUSPICIOUS_EXTENSIONS.has(`.${ext}`)
+    );
+}
+
+/**
+ * Normalize a user-supplied extension list (`".CFG"`, `"txt"`) for the
+ * extraExtractExtensions setting. Throws on an entry that fails
+ * isValidExtraExtension so the Settings page can show the bad value.
+ */
+export function normalizeExtraExtensions(exts: string[]): string[] {
+    const out = new Set<string>();
+    for (const raw of exts) {
+        const ext = String(raw).trim().toLowerCase().replace(/^\./, '');
+        if (!ext) continue;
+        if (!isValidExtraExtension(ext)) {
+            throw new Error(`Unsupported extra extension: ${raw}`);
+        }
+        out.add(ext);
+    }
+    return [...out];
+}
+
+function extraExtensionSet(options: ExtractOptions): Set<string> {
+    return new Set((options.extraExtensions ?? []).map((e) => e.toLowerCase()).filter(isValidExtraExtension));
+}
+
+function isWantedExtra(fileName: string, wanted: Set<string>): boolean {
+    return wanted.size > 0 && wanted.has(extname(fileName).slice(1).toLowerCase());
+}
+
+/**
+ * Archive-relative path for an auxiliary file, or null when the entry would
+ * escape the extras folder (absolute paths, `..` segments).
+ */
+function safeExtraRelativePath(entryName: string): string | null {
+    const normalized = posix.normalize(entryName.replace(/\\/g, '/'));
+    if (normalized.startsWith('/') || /^[a-z]:/i.test(normalized)) return null;
+    if (normalized.split('/').some((part) => part === '..')) return null;
+    return normalized
+        .replace(/^\.\//, '')
+        .split('/')
+        .map(toWindowsSafeFileName)
+        .join('/');
+}
+
+/** Write one auxiliary file under destDir's extras folder. Returns the
+ *  relative path recorded, or null when the entry was unsafe and dropped. */
+function writeExtra(destDir: string, entryName: string, data: Buffer): string | null {
+    const rel = safeExtraRelativePath(entryName);
+    if (!rel) return null;
+    const destPath = join(destDir, EXTRAS_DIR_NAME, ...rel.split('/'));
+    mkdirSync(toLongPath(dirname(destPath)), { recursive: true });
+    writeFileSync(toLongPath(destPath), data);
+    return rel;
+}
+
+/** Undo percent-encoding some RAR tools apply to entry names
+ *  (`pak01%20hd_dir.vpk`). Names without a valid `%XX` escape, or with a
+ *  malformed one, are returned as-is. */
+function decodeEntryPart(part: string): string {
+    if (!/%[0-9a-f]{2}/i.test(part)) return part;
+    try {
+        return decodeURIComponent(part);
+    } catch {
+        return part;
+    }
+}
+
+/** An archive entry's path segments, split on either slash style and decoded.
+ *  Archives built on Windows store `Variant\pak01_dir.vpk`, which
+ *  path.basename on Linux and macOS reads as a single file name. */
+function archiveEntryParts(entryName: string): string[] {
+    return entryName.split(/[\\/]/).filter(Boolean).map(decodeEntryPart);
+}
+
+/**
+ * An archive entry's own file name, the same on every platform whatever slash
+ * style or percent-encoding the archive used. An encoded separator (`%2F`)
+ * decodes into the name and is later replaced by toWindowsSafeFileName.
+ * Exported for tests.
+ */
+export function archiveEntryFileName(entryName: string): string {
+    const parts = archiveEntryParts(entryName);
+    return parts[parts.length - 1] ?? '';
+}
+
 /**
  * The immediate parent folder of an archive entry, or undefined when the entry
  * sits at the archive root. Multi-variant mods use these folders (e.g.
@@ -131,7 +312,7 @@ export interface ExtractedVpk {
  * distinguishes otherwise identically-named VPKs.
  */
 function archiveParentFolder(entryName: string): string | undefined {
-    const parts = entryName.split(/[\\/]/).filter(Boolean);
+    const parts = archiveEntryParts(entryName);
     return parts.length >= 2 ? parts[parts.length - 2] : undefined;
 }
 
@@ -159,6 +340,44 @@ function uniqueDestName(fileName: string, taken: Set<string>): string {
     return candidate;
 }
 
+/** An extracted VPK that failed the post-extraction check. */
+export interface InvalidExtractedVpk {
+    /** The VPK's basename inside the archive. */
+    fileName: string;
+    reason: 'missing' | VpkHeaderProblem;
+}
+
+/**
+ * Drop extracted VPKs that are zero-length or fail the magic-number check,
+ * deleting them from disk. A corrupt archive or a partial extraction used to
+ * install these as silently broken mods. Returns the survivors and what was
+ * removed, so the caller can warn (or fail when nothing survived).
+ */
+export function removeInvalidExtractedVpks(vpks: ExtractedVpk[]): {
+    valid: ExtractedVpk[];
+    invalid: InvalidExtractedVpk[];
+} {
+    const valid: ExtractedVpk[] = [];
+    const invalid: InvalidExtractedVpk[] = [];
+    for (const vpk of vpks) {
+        let reason: InvalidExtractedVpk['reason'] | null;
+        try {
+            reason = checkVpkHeader(toLongPath(vpk.path));
+        } catch {
+            reason = 'missing';
+        }
+        if (!reason) {
+            valid.push(vpk);
+            continue;
+        }
+        invalid.push({ fileName: vpk.fileName, reason });
+        try {
+            unlinkSync(toLongPath(vpk.path));
+        } catch { /* already gone */ }
+    }
+    return { valid, invalid };
+}
+
 /**
  * Extract an archive to a destination directory
  * Returns the list of extracted VPK files
@@ -167,65 +386,271 @@ export async function extractArchive(
     archivePath: string,
     destDir: string
 ): Promise<ExtractedVpk[]> {
+    return (await extractArchiveWithReport(archivePath, destDir)).vpks;
+}
+
+/**
+ * Extract an archive and also report the non-VPK entries that were skipped
+ * (configs, readmes, loose assets), so a mod that ships an accompanying file
+ * doesn't fail silently.
+ */
+export async function extractArchiveWithReport(
+    archivePath: string,
+    destDir: string,
+    options: ExtractOptions = {}
+): Promise<ExtractionResult> {
     const ext = extname(archivePath).toLowerCase();
+    const wanted = extraExtensionSet(options);
 
+    let result: ExtractionResult;
     switch (ext) {
         case '.zip':
-            return extractZip(archivePath, destDir);
+            result = await extractZipWithFallback(archivePath, destDir, wanted);
+            break;
         case '.7z':
-            return extract7z(archivePath, destDir);
+            result = await extract7z(archivePath, destDir, wanted);
+            break;
         case '.rar':
-            return extractRar(archivePath, destDir);
+            result = await extractRar(archivePath, destDir, wanted);
+            break;
         default:
             throw new Error(`Unknown archive format: ${ext}`);
     }
+
+    if (result.vpks.length > 0) return result;
+    const loose = findLooseSource2Files(result.skipped);
+    if (!loose) return result;
+    return packLooseFiles(archivePath, destDir, result, loose);
+}
+
+// Top-level folders of a Source 2 game tree. Compiled resources under one of
+// these (materials/foo.vmat_c, sounds/bar.vsnd_c) are what a loose-file mod
+// ships instead of a VPK.
+const SOURCE2_CONTENT_FOLDERS = new Set([
+    'materials',
+    'models',
+    'particles',
+    'sounds',
+    'soundevents',
+    'panorama',
+    'scripts',
+    'resource',
+    'maps',
+    'animgraphs',
+]);
+
+/** Loose files found in an archive, as archive entry name -> game path. */
+export interface LooseSource2Files {
+    /** Archive folder the game tree sits under ('' for the archive root). */
+    root: string;
+    files: Array<{ entryName: string; gamePath: string }>;
 }
 
 /**
- * Extract a ZIP archive
+ * Detect a loose Source 2 layout among skipped archive entries: compiled
+ * (`*_c`) files under a known game folder. When the tree appears under
+ * several archive folders (one per variant), the folder holding the most
+ * files wins. Null when nothing looks like game content. Exported for tests.
  */
-function extractZip(archivePath: string, destDir: string): ExtractedVpk[] {
-    const zip = new AdmZip(archivePath);
+export function findLooseSource2Files(entryNames: string[]): LooseSource2Files | null {
+    const byRoot = new Map<string, LooseSource2Files['files']>();
+    for (const entryName of entryNames) {
+        const parts = archiveEntryParts(entryName);
+        if (!parts[parts.length - 1]?.toLowerCase().endsWith('_c')) continue;
+        const index = parts.findIndex((part, i) => i < parts.length - 1 && SOURCE2_CONTENT_FOLDERS.has(part.toLowerCase()));
+        if (index < 0) continue;
+        const root = parts.slice(0, index).join('/');
+        if (!byRoot.has(root)) byRoot.set(root, []);
+        byRoot.get(root)!.push({ entryName, gamePath: parts.slice(index).join('/').toLowerCase() });
+    }
+    let best: LooseSource2Files | null = null;
+    for (const [root, files] of byRoot) {
+        if (!best || files.length > best.files.length) best = { root, files };
+    }
+    return best;
+}
+
+/** Contents of the named archive entries, read back out of the archive. */
+async function readArchiveEntries(archivePath: string, entryNames: string[]): Promise<Map<string, Buffer>> {
+    const wanted = new Set(entryNames);
+    const contents = new Map<string, Buffer>();
+    const ext = extname(archivePath).toLowerCase();
+
+    if (ext === '.zip') {
+        try {
+            for (const entry of new AdmZip(archivePath).getEntries()) {
+                if (wanted.has(entry.entryName)) contents.set(entry.entryName, entry.getData());
+            }
+            return contents;
+        } catch (err) {
+            console.warn('[extract] adm-zip failed reading loose files, falling back to 7z:', err);
+            contents.clear();
+        }
+    } else if (ext === '.rar') {
+        try {
+            const data = readFileSync(archivePath);
+            const ab = data.buffer.slice(data.byteOffset, data.byteOffset + data.byteLength) as ArrayBuffer;
+            const extractor = await createExtractorFromData({ data: ab });
+            const extracted = extractor.extract({ files: (header) => wanted.has(header.name) });
+            for (const file of extracted.files) {
+                if (file.extraction) contents.set(file.fileHeader.name, Buffer.from(file.extraction));
+            }
+            if (contents.size > 0) return contents;
+        } catch (err) {
+            console.warn('[extract] node-unrar-js failed reading loose files, falling back to 7z:', err);
+            contents.clear();
+        }
+    }
+
+    // 7z (and the zip / RAR fallback): skipped names are paths relative to the
+    // extraction root, so extract again and read them back from disk.
+    const tempDir = createTempDir('modmanager-loose');
+    try {
+        await tryExtractionTools('7z', find7zPath(), (tool) => runCommand(tool, ['x', '-y', `-o${tempDir}`, archivePath]));
+        for (const name of wanted) {
+            const path = join(tempDir, ...name.split('/'));
+            if (existsSync(path)) contents.set(name, readFileSync(toLongPath(path)));
+        }
+        return contents;
+    } finally {
+        try {
+            rmDirRecursive(tempDir);
+        } catch {
+            // Ignore cleanup errors
+        }
+    }
+}
+
+/**
+ * Pack a loose-file mod into `pak01_dir.vpk` in destDir so it installs like
+ * any other mod. The packed entries leave the skipped list; everything else
+ * (readmes, a second variant's tree) is still reported as skipped.
+ */
+async function packLooseFiles(
+    archivePath: string,
+    destDir: string,
+    result: ExtractionResult,
+    loose: LooseSource2Files
+): Promise<ExtractionResult> {
+    const contents = await readArchiveEntries(archivePath, loose.files.map((f) => f.entryName));
+    const files: VpkWriteFile[] = [];
+    const packed = new Set<string>();
+    const seen = new Set<string>();
+    for (const { entryName, gamePath } of loose.files) {
+        const data = contents.get(entryName);
+        if (!data || seen.has(gamePath)) continue;
+        seen.add(gamePath);
+        files.push({ path: gamePath, data });
+        packed.add(entryName);
+    }
+    if (files.length === 0) return result;
+
+    const fileName = 'pak01_dir.vpk';
+    const destPath = join(destDir, fileName);
+    writeVpkDirectory(files, toLongPath(destPath));
+    console.log(`[extract] Packed ${files.length} loose file(s) from ${loose.root || 'the archive root'} into ${fileName}`);
+    return {
+        ...result,
+        vpks: [{ path: destPath, fileName, archiveFolder: loose.root ? loose.root.split('/').pop() : undefined }],
+        skipped: result.skipped.filter((name) => !packed.has(name)),
+    };
+}
+
+/**
+ * Extract a ZIP in-process, falling back to the 7z binaries when adm-zip
+ * throws. adm-zip rejects some archives 7-Zip reads fine (odd central
+ * directories, data descriptors, some self-built zips), and without this the
+ * whole download failed with an opaque "Invalid or unsupported zip format".
+ */
+async function extractZipWithFallback(
+    archivePath: string,
+    destDir: string,
+    wanted: Set<string>
+): Promise<ExtractionResult> {
+    try {
+        return extractZip(archivePath, destDir, wanted);
+    } catch (err) {
+        console.warn('[extractZip] adm-zip failed, falling back to 7z:', err);
+        return extract7z(archivePath, destDir, wanted);
+    }
+}
+
+/**
+ * Extract a ZIP archive. On failure, removes whatever it already wrote so a
+ * fallback extractor starts from a clean destination.
+ */
+function extractZip(archivePath: string, destDir: string, wanted: Set<string>): ExtractionResult {
     const extracted: ExtractedVpk[] = [];
+    try {
+        return extractZipEntries(archivePath, destDir, wanted, extracted);
+    } catch (err) {
+        for (const { path } of extracted) {
+            try {
+                unlinkSync(path);
+            } catch {
+                // Ignore cleanup errors
+            }
+        }
+        try {
+            rmDirRecursive(join(destDir, EXTRAS_DIR_NAME));
+        } catch {
+            // Ignore cleanup errors
+        }
+        throw err;
+    }
+}
+
+function extractZipEntries(
+    archivePath: string,
+    destDir: string,
+    wanted: Set<string>,
+    extracted: ExtractedVpk[]
+): ExtractionResult {
+    const zip = new AdmZip(archivePath);
+    const skipped: string[] = [];
+    const extras: string[] = [];
     const taken = new Set<string>();
 
     for (const entry of zip.getEntries()) {
         if (entry.isDirectory) continue;
 
-        const fileName = basename(entry.entryName);
-        if (extname(fileName).toLowerCase() !== '.vpk') continue;
+        const fileName = archiveEntryFileName(entry.entryName);
+        if (extname(fileName).toLowerCase() !== '.vpk') {
+            const kept = isWantedExtra(fileName, wanted)
+                ? writeExtra(destDir, entry.entryName, entry.getData())
+                : null;
+            if (kept) extras.push(kept);
+            else skipped.push(entry.entryName);
+            continue;
+        }
 
         // Write straight to the chosen name rather than extractEntryTo, which can
         // only flatten to the entry's own basename and so clobbers same-named
         // VPKs from sibling variant folders.
-        const destPath = join(destDir, uniqueDestName(fileName, taken));
-        writeFileSync(destPath, entry.getData());
+        const destPath = join(destDir, uniqueDestName(toWindowsSafeFileName(fileName), taken));
+        writeFileSync(toLongPath(destPath), entry.getData());
         extracted.push({ path: destPath, fileName, archiveFolder: archiveParentFolder(entry.entryName) });
     }
 
-    return extracted;
+    return { vpks: extracted, skipped, extras };
 }
 
 /**
  * Extract a 7z archive using the bundled 7za binary (falls back to system 7z).
  */
-async function extract7z(archivePath: string, destDir: string): Promise<ExtractedVpk[]> {
+async function extract7z(archivePath: string, destDir: string, wanted: Set<string>): Promise<ExtractionResult> {
     const tempDir = createTempDir('modmanager-7z');
 
     try {
-        for (const tool of find7zPath()) {
-            try {
-                await runCommand(tool, ['x', '-y', `-o${tempDir}`, archivePath]);
-                const vpks = collectVpks(tempDir);
-                return copyVpksToDest(vpks, destDir, tempDir);
-            } catch {
-                // Try next tool
-            }
-        }
-
-        throw new Error(
-            "Failed to extract 7z archive. The bundled extractor failed and no system 7-Zip was found. Please install 7-Zip from https://7-zip.org and try again."
-        );
+        return await tryExtractionTools('7z', find7zPath(), async (tool) => {
+            await runCommand(tool, ['x', '-y', `-o${tempDir}`, archivePath]);
+            const { vpks, skipped, extras } = collectVpks(tempDir, wanted);
+            return {
+                vpks: copyVpksToDest(vpks, destDir, tempDir),
+                skipped,
+                extras: copyExtrasToDest(extras, destDir, tempDir),
+            };
+        });
     } finally {
         try {
             rmDirRecursive(tempDir);
@@ -240,7 +665,7 @@ async function extract7z(archivePath: string, destDir: string): Promise<Extracte
  * default; falls back to the bundled 7za or system unrar if the in-process
  * extractor fails (e.g. RAR5-specific features it can't handle).
  */
-async function extractRar(archivePath: string, destDir: string): Promise<ExtractedVpk[]> {
+async function extractRar(archivePath: string, destDir: string, wanted: Set<string>): Promise<ExtractionResult> {
     // Primary path: pure-JS in-process RAR extractor (no install required).
     try {
         const data = readFileSync(archivePath);
@@ -248,22 +673,38 @@ async function extractRar(archivePath: string, destDir: string): Promise<Extract
         const ab = data.buffer.slice(data.byteOffset, data.byteOffset + data.byteLength) as ArrayBuffer;
         const extractor = await createExtractorFromData({ data: ab });
 
+        const skipped: string[] = [];
+        const extras: string[] = [];
         const extracted = extractor.extract({
-            files: (header) => !header.flags.directory && extname(header.name).toLowerCase() === '.vpk',
+            files: (header) => {
+                if (header.flags.directory) return false;
+                if (extname(header.name).toLowerCase() === '.vpk') return true;
+                if (isWantedExtra(header.name, wanted)) return true;
+                skipped.push(header.name);
+                return false;
+            },
         });
 
         const extractedVpks: ExtractedVpk[] = [];
         const taken = new Set<string>();
         for (const file of extracted.files) {
             if (!file.extraction) continue;
-            const fileName = basename(file.fileHeader.name);
-            const destPath = join(destDir, uniqueDestName(fileName, taken));
-            writeFileSync(destPath, Buffer.from(file.extraction));
+            const fileName = archiveEntryFileName(file.fileHeader.name);
+            if (extname(fileName).toLowerCase() !== '.vpk') {
+                const kept = writeExtra(destDir, file.fileHeader.name, Buffer.from(file.extraction));
+                if (kept) extras.push(kept);
+                else skipped.push(file.fileHeader.name);
+                continue;
+            }
+            const destPath = join(destDir, uniqueDestName(toWindowsSafeFileName(fileName), taken));
+            writeFileSync(toLongPath(destPath), Buffer.from(file.extraction));
             extractedVpks.push({ path: destPath, fileName, archiveFolder: archiveParentFolder(file.fileHeader.name) });
         }
 
-        if (extractedVpks.length > 0) {
-            return extractedVpks;
+        // A loose-file mod has no VPK by design; hand it to the packer rather
+        // than a fallback tool that can't add anything.
+        if (extractedVpks.length > 0 || findLooseSource2Files(skipped)) {
+            return { vpks: extractedVpks, skipped, extras };
         }
         // No VPKs found via in-process — fall through to 7za/unrar in case of
         // odd RAR5 solid archives that node-unrar-js can't iterate.
@@ -274,23 +715,19 @@ async function extractRar(archivePath: string, destDir: string): Promise<Extract
     // Fallback path: bundled 7za, system 7z, or system unrar.
     const tempDir = createTempDir('modmanager-rar');
     try {
-        for (const tool of [...find7zPath(), 'unrar']) {
-            try {
-                if (tool === 'unrar') {
-                    await runCommand(tool, ['x', '-y', archivePath, tempDir]);
-                } else {
-                    await runCommand(tool, ['x', '-y', `-o${tempDir}`, archivePath]);
-                }
-                const vpks = collectVpks(tempDir);
-                return copyVpksToDest(vpks, destDir, tempDir);
-            } catch {
-                // Try next tool
+        return await tryExtractionTools('RAR', [...find7zPath(), 'unrar'], async (tool) => {
+            if (tool === 'unrar') {
+                await runCommand(tool, ['x', '-y', archivePath, tempDir]);
+            } else {
+                await runCommand(tool, ['x', '-y', `-o${tempDir}`, archivePath]);
             }
-        }
-
-        throw new Error(
-            "RAR extraction failed. The bundled extractor could not read this archive. Please install 7-Zip from https://7-zip.org and try again."
-        );
+            const { vpks, skipped, extras } = collectVpks(tempDir, wanted);
+            return {
+                vpks: copyVpksToDest(vpks, destDir, tempDir),
+                skipped,
+                extras: copyExtrasToDest(extras, destDir, tempDir),
+            };
+        });
     } finally {
         try {
             rmDirRecursive(tempDir);
@@ -300,6 +737,15 @@ async function extractRar(archivePath: string, destDir: string): Promise<Extract
     }
 }
 
+/**
+ * Run 7z with `args`, trying the bundled binary first and then system
+ * installs. For callers outside extraction that need 7z's streaming zip
+ * support (addon backups are far too large for adm-zip's in-memory buffers).
+ */
+export function run7z(args: string[], timeoutMs?: number): Promise<void> {
+    return tryExtractionTools('7z', find7zPath(), (tool) => runCommand(tool, args, timeoutMs));
+}
+
 /**
  * Run a command and wait for it to complete
  * Includes timeout to prevent indefinite hangs (P1 fix #6)
@@ -340,16 +786,26 @@ function runCommand(cmd: string, args: string[], timeoutMs = 300000): Promise<vo
         proc.on('error', (err) => {
             clearTimeout(timeoutId);
             if (killed) return;
-            reject(new Error(`${cmd} failed to run: ${err.message}`));
+            // Keep the errno so callers can tell a missing binary (ENOENT) apart.
+            reject(Object.assign(new Error(`${cmd} failed to run: ${err.message}`), {
+                code: (err as NodeJS.ErrnoException).code,
+            }));
         });
     });
 }
 
 /**
- * Recursively collect VPK files from a directory
+ * Recursively collect VPK files from a directory, plus auxiliary files whose
+ * extension is in `wanted`. Every other file is reported as skipped, relative
+ * to `dir` with forward slashes.
  */
-function collectVpks(dir: string): string[] {
+function collectVpks(
+    dir: string,
+    wanted: Set<string> = new Set()
+): { vpks: string[]; skipped: string[]; extras: string[] } {
     const vpks: string[] = [];
+    const skipped: string[] = [];
+    const extras: string[] = [];
 
     function walk(currentDir: string): void {
         if (!existsSync(currentDir)) return;
@@ -361,12 +817,16 @@ function collectVpks(dir: string): string[] {
                 walk(fullPath);
             } else if (extname(entry.name).toLowerCase() === '.vpk') {
                 vpks.push(fullPath);
+            } else if (isWantedExtra(entry.name, wanted)) {
+                extras.push(fullPath);
+            } else {
+                skipped.push(relative(dir, fullPath).split(sep).join('/'));
             }
         }
     }
 
     walk(dir);
-    return vpks;
+    return { vpks, skipped, extras };
 }
 
 /**
@@ -379,9 +839,11 @@ function copyVpksToDest(vpks: string[], destDir: string, rootDir: string): Extra
     const taken = new Set<string>();
 
     for (const vpk of vpks) {
-        const fileName = basename(vpk);
-        const destPath = join(destDir, uniqueDestName(fileName, taken));
-        copyFileSync(vpk, destPath);
+        // The 7z CLI on Linux can leave a Windows entry's backslashes inside
+        // the on-disk name; split them like any other entry name.
+        const fileName = archiveEntryFileName(vpk);
+        const destPath = join(destDir, uniqueDestName(toWindowsSafeFileName(fileName), taken));
+        copyFileSync(toLongPath(vpk), toLongPath(destPath));
         const parent = dirname(vpk);
         copied.push({
             path: destPath,
@@ -393,6 +855,22 @@ function copyVpksToDest(vpks: string[], destDir: string, rootDir: string): Extra
     return copied;
 }
 
+/**
+ * Copy auxiliary files into destDir's extras folder, keeping their path
+ * relative to the extraction root. Returns those relative paths.
+ */
+function copyExtrasToDest(extras: string[], destDir: string, rootDir: string): string[] {
+    const copied: string[] = [];
+    for (const file of extras) {
+        const rel = relative(rootDir, file).split(sep).map(toWindowsSafeFileName).join('/');
+        const destPath = join(destDir, EXTRAS_DIR_NAME, ...rel.split('/'));
+        mkdirSync(toLongPath(dirname(destPath)), { recursive: true });
+        copyFileSync(toLongPath(file), toLongPath(destPath));
+        copied.push(rel);
+    }
+    return copied;
+}
+
 /**
  * Create a temporary directory with cryptographically secure random name
  * (P0 security fix #3 - prevents race condition attacks)
@@ -480,3 +958,84 @@ export async function listArchiveContents(archivePath: string): Promise<string[]
 
     return tryCandidate(0);
 }
+
+/** Compare archive entry names regardless of slash style or a leading `./`. */
+function normalizeEntryName(name: string): string {
+    return name.replace(/\\/g, '/').replace(/^\.\//, '');
+}
+
+/**
+ * Extract one named entry (as reported by listArchiveContents) from a zip, 7z
+ * or rar archive to `destPath`, without unpacking the rest. Zip and rar are
+ * read in-process first; all three fall back to the 7z binaries. Throws when
+ * the entry is missing or no extractor could read it.
+ */
+export async function extractSingleEntry(
+    archivePath: string,
+    entryName: string,
+    destPath: string
+): Promise<string> {
+    const ext = extname(archivePath).toLowerCase();
+    const wanted = normalizeEntryName(entryName);
+    if (!wanted || wanted.endsWith('/')) {
+        throw new Error(`Invalid archive entry: ${entryName}`);
+    }
+    mkdirSync(toLongPath(dirname(destPath)), { recursive: true });
+
+    if (ext === '.zip') {
+        let entries: ReturnType<AdmZip['getEntries']> | null = null;
+        try {
+            entries = new AdmZip(archivePath).getEntries();
+        } catch (err) {
+            console.warn('[extractSingleEntry] adm-zip could not read the archive, falling back to 7z:', err);
+        }
+        if (entries) {
+            // adm-zip read the central directory, so a miss is a bad entry
+            // name; 7z would only fail the same way with a vaguer error.
+            const entry = entries.find((e) => !e.isDirectory && normalizeEntryName(e.entryName) === wanted);
+            if (!entry) throw new Error(`Entry not found in archive: ${entryName}`);
+            try {
+                writeFileSync(toLongPath(destPath), entry.getData());
+                return destPath;
+            } catch (err) {
+                console.warn('[extractSingleEntry] adm-zip failed, falling back to 7z:', err);
+            }
+        }
+    } else if (ext === '.rar') {
+        try {
+            const data = readFileSync(archivePath);
+            const ab = data.buffer.slice(data.byteOffset, data.byteOffset + data.byteLength) as ArrayBuffer;
+            const extractor = await createExtractorFromData({ data: ab });
+            const extracted = extractor.extract({
+                files: (header) => !header.flags.directory && normalizeEntryName(header.name) === wanted,
+            });
+            for (const file of extracted.files) {
+                if (!file.extraction) continue;
+                writeFileSync(toLongPath(destPath), Buffer.from(file.extraction));
+                return destPath;
+            }
+        } catch (err) {
+            console.warn('[extractSingleEntry] node-unrar-js failed, falling back to 7z:', err);
+        }
+    } else if (ext !== '.7z') {
+        throw new Error(`Unknown archive format: ${ext}`);
+    }
+
+    // `e` flattens paths, so the entry lands in tempDir under its basename.
+    const tempDir = createTempDir('modmanager-entry');
+    try {
+        return await tryExtractionTools('7z', find7zPath(), async (tool) => {
+            await runCommand(tool, ['e', '-y', `-o${tempDir}`, archivePath, wanted]);
+            const out = join(tempDir, basename(wanted));
+            if (!existsSync(out)) throw new Error(`Entry not found in archive: ${entryName}`);
+            copyFileSync(toLongPath(out), toLongPath(destPath));
+            return destPath;
+        });
+    } finally {
+        try {
+            rmDirRecursive(tempDir);
+        } catch {
+            // Ignore cleanup errors
+        }
+    }
+}
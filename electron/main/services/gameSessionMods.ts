@@ -1,4 +1,5 @@
 import { isDeadlockRunning } from './launch';
+import { loadSettings } from './settings';
 import type { Mod } from './mods';
 
 export const GAME_RUNNING_MOD_LOCK_MESSAGE = 'Game is running';
@@ -74,6 +75,18 @@ export async function beginModMutationRunningScope(): Promise<void> {
     lockScopedRunning = await isDeadlockRunning();
 }
 
+/**
+ * With `blockModChangesWhileGameRunning` on, refuse every mod-folder mutation
+ * while Deadlock is running (or launching), not just moves of the mods it has
+ * loaded. Call inside the running scope so the state is already known.
+ */
+export function assertModChangesAllowed(): void {
+    const running = lockScopedRunning === true || (snapshot !== null && Date.now() < launchGraceUntil);
+    if (running && loadSettings().blockModChangesWhileGameRunning === true) {
+        throw new Error(GAME_RUNNING_MOD_LOCK_MESSAGE);
+    }
+}
+
 export function endModMutationRunningScope(): void {
     lockScopedRunning = undefined;
 }
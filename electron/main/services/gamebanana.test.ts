@@ -0,0 +1,184 @@
+/**
+ * Section handling for non-Mod GameBanana items (Wips, Sounds, Tools). The
+ * download flow used to lean on a 'Mod' default, so a grimoire:// link with a
+ * lowercase modType ("wip") or a Wip picked in Browse hit the wrong API path.
+ * fetch is stubbed to capture the request URL; electron, settings and the
+ * sqlite cache are mocked so this stays off the native graph.
+ */
+import { describe, it, expect, vi, afterEach } from 'vitest';
+import { createServer } from 'http';
+import type { AddressInfo } from 'net';
+
+vi.mock('electron', () => ({
+  app: { getVersion: () => '0.0.0' },
+  BrowserWindow: { getAllWindows: () => [] },
+}));
+vi.mock('./rateLimiter', () => ({ gamebananaRateLimiter: { acquire: vi.fn(async () => {}) } }));
+vi.mock('./modDatabase', () => ({ getCachedCategoryTree: vi.fn(), saveCachedCategoryTree: vi.fn() }));
+vi.mock('./settings', () => ({ assertOnline: vi.fn() }));
+
+import { getCachedCategoryTree } from './modDatabase';
+import { fetchModDetails, fetchSubmissions, fetchTopCategories, getGameBananaApiBase, normalizeGameBananaSection, GAMEBANANA_RATE_LIMITED_MESSAGE } from './gamebanana';
+
+describe('normalizeGameBananaSection', () => {
+  it('canonicalizes known models regardless of case and defaults to Mod', () => {
+    expect(normalizeGameBananaSection('wip')).toBe('Wip');
+    expect(normalizeGameBananaSection('Sound')).toBe('Sound');
+    expect(normalizeGameBananaSection(' tool ')).toBe('Tool');
+    expect(normalizeGameBananaSection(undefined)).toBe('Mod');
+    expect(normalizeGameBananaSection('')).toBe('Mod');
+  });
+
+  it('rejects anything that is not a bare model name', () => {
+    expect(() => normalizeGameBananaSection('Mod/../Member')).toThrow(/Unsupported/);
+  });
+});
+
+describe('fetchModDetails (non-Mod section)', () => {
+  afterEach(() => {
+    vi.unstubAllGlobals();
+  });
+
+  it('requests the Wip model path and returns its files', async () => {
+    const urls: string[] = [];
+    vi.stubGlobal('fetch', vi.fn(async (url: string) => {
+      urls.push(url);
+      return new Response(
+        JSON.stringify({
+          _idRow: 42,
+          _sName: 'Work In Progress Skin',
+          _aFiles: [{ _idRow: 7, _sFile: 'wip.zip', _nFilesize: 10, _sDownloadUrl: 'https://gamebanana.com/dl/7', _nDownloadCount: 0 }],
+        }),
+        { status: 200 }
+      );
+    }));
+
+    const details = await fetchModDetails(42, 'wip');
+    expect(urls[0]).toContain('/apiv11/Wip/42?');
+    expect(details.files?.map((f) => f.id)).toEqual([7]);
+  });
+});
+
+describe('fetchTopCategories', () => {
+  it('flattens the cached tree and ranks every level by item count', async () => {
+    vi.mocked(getCachedCategoryTree).mockReturnValue({
+      fetchedAt: Date.now(),
+      payload: JSON.stringify([
+        {
+          id: 1, name: 'Skins', itemCount: 300,
+          children: [
+            { id: 11, name: 'Haze', itemCount: 120 },
+            { id: 12, name: 'Abrams', itemCount: 40 },
+          ],
+        },
+        { id: 2, name: 'HUD', itemCount: 90 },
+      ]),
+    } as ReturnType<typeof getCachedCategoryTree>);
+
+    const top = await fetchTopCategories('ModCategory', 3);
+    expect(top.map((c) => c.name)).toEqual(['Skins', 'Haze', 'HUD']);
+    expect(top[0].children).toBeUndefined();
+  });
+});
+
+describe('fetchSubmissions (perPage above the API cap)', () => {
+  afterEach(() => {
+    vi.unstubAllGlobals();
+  });
+
+  it('stitches the covering API pages into one window of the requested size', async () => {
+    const pages: string[] = [];
+    vi.stubGlobal('fetch', vi.fn(async (url: string) => {
+      const params = new URL(url).searchParams;
+      const apiPage = Number(params.get('_nPage'));
+      pages.push(`${apiPage}:${params.get('_nPerpage')}`);
+      const records = Array.from({ length: 50 }, (_, i) => ({ _idRow: (apiPage - 1) * 50 + i + 1, _sName: 'm' }));
+      return new Response(
+        JSON.stringify({ _aRecords: records, _aMetadata: { _nRecordCount: 500, _bIsComplete: false, _nPerpage: 50 } }),
+        { status: 200 }
+      );
+    }));
+
+    // Page 2 of 75 is records 76..150, spread over API pages 2 and 3.
+    const response = await fetchSubmissions('Mod', 2, 75);
+    expect(pages).toEqual(['2:50', '3:50']);
+    expect(response.records.map((m) => m.id)).toEqual(Array.from({ length: 75 }, (_, i) => 76 + i));
+    expect(response.perPage).toBe(75);
+    expect(response.totalCount).toBe(500);
+  });
+});
+
+describe('GRIMOIRE_GAMEBANANA_API_BASE override', () => {
+  afterEach(() => {
+    delete process.env.GRIMOIRE_GAMEBANANA_API_BASE;
+  });
+
+  it('ignores values that are not http(s) URLs', () => {
+    process.env.GRIMOIRE_GAMEBANANA_API_BASE = 'file:///etc';
+    expect(getGameBananaApiBase()).toBe('https://gamebanana.com/apiv11');
+  });
+
+  it('sends API requests to a local mock server', async () => {
+    const paths: string[] = [];
+    const server = createServer((req, res) => {
+      paths.push(req.url ?? '');
+      res.setHeader('content-type', 'application/json');
+      res.end(JSON.stringify({
+        _idRow: 9001,
+        _sName: 'Mocked Skin',
+        _aFiles: [{ _idRow: 3, _sFile: 'mock.zip', _nFilesize: 1, _sDownloadUrl: 'https://gamebanana.com/dl/3', _nDownloadCount: 0 }],
+      }));
+    });
+    await new Promise<void>((resolve) => server.listen(0, '127.0.0.1', resolve));
+    try {
+      const { port } = server.address() as AddressInfo;
+      process.env.GRIMOIRE_GAMEBANANA_API_BASE = `http://127.0.0.1:${port}/apiv11/`;
+
+      const details = await fetchModDetails(9001, 'Mod');
+      expect(details.name).toBe('Mocked Skin');
+      expect(paths[0]).toMatch(/^\/apiv11\/Mod\/9001\?/);
+    } finally {
+      await new Promise<void>((resolve) => server.close(() => resolve()));
+    }
+  });
+});
+
+describe('rate-limit retries', () => {
+  afterEach(() => {
+    vi.useRealTimers();
+    vi.unstubAllGlobals();
+  });
+
+  it('backs off on 503 and succeeds once GameBanana recovers', async () => {
+    vi.useFakeTimers();
+    const fetchMock = vi.fn()
+      .mockResolvedValueOnce(new Response('busy', { status: 503 }))
+      .mockResolvedValueOnce(new Response('busy', { status: 429, headers: { 'retry-after': '2' } }))
+      .mockResolvedValueOnce(new Response(JSON.stringify({ _idRow: 5, _sName: 'Recovered', _aFiles: [] }), { status: 200 }));
+    vi.stubGlobal('fetch', fetchMock);
+
+    const pending = fetchModDetails(5);
+    await vi.runAllTimersAsync();
+    expect((await pending).name).toBe('Recovered');
+    expect(fetchMock).toHaveBeenCalledTimes(3);
+  });
+
+  it('gives up with the rate-limited message after the last attempt', async () => {
+    vi.useFakeTimers();
+    const fetchMock = vi.fn(async () => new Response('slow down', { status: 429 }));
+    vi.stubGlobal('fetch', fetchMock);
+
+    const assertion = expect(fetchModDetails(5)).rejects.toThrow(GAMEBANANA_RATE_LIMITED_MESSAGE);
+    await vi.runAllTimersAsync();
+    await assertion;
+    expect(fetchMock).toHaveBeenCalledTimes(4);
+  });
+
+  it('does not retry client errors', async () => {
+    const fetchMock = vi.fn(async () => new Response('missing', { status: 404, statusText: 'Not Found' }));
+    vi.stubGlobal('fetch', fetchMock);
+
+    await expect(fetchModDetails(5)).rejects.toThrow('GameBanana API error: 404');
+    expect(fetchMock).toHaveBeenCalledTimes(1);
+  });
+});
@@ -1,5 +1,6 @@
 import { BrowserWindow } from 'electron';
 import { gamebananaRateLimiter } from './rateLimiter';
+import { assertOnline } from './settings';
 import { GRIMOIRE_USER_AGENT } from './userAgent';
 import { getCachedCategoryTree, saveCachedCategoryTree } from './modDatabase';
 // The GameBanana wire types are single-sourced in src/types/gamebanana.ts
@@ -12,11 +13,13 @@ import type {
     GameBananaMod,
     GameBananaSubmitter,
     GameBananaArtistLink,
+    GameBananaSubmitterProfile,
     GameBananaPreviewMedia,
     GameBananaPreviewMetadata,
     GameBananaImage,
     GameBananaCategory,
     GameBananaModsResponse,
+    GameBananaNewModsResponse,
     GameBananaFile,
     GameBananaComment,
     GameBananaModUpdateChange,
@@ -29,17 +32,20 @@ import type {
     GameBananaModFileListEntry,
     GameBananaModFileList,
 } from '../../../src/types/gamebanana';
+import type { BrowseModsArgs, LastBrowseResult } from '../../../src/types/electron';
 export type {
     GameBananaSection,
     GameBananaCategoryNode,
     GameBananaMod,
     GameBananaSubmitter,
     GameBananaArtistLink,
+    GameBananaSubmitterProfile,
     GameBananaPreviewMedia,
     GameBananaPreviewMetadata,
     GameBananaImage,
     GameBananaCategory,
     GameBananaModsResponse,
+    GameBananaNewModsResponse,
     GameBananaFile,
     GameBananaComment,
     GameBananaModUpdateChange,
@@ -69,7 +75,28 @@ function notifyRateLimited(): void {
     }
 }
 
-const GAMEBANANA_API_BASE = 'https://gamebanana.com/apiv11';
+const DEFAULT_GAMEBANANA_API_BASE = 'https://gamebanana.com/apiv11';
+
+/**
+ * apiv11 base URL. GRIMOIRE_GAMEBANANA_API_BASE overrides it so the service
+ * can run against a local mock server in tests, or through a mirror/proxy on
+ * networks that block gamebanana.com. Read per request so tests can set it
+ * after import; anything that isn't an http(s) URL falls back to the default.
+ */
+export function getGameBananaApiBase(): string {
+    const override = process.env.GRIMOIRE_GAMEBANANA_API_BASE?.trim();
+    if (!override) return DEFAULT_GAMEBANANA_API_BASE;
+    try {
+        const parsed = new URL(override);
+        if (parsed.protocol === 'https:' || parsed.protocol === 'http:') {
+            return override.replace(/\/+$/, '');
+        }
+    } catch {
+        // Fall through to the default.
+    }
+    console.warn(`[GameBanana] Ignoring invalid GRIMOIRE_GAMEBANANA_API_BASE: ${override}`);
+    return DEFAULT_GAMEBANANA_API_BASE;
+}
 const GAMEBANANA_CORE_ITEM_DATA = 'https://api.gamebanana.com/Core/Item/Data';
 const DEADLOCK_GAME_ID = 20948;
 const CORE_ITEM_DATA_MAX_URL_LENGTH = 7_500;
@@ -224,6 +251,7 @@ interface ModDetailsRaw {
     _sName: string;
     _sText?: string;
     _bIsNsfw?: boolean;
+    _tsDateModified?: number;
     _aFiles?: FileRaw[];
     _aPreviewMedia?: ModRaw['_aPreviewMedia'];
     _aCategory?: ModRaw['_aRootCategory'];
@@ -280,12 +308,70 @@ interface CollectionItemsResponseRaw {
     };
 }
 
+// GameBanana item models that can carry downloadable Deadlock files. Keys are
+// lowercased so a grimoire:// modType like "wip" resolves to the real model.
+const KNOWN_SECTIONS: Record<string, string> = {
+    mod: 'Mod',
+    sound: 'Sound',
+    gui: 'Gui',
+    model: 'Model',
+    wip: 'Wip',
+    tool: 'Tool',
+    skin: 'Skin',
+    spray: 'Spray',
+};
+
+/**
+ * Canonical GameBanana model name for a section, defaulting to 'Mod'. The
+ * section is interpolated into API paths, so anything that isn't a plain
+ * alphabetic model name is rejected rather than passed through.
+ */
+export function normalizeGameBananaSection(section: string | undefined): string {
+    const trimmed = section?.trim();
+    if (!trimmed) return 'Mod';
+    const known = KNOWN_SECTIONS[trimmed.toLowerCase()];
+    if (known) return known;
+    if (/^[A-Za-z]+$/.test(trimmed)) return trimmed;
+    throw new Error(`Unsupported GameBanana section: ${trimmed}`);
+}
+
 /**
  * Helper to fetch JSON from GameBanana API
  * Includes timeout (P1 fix #5) and rate limiting (P2 fix #13)
  */
 interface GameBananaRequestOptions {
     signal?: AbortSignal;
+    /** Tries before a 429/5xx becomes an error. Defaults to GAMEBANANA_MAX_ATTEMPTS. */
+    maxAttempts?: number;
+}
+
+/** Requests answered 429 or 5xx are retried this many times in total. */
+const GAMEBANANA_MAX_ATTEMPTS = 4;
+/** First backoff delay; doubles per attempt up to GAMEBANANA_MAX_BACKOFF_MS. */
+const GAMEBANANA_BACKOFF_BASE_MS = 1_000;
+const GAMEBANANA_MAX_BACKOFF_MS = 16_000;
+/** A Retry-After longer than this isn't waited out; the request fails instead. */
+const GAMEBANANA_MAX_RETRY_AFTER_MS = 60_000;
+
+/**
+ * Thrown once GameBanana is still answering 429/503 after every retry, so the
+ * UI can show a calm "try later" rather than a raw API error. Keep the
+ * message stable: it's what reaches the renderer over IPC.
+ */
+export const GAMEBANANA_RATE_LIMITED_MESSAGE =
+    'GameBanana is rate-limiting requests right now. Wait a minute, then try again.';
+
+export class GameBananaRateLimitError extends Error {
+    constructor() {
+        super(GAMEBANANA_RATE_LIMITED_MESSAGE);
+        this.name = 'GameBananaRateLimitError';
+    }
+}
+
+/** Exponential backoff delay before retry number `attempt` (0-based), honouring Retry-After. */
+function gameBananaRetryDelayMs(attempt: number, retryAfterMs: number | null): number {
+    if (retryAfterMs !== null) return retryAfterMs;
+    return Math.min(GAMEBANANA_BACKOFF_BASE_MS * 2 ** attempt, GAMEBANANA_MAX_BACKOFF_MS);
 }
 
 function throwIfAborted(signal?: AbortSignal): void {
@@ -316,7 +402,11 @@ function createTimeoutSignal(timeoutMs: number, externalSignal?: AbortSignal): {
 }
 
 async function fetchJson<T>(url: string, timeoutMs = 30000, options: GameBananaRequestOptions = {}): Promise<T> {
-    for (let attempt = 0; attempt < 2; attempt++) {
+    // Backstop for callers outside the IPC layer (profile resolve, one-click
+    // installs, unknown-mod matching); the handlers check up front.
+    assertOnline();
+    const maxAttempts = Math.max(1, options.maxAttempts ?? GAMEBANANA_MAX_ATTEMPTS);
+    for (let attempt = 0; attempt < maxAttempts; attempt++) {
         throwIfAborted(options.signal);
         await gamebananaRateLimiter.acquire();
         throwIfAborted(options.signal);
@@ -335,12 +425,22 @@ async function fetchJson<T>(url: string, timeoutMs = 30000, options: GameBananaR
                 if (response.status === 429) {
                     notifyRateLimited();
                 }
+                // 429 and 503 are GameBanana shedding load; other 5xx are
+                // usually transient too. Everything else fails immediately.
+                const rateLimited = response.status === 429 || response.status === 503;
+                const retryable = rateLimited || response.status >= 500;
                 const retryAfterMs = parseRetryAfterMs(response.headers.get('retry-after'));
-                if (response.status === 429 && retryAfterMs !== null && retryAfterMs <= 60_000 && attempt === 0) {
+                const canWait = retryAfterMs === null || retryAfterMs <= GAMEBANANA_MAX_RETRY_AFTER_MS;
+                if (retryable && canWait && attempt < maxAttempts - 1) {
+                    const delayMs = gameBananaRetryDelayMs(attempt, retryAfterMs);
+                    debugGameBanana(`[fetchJson] ${response.status}, retrying in ${delayMs}ms (attempt ${attempt + 1}/${maxAttempts})`);
                     request.cleanup();
-                    await delayWithAbort(retryAfterMs, options.signal);
+                    await delayWithAbort(delayMs, options.signal);
                     continue;
                 }
+                if (rateLimited) {
+                    throw new GameBananaRateLimitError();
+                }
                 throw new Error(`GameBanana API error: ${response.status} ${response.statusText}`);
             }
 
@@ -491,7 +591,7 @@ export async function fetchModComments(
     page = 1,
     perPage = 15
 ): Promise<{ comments: GameBananaComment[]; totalCount: number }> {
-    const url = `${GAMEBANANA_API_BASE}/${section}/${modId}/Posts?_nPerpage=${perPage}&_nPage=${page}`;
+    const url = `${getGameBananaApiBase()}/${normalizeGameBananaSection(section)}/${modId}/Posts?_nPerpage=${perPage}&_nPage=${page}`;
     const raw = await fetchJson<PostsResponseRaw>(url);
 
     return {
@@ -520,7 +620,7 @@ export async function fetchModUpdates(
     page = 1,
     perPage = 5
 ): Promise<GameBananaModUpdatesResponse> {
-    const url = `${GAMEBANANA_API_BASE}/${section}/${modId}/Updates?_nPerpage=${perPage}&_nPage=${page}`;
+    const url = `${getGameBananaApiBase()}/${normalizeGameBananaSection(section)}/${modId}/Updates?_nPerpage=${perPage}&_nPage=${page}`;
     const raw = await fetchJson<UpdatesResponseRaw | UpdateRaw[]>(url);
     const records = Array.isArray(raw) ? raw : raw._aRecords ?? [];
 
@@ -547,7 +647,7 @@ export async function fetchModUpdates(
  */
 export async function fetchSections(): Promise<GameBananaSection[]> {
     // Rust: /Game/{id}/CategoryTree
-    const url = `${GAMEBANANA_API_BASE}/Game/${DEADLOCK_GAME_ID}/CategoryTree`;
+    const url = `${getGameBananaApiBase()}/Game/${DEADLOCK_GAME_ID}/CategoryTree`;
     debugGameBanana('[fetchSections] URL:', url);
     const raw = await fetchJson<SectionRaw[] | Record<string, SectionRaw>>(url);
     debugGameBanana('[fetchSections] Response type:', typeof raw, Array.isArray(raw));
@@ -565,7 +665,7 @@ export async function fetchCategoryTree(
     options: GameBananaRequestOptions = {}
 ): Promise<GameBananaCategoryNode[]> {
     // Rust: /Util/{model}/NestedStructure?_idGameRow={id}
-    const url = `${GAMEBANANA_API_BASE}/Util/${categoryModel}/NestedStructure?_idGameRow=${DEADLOCK_GAME_ID}`;
+    const url = `${getGameBananaApiBase()}/Util/${categoryModel}/NestedStructure?_idGameRow=${DEADLOCK_GAME_ID}`;
     debugGameBanana('[fetchCategoryTree] URL:', url);
     const raw = await fetchJson<CategoryNodeRaw[] | Record<string, CategoryNodeRaw>>(url, 30000, options);
 
@@ -642,7 +742,37 @@ async function refreshCategoryCache(categoryModel: string): Promise<void> {
 }
 
 /**
- * Fetch mods from GameBanana
+ * The `limit` categories with the most items, flattened out of the cached
+ * tree (children dropped), for a "where the content is" sidebar. Parents
+ * count their descendants on GameBanana, so both levels compete on one list.
+ */
+export async function fetchTopCategories(
+    categoryModel: string,
+    limit = 10
+): Promise<GameBananaCategoryNode[]> {
+    const flat: GameBananaCategoryNode[] = [];
+    const walk = (nodes: GameBananaCategoryNode[]) => {
+        for (const { children, ...node } of nodes) {
+            flat.push(node);
+            if (children) walk(children);
+        }
+    };
+    walk(await fetchCategoryTreeCached(categoryModel));
+    return flat
+        .sort((a, b) => b.itemCount - a.itemCount || a.name.localeCompare(b.name))
+        .slice(0, Math.max(0, limit));
+}
+
+/** apiv11 list/search endpoints silently clamp _nPerpage to this. */
+export const GAMEBANANA_MAX_PER_PAGE = 50;
+
+/**
+ * Fetch mods from GameBanana. A `perPage` above GAMEBANANA_MAX_PER_PAGE is
+ * served by fetching the covering API pages one after another (through the
+ * usual rate limiter) and slicing out the requested window, so callers get
+ * exactly `perPage` records per page whatever the API cap. That costs
+ * ceil(perPage / 50) requests per page, plus one when the window isn't
+ * aligned to the API's page boundaries.
  */
 export async function fetchSubmissions(
     model: string,
@@ -653,6 +783,46 @@ export async function fetchSubmissions(
     sort?: string,
     submitterId?: number,
     options: GameBananaRequestOptions = {}
+): Promise<GameBananaModsResponse> {
+    const size = Math.max(1, Math.floor(perPage));
+    const fetchPage = (apiPage: number, apiPerPage: number) =>
+        fetchSubmissionsPage(model, apiPage, apiPerPage, search, categoryId, sort, submitterId, options);
+    if (size <= GAMEBANANA_MAX_PER_PAGE) {
+        return fetchPage(page, size);
+    }
+
+    const start = (Math.max(1, page) - 1) * size;
+    const firstApiPage = Math.floor(start / GAMEBANANA_MAX_PER_PAGE) + 1;
+    const lastApiPage = Math.floor((start + size - 1) / GAMEBANANA_MAX_PER_PAGE) + 1;
+    const records: GameBananaMod[] = [];
+    let first: GameBananaModsResponse | null = null;
+    let last: GameBananaModsResponse | null = null;
+    for (let apiPage = firstApiPage; apiPage <= lastApiPage; apiPage++) {
+        last = await fetchPage(apiPage, GAMEBANANA_MAX_PER_PAGE);
+        first ??= last;
+        records.push(...last.records);
+        // A short page is the end of the listing.
+        if (last.records.length < GAMEBANANA_MAX_PER_PAGE) break;
+    }
+
+    const offset = start - (firstApiPage - 1) * GAMEBANANA_MAX_PER_PAGE;
+    return {
+        records: records.slice(offset, offset + size),
+        totalCount: first?.totalCount ?? 0,
+        isComplete: last?.isComplete ?? true,
+        perPage: size,
+    };
+}
+
+async function fetchSubmissionsPage(
+    model: string,
+    page: number,
+    perPage: number,
+    search: string | undefined,
+    categoryId: number | undefined,
+    sort: string | undefined,
+    submitterId: number | undefined,
+    options: GameBananaRequestOptions
 ): Promise<GameBananaModsResponse> {
     let url: string;
     // GameBanana's default list order (no _sSort) is by date *modified*, not date
@@ -696,7 +866,7 @@ export async function fetchSubmissions(
             params.set('_sSort', sortMap[sort]);
         }
 
-        url = `${GAMEBANANA_API_BASE}/Util/Search/Results?${params.toString()}`;
+        url = `${getGameBananaApiBase()}/Util/Search/Results?${params.toString()}`;
     } else {
         const params = new URLSearchParams();
         // Some endpoints ignore Generic_Game without an explicit game id.
@@ -718,7 +888,7 @@ export async function fetchSubmissions(
             params.set('_sSort', sortMap[sort]);
         }
 
-        url = `${GAMEBANANA_API_BASE}/${model}/Index?${params.toString()}`;
+        url = `${getGameBananaApiBase()}/${model}/Index?${params.toString()}`;
     }
 
     debugGameBanana('[fetchSubmissions] URL:', url);
@@ -737,6 +907,175 @@ export async function fetchSubmissions(
     };
 }
 
+// GameBanana's apiv11 has no stable "featured" feed for a single game, so the
+// home-page list is approximated: pull a wide page of recently updated mods and
+// rank it by likes. Cached in memory so reopening the home screen is instant.
+const FEATURED_POOL_SIZE = 50;
+const FEATURED_CACHE_MS = 30 * 60 * 1000;
+let featuredCache: { fetchedAt: number; response: GameBananaModsResponse } | null = null;
+let featuredInFlight: Promise<GameBananaModsResponse> | null = null;
+
+/**
+ * Curated "trending" Deadlock mods for the home screen: recently updated,
+ * ranked by like count. Stale cache is served if the refresh fails.
+ */
+export async function fetchFeaturedMods(limit = 20): Promise<GameBananaModsResponse> {
+    const count = Math.max(1, Math.min(Math.floor(limit) || 20, FEATURED_POOL_SIZE));
+    const slice = (response: GameBananaModsResponse): GameBananaModsResponse => ({
+        ...response,
+        records: response.records.slice(0, count),
+        totalCount: Math.min(response.records.length, count),
+        perPage: count,
+    });
+
+    if (featuredCache && Date.now() - featuredCache.fetchedAt < FEATURED_CACHE_MS) {
+        return slice(featuredCache.response);
+    }
+
+    if (!featuredInFlight) {
+        featuredInFlight = (async () => {
+            const pool = await fetchSubmissions('Mod', 1, FEATURED_POOL_SIZE, undefined, undefined, 'updated');
+            const records = pool.records
+                .filter((mod) => mod.hasFiles && !mod.nsfw)
+                .sort((a, b) => b.likeCount - a.likeCount || b.dateModified - a.dateModified);
+            const response: GameBananaModsResponse = {
+                records,
+                totalCount: records.length,
+                isComplete: true,
+                perPage: records.length,
+            };
+            featuredCache = { fetchedAt: Date.now(), response };
+            return response;
+        })().finally(() => {
+            featuredInFlight = null;
+        });
+    }
+
+    try {
+        return slice(await featuredInFlight);
+    } catch (err) {
+        if (featuredCache) {
+            debugGameBanana('[fetchFeaturedMods] refresh failed, serving stale list:', err);
+            return slice(featuredCache.response);
+        }
+        throw err;
+    }
+}
+
+/** Pages fetch-new-mods-since reads at most, so a long absence costs a
+ *  bounded number of requests instead of walking the whole listing. */
+const NEW_MODS_MAX_PAGES = 5;
+
+/**
+ * Submissions in `model` added after `since` (unix seconds), newest first.
+ * Pages through the date-added listing and stops at the first record at or
+ * before the cutoff, at a short page, or at NEW_MODS_MAX_PAGES.
+ */
+export async function fetchNewModsSince(model: string, since: number): Promise<GameBananaNewModsResponse> {
+    const checkedAt = Math.floor(Date.now() / 1000);
+    const records: GameBananaMod[] = [];
+    let truncated = true;
+    for (let page = 1; page <= NEW_MODS_MAX_PAGES; page++) {
+        const response = await fetchSubmissionsPage(
+            model, page, GAMEBANANA_MAX_PER_PAGE, undefined, undefined, 'recent', undefined, {}
+        );
+        const fresh = response.records.filter((mod) => mod.dateAdded > since);
+        records.push(...fresh);
+        if (fresh.length < response.records.length || response.records.length < GAMEBANANA_MAX_PER_PAGE) {
+            truncated = false;
+            break;
+        }
+    }
+    return { records, since, checkedAt, truncated };
+}
+
+// Last Browse results per query, so a remounted Browse page can restore what
+// it had (every loaded page) instead of re-requesting page 1 onward in a burst.
+// Keyed by every browse param except page; oldest query evicted first.
+const LAST_BROWSE_TTL_MS = 10 * 60 * 1000;
+const LAST_BROWSE_MAX_QUERIES = 20;
+const lastBrowseResults = new Map<string, { fetchedAt: number; pages: Map<number, GameBananaModsResponse> }>();
+
+function browseQueryKey(args: Omit<BrowseModsArgs, 'page'>): string {
+    return JSON.stringify([
+        args.section ?? 'Mod',
+        args.perPage,
+        args.search?.trim() || null,
+        args.categoryId ?? null,
+        args.sort ?? null,
+        args.submitterId ?? null,
+    ]);
+}
+
+/** Record a successful browse-mods page for getLastBrowseResult. */
+export function rememberBrowseResult(args: BrowseModsArgs, response: GameBananaModsResponse): void {
+    const key = browseQueryKey(args);
+    const existing = lastBrowseResults.get(key);
+    // Page 1 means the query was (re)started, so older pages are stale.
+    const pages = existing && args.page > 1 ? existing.pages : new Map<number, GameBananaModsResponse>();
+    pages.set(args.page, response);
+    lastBrowseResults.delete(key);
+    lastBrowseResults.set(key, { fetchedAt: Date.now(), pages });
+    while (lastBrowseResults.size > LAST_BROWSE_MAX_QUERIES) {
+        const oldest = lastBrowseResults.keys().next().value;
+        if (oldest === undefined) break;
+        lastBrowseResults.delete(oldest);
+    }
+}
+
+// Short-lived response cache for browse-mods pages, so paging back and forth
+// doesn't re-request pages GameBanana just served (a big share of the 429s).
+// LRU by insertion order: a hit moves the entry to the back.
+const SUBMISSIONS_CACHE_TTL_MS = 60 * 1000;
+const SUBMISSIONS_CACHE_MAX_ENTRIES = 50;
+const submissionsCache = new Map<string, { fetchedAt: number; response: GameBananaModsResponse }>();
+
+/**
+ * fetchSubmissions for the Browse grid, answered from the response cache when
+ * the same page of the same query was fetched in the last minute. Other
+ * callers (sync, unknown-mod matching) keep calling fetchSubmissions directly
+ * so they always see live data.
+ */
+export async function fetchSubmissionsCached(args: BrowseModsArgs): Promise<GameBananaModsResponse> {
+    const key = `${browseQueryKey(args)}#${args.page}`;
+    const cached = submissionsCache.get(key);
+    submissionsCache.delete(key);
+    if (cached && Date.now() - cached.fetchedAt < SUBMISSIONS_CACHE_TTL_MS) {
+        submissionsCache.set(key, cached);
+        return cached.response;
+    }
+
+    const { page, perPage, search, section = 'Mod', categoryId, sort, submitterId } = args;
+    const response = await fetchSubmissions(section, page, perPage, search, categoryId, sort, submitterId);
+    submissionsCache.set(key, { fetchedAt: Date.now(), response });
+    while (submissionsCache.size > SUBMISSIONS_CACHE_MAX_ENTRIES) {
+        const oldest = submissionsCache.keys().next().value;
+        if (oldest === undefined) break;
+        submissionsCache.delete(oldest);
+    }
+    return response;
+}
+
+/** Forget cached and remembered Browse pages, so a manual refresh hits the network. */
+export function clearBrowseCache(): void {
+    submissionsCache.clear();
+    lastBrowseResults.clear();
+}
+
+/**
+ * The pages already loaded for this query (1..n, stopping at the first gap),
+ * or null when there are none or they're older than LAST_BROWSE_TTL_MS.
+ */
+export function getLastBrowseResult(args: Omit<BrowseModsArgs, 'page'>): LastBrowseResult | null {
+    const entry = lastBrowseResults.get(browseQueryKey(args));
+    if (!entry || Date.now() - entry.fetchedAt > LAST_BROWSE_TTL_MS) return null;
+    const pages: GameBananaModsResponse[] = [];
+    for (let page = 1; entry.pages.has(page); page++) {
+        pages.push(entry.pages.get(page)!);
+    }
+    return pages.length > 0 ? { pages, fetchedAt: entry.fetchedAt } : null;
+}
+
 /**
  * Fetch mod details including files
  */
@@ -751,6 +1090,7 @@ export async function fetchModDetails(
         '_sName',
         '_sText',
         '_bIsNsfw',
+        '_tsDateModified',
         '_aCategory',
         '_aFiles',
         '_aPreviewMedia',
@@ -759,7 +1099,7 @@ export async function fetchModDetails(
         fields.push('_aSubmitter');
     }
     const params = new URLSearchParams({ _csvProperties: fields.join(',') });
-    const url = `${GAMEBANANA_API_BASE}/${section}/${modId}?${params.toString()}`;
+    const url = `${getGameBananaApiBase()}/${normalizeGameBananaSection(section)}/${modId}?${params.toString()}`;
     debugGameBanana('[fetchModDetails] URL:', url);
     const raw = await fetchJson<ModDetailsRaw>(url);
 
@@ -768,6 +1108,7 @@ export async function fetchModDetails(
         name: raw._sName,
         description: raw._sText,
         nsfw: raw._bIsNsfw ?? false,
+        dateModified: raw._tsDateModified,
         category: raw._aCategory
             ? {
                 id: raw._aCategory._idRow,
@@ -786,6 +1127,7 @@ export async function fetchModDetails(
             dateAdded: f._tsDateAdded,
             description: f._sDescription,
             isArchived: f._bIsArchived ?? false,
+            md5: f._sMd5Checksum,
         })),
         previewMedia: raw._aPreviewMedia
             ? {
@@ -819,7 +1161,7 @@ export async function fetchModFileList(
     modId: number,
     section = 'Mod'
 ): Promise<GameBananaModFileList> {
-    const url = `${GAMEBANANA_API_BASE}/${section}/${modId}?_csvProperties=_idRow,_aFiles`;
+    const url = `${getGameBananaApiBase()}/${normalizeGameBananaSection(section)}/${modId}?_csvProperties=_idRow,_aFiles`;
     const raw = await fetchJson<ModFileListRaw>(url);
     return {
         id: raw._idRow,
@@ -1001,6 +1343,12 @@ interface ContactInfoRaw {
 }
 
 interface ProfilePageRaw {
+    _idRow?: number;
+    _sName?: string;
+    _sProfileUrl?: string;
+    _sAvatarUrl?: string;
+    _sUserTitle?: string;
+    _tsJoinDate?: number;
     _bIsPrivate?: boolean;
     _aContactInfo?: ContactInfoRaw[];
 }
@@ -1024,7 +1372,7 @@ export async function fetchSubmitterLinks(memberId: number): Promise<GameBananaA
 
     let links: GameBananaArtistLink[] = [];
     try {
-        const raw = await fetchJson<ProfilePageRaw>(`${GAMEBANANA_API_BASE}/Member/${memberId}/ProfilePage`);
+        const raw = await fetchJson<ProfilePageRaw>(`${getGameBananaApiBase()}/Member/${memberId}/ProfilePage`);
         if (!raw._bIsPrivate) {
             links = mapContactInfo(raw._aContactInfo);
         }
@@ -1039,6 +1387,44 @@ export async function fetchSubmitterLinks(memberId: number): Promise<GameBananaA
     return links;
 }
 
+const SUBMITTER_PROFILE_PER_PAGE = 20;
+
+/**
+ * An author's profile (name, avatar, title, contact links) together with the
+ * first page of their submissions in `model` and the total count, from the
+ * same submitter filter Browse uses. Unlike fetchSubmitterLinks this is not
+ * best-effort: the author page has nothing to show without the profile.
+ */
+export async function fetchSubmitterProfile(memberId: number, model = 'Mod'): Promise<GameBananaSubmitterProfile> {
+    if (!Number.isInteger(memberId) || memberId <= 0) {
+        throw new Error(`Invalid submitter id: ${memberId}`);
+    }
+    const [raw, submissions] = await Promise.all([
+        fetchJson<ProfilePageRaw>(`${getGameBananaApiBase()}/Member/${memberId}/ProfilePage`),
+        fetchSubmissions(model, 1, SUBMITTER_PROFILE_PER_PAGE, undefined, undefined, 'recent', memberId),
+    ]);
+    const isPrivate = raw._bIsPrivate === true;
+    const links = isPrivate ? [] : mapContactInfo(raw._aContactInfo);
+    submitterLinksCache.set(memberId, links);
+    // A private profile can omit the name; any of their mods still carries it.
+    const fromMods = submissions.records.find((m) => m.submitter?.id === memberId)?.submitter;
+    return {
+        submitter: {
+            id: memberId,
+            name: raw._sName ?? fromMods?.name ?? `Member ${memberId}`,
+            avatarUrl: raw._sAvatarUrl ?? fromMods?.avatarUrl,
+            profileUrl: raw._sProfileUrl ?? fromMods?.profileUrl ?? `https://gamebanana.com/members/${memberId}`,
+            kofiUrl: fromMods?.kofiUrl,
+        },
+        title: raw._sUserTitle || undefined,
+        joinedAt: raw._tsJoinDate,
+        isPrivate,
+        links,
+        modCount: submissions.totalCount,
+        mods: submissions.records,
+    };
+}
+
 function mapContactInfo(items: ContactInfoRaw[] | undefined): GameBananaArtistLink[] {
     const out: GameBananaArtistLink[] = [];
     const seen = new Set<string>();
@@ -1182,7 +1568,7 @@ export function parseCollectionId(input: string): number | null {
  * Items live on a separate endpoint — see fetchCollectionItems.
  */
 export async function fetchCollection(collectionId: number): Promise<GameBananaCollection> {
-    const url = `${GAMEBANANA_API_BASE}/Collection/${collectionId}?_csvProperties=_idRow,_sName,_sDescription,_aSubmitter,_aPreviewMedia,_tsDateAdded,_tsDateModified`;
+    const url = `${getGameBananaApiBase()}/Collection/${collectionId}?_csvProperties=_idRow,_sName,_sDescription,_aSubmitter,_aPreviewMedia,_tsDateAdded,_tsDateModified`;
     console.log('[fetchCollection] URL:', url);
     const raw = await fetchJson<CollectionRaw>(url);
 
@@ -1206,7 +1592,7 @@ export async function fetchCollectionItems(
     collectionId: number,
     page = 1
 ): Promise<GameBananaCollectionItemsResponse> {
-    const url = `${GAMEBANANA_API_BASE}/Collection/${collectionId}/Items?_nPage=${page}`;
+    const url = `${getGameBananaApiBase()}/Collection/${collectionId}/Items?_nPage=${page}`;
     console.log('[fetchCollectionItems] URL:', url);
     const raw = await fetchJson<CollectionItemsResponseRaw>(url);
 
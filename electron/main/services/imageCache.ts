@@ -0,0 +1,143 @@
+/**
+ * Disk cache for remote GameBanana images (category icons, mod thumbnails).
+ *
+ * The category sidebar re-requested every icon on each mount. Images are
+ * fetched once, stored under userData/image-cache by a hash of their URL, and
+ * served back through the `grimoire-img:` scheme so the renderer can show them
+ * under the prod CSP without another network round trip. Regenerable, so the
+ * folder is part of the Settings "Local preview cache".
+ */
+import { promises as fs } from 'fs';
+import { createHash } from 'crypto';
+import { join, extname } from 'path';
+import { pathToFileURL } from 'url';
+import { protocol, net } from 'electron';
+import { gamebananaRateLimiter } from './rateLimiter';
+import { GRIMOIRE_USER_AGENT } from './userAgent';
+import { getUserDataPath } from '../utils/paths';
+import type { CachedImage } from '../../../src/types/electron';
+
+export const IMAGE_CACHE_SCHEME = 'grimoire-img';
+
+/** Folder name under userData; listed in previewCache.ts. */
+export const IMAGE_CACHE_DIR = 'image-cache';
+
+const IMAGE_EXTENSIONS = new Set(['.png', '.jpg', '.jpeg', '.webp', '.gif']);
+const MAX_IMAGE_BYTES = 5 * 1024 * 1024;
+const FETCH_TIMEOUT_MS = 15000;
+
+// One download per URL even when a whole sidebar asks at once.
+const inFlight = new Map<string, Promise<CachedImage>>();
+
+function cacheRoot(): string {
+    return join(getUserDataPath(), IMAGE_CACHE_DIR);
+}
+
+/** Only GameBanana's https image hosts are fetched; anything else is refused. */
+function validateImageUrl(url: string): URL {
+    let parsed: URL;
+    try {
+        parsed = new URL(url);
+    } catch {
+        throw new Error(`Invalid image URL: ${url}`);
+    }
+    const host = parsed.hostname.toLowerCase();
+    if (parsed.protocol !== 'https:' || !(host === 'gamebanana.com' || host.endsWith('.gamebanana.com'))) {
+        throw new Error(`Refusing to cache image from ${parsed.host}`);
+    }
+    return parsed;
+}
+
+function cacheFileName(url: URL): string {
+    const ext = extname(url.pathname).toLowerCase();
+    const hash = createHash('sha256').update(url.toString()).digest('hex').slice(0, 32);
+    return `${hash}${IMAGE_EXTENSIONS.has(ext) ? ext : '.img'}`;
+}
+
+function toCachedImage(fileName: string): CachedImage {
+    return {
+        path: join(cacheRoot(), fileName),
+        url: `${IMAGE_CACHE_SCHEME}://i/${encodeURIComponent(fileName)}`,
+    };
+}
+
+async function downloadImage(url: URL, fileName: string): Promise<CachedImage> {
+    const cached = toCachedImage(fileName);
+    await gamebananaRateLimiter.acquire();
+    const response = await fetch(url, {
+        headers: { 'User-Agent': GRIMOIRE_USER_AGENT },
+        signal: AbortSignal.timeout(FETCH_TIMEOUT_MS),
+    });
+    if (!response.ok) {
+        throw new Error(`Image request failed: ${response.status} ${response.statusText}`);
+    }
+    const contentType = response.headers.get('content-type') ?? '';
+    if (!contentType.startsWith('image/')) {
+        throw new Error(`Not an image (${contentType || 'no content type'}): ${url}`);
+    }
+    const data = Buffer.from(await response.arrayBuffer());
+    if (data.length === 0 || data.length > MAX_IMAGE_BYTES) {
+        throw new Error(`Image has an unexpected size (${data.length} bytes): ${url}`);
+    }
+    await fs.mkdir(cacheRoot(), { recursive: true });
+    const tempPath = `${cached.path}.${process.pid}.tmp`;
+    await fs.writeFile(tempPath, data);
+    await fs.rename(tempPath, cached.path);
+    return cached;
+}
+
+/**
+ * The local copy of a GameBanana image when it is already on disk, else null.
+ * Never touches the network, so offline mode can still serve it.
+ */
+export async function findCachedImage(url: string): Promise<CachedImage | null> {
+    const cached = toCachedImage(cacheFileName(validateImageUrl(url)));
+    try {
+        await fs.access(cached.path);
+        return cached;
+    } catch {
+        return null;
+    }
+}
+
+/**
+ * Local copy of a GameBanana image, downloading it on the first request.
+ * Returns the on-disk path plus a `grimoire-img:` URL for <img> tags.
+ */
+export async function getCachedImage(url: string): Promise<CachedImage> {
+    const existing = await findCachedImage(url);
+    if (existing) return existing;
+
+    const parsed = validateImageUrl(url);
+    const fileName = cacheFileName(parsed);
+    const key = parsed.toString();
+    const pending = inFlight.get(key);
+    if (pending) return pending;
+    const download = downloadImage(parsed, fileName).finally(() => inFlight.delete(key));
+    inFlight.set(key, download);
+    return download;
+}
+
+/**
+ * Register the `grimoire-img:` scheme handler. URLs look like
+ * `grimoire-img://i/<file>`, where `<file>` is a cache file name. Must be
+ * paired with a registerSchemesAsPrivileged call before app-ready (done in
+ * index.ts). Mirrors registerFoundryThumbnailProtocol.
+ */
+export function registerImageCacheProtocol(): void {
+    protocol.handle(IMAGE_CACHE_SCHEME, async (request) => {
+        try {
+            const url = new URL(request.url);
+            const segments = url.pathname.split('/').filter(Boolean).map(decodeURIComponent);
+            if (segments.length !== 1) return new Response(null, { status: 404 });
+            const [file] = segments;
+            // Cache names are hash + extension; reject anything else outright.
+            if (!/^[a-f0-9]{32}\.[a-z]+$/.test(file)) return new Response(null, { status: 400 });
+            const filePath = join(cacheRoot(), file);
+            await fs.access(filePath);
+            return net.fetch(pathToFileURL(filePath).toString());
+        } catch {
+            return new Response(null, { status: 404 });
+        }
+    });
+}
@@ -0,0 +1,157 @@
+import { existsSync, readFileSync, unlinkSync, statSync } from 'fs';
+import { join, resolve, basename } from 'path';
+import { writeFileAtomicSync } from '../utils/atomicWrite';
+import { getUserDataPath } from '../utils/paths';
+import { metaKeyFor } from './deadlock';
+import { scanMods, deleteMod } from './mods';
+import { checkVpkHeader } from './vpk';
+// IncompleteInstall is single-sourced in src/types/electron.ts; re-exported
+// for the IPC layer.
+import type { IncompleteInstall } from '../../../src/types/electron';
+export type { IncompleteInstall };
+
+/**
+ * Install journal: every VPK move into the disabled folder is recorded before
+ * it starts and cleared once it lands. A cross-device move falls back to a
+ * copy, so a crash or suspend (common on the Deck) mid-copy leaves a truncated
+ * VPK that scanMods then lists as a broken mod. Entries that survive into a
+ * later session mark exactly those interrupted writes.
+ */
+
+interface JournalEntry {
+    path: string;
+    startedAt: number;
+    sessionId: string;
+}
+
+// Entries written by this process are in-flight installs, not interrupted ones.
+const SESSION_ID = `${process.pid}-${Date.now()}`;
+
+let journal: JournalEntry[] | null = null;
+
+function getJournalPath(): string {
+    return join(getUserDataPath(), 'install-journal.json');
+}
+
+function normalizeJournalPath(filePath: string): string {
+    const normalized = resolve(filePath);
+    return process.platform === 'win32' ? normalized.toLowerCase() : normalized;
+}
+
+function loadJournal(): JournalEntry[] {
+    if (journal) return journal;
+    try {
+        const path = getJournalPath();
+        const raw = existsSync(path) ? JSON.parse(readFileSync(path, 'utf-8')) : [];
+        journal = Array.isArray(raw) ? (raw as JournalEntry[]) : [];
+    } catch (err) {
+        console.warn('[installJournal] Failed to read journal, starting empty:', err);
+        journal = [];
+    }
+    return journal;
+}
+
+function saveJournal(entries: JournalEntry[]): void {
+    journal = entries;
+    try {
+        writeFileAtomicSync(getJournalPath(), JSON.stringify(entries, null, 2));
+    } catch (err) {
+        // Best-effort: a journal write failure must never fail the install.
+        console.warn('[installJournal] Failed to write journal:', err);
+    }
+}
+
+/** Record that a VPK is about to be written to `destinationPath`. */
+export function beginInstallWrite(destinationPath: string): void {
+    const key = normalizeJournalPath(destinationPath);
+    const entries = loadJournal().filter((e) => normalizeJournalPath(e.path) !== key);
+    entries.push({ path: destinationPath, startedAt: Date.now(), sessionId: SESSION_ID });
+    saveJournal(entries);
+}
+
+/** Clear the journal entry once the VPK at `destinationPath` is fully written. */
+export function completeInstallWrite(destinationPath: string): void {
+    const key = normalizeJournalPath(destinationPath);
+    const entries = loadJournal();
+    const remaining = entries.filter((e) => normalizeJournalPath(e.path) !== key);
+    if (remaining.length !== entries.length) saveJournal(remaining);
+}
+
+/** Why a journaled VPK fails the header check, or null when it looks intact. */
+function checkJournaledVpk(filePath: string): IncompleteInstall['reason'] | null {
+    const problem = checkVpkHeader(filePath);
+    // An interrupted copy reads as truncated whether or not any bytes landed.
+    return problem === 'empty' ? 'truncated' : problem;
+}
+
+/**
+ * VPKs left behind by an install that was interrupted in an earlier session
+ * and that fail the magic-number/size check. Journal entries whose file is
+ * gone or turned out intact are pruned as a side effect.
+ */
+export function findIncompleteInstalls(): IncompleteInstall[] {
+    const entries = loadJournal();
+    const kept: JournalEntry[] = [];
+    const found: IncompleteInstall[] = [];
+
+    for (const entry of entries) {
+        if (entry.sessionId === SESSION_ID) {
+            kept.push(entry);
+            continue;
+        }
+        if (!existsSync(entry.path)) continue;
+        let reason: IncompleteInstall['reason'] | null;
+        try {
+            reason = checkJournaledVpk(entry.path);
+        } catch (err) {
+            console.warn(`[installJournal] Failed to inspect ${entry.path}:`, err);
+            kept.push(entry);
+            continue;
+        }
+        if (!reason) continue;
+        kept.push(entry);
+        found.push({
+            path: entry.path,
+            fileName: basename(entry.path),
+            metaKey: metaKeyFor(entry.path),
+            size: statSync(entry.path).size,
+            reason,
+            interruptedAt: new Date(entry.startedAt).toISOString(),
+        });
+    }
+
+    if (kept.length !== entries.length) saveJournal(kept);
+    return found;
+}
+
+/**
+ * Delete the given incomplete installs. Only paths currently reported by
+ * findIncompleteInstalls are touched, and deletion goes through deleteMod so
+ * it takes the mod mutation lock and drops the stale metadata entry.
+ */
+export async function cleanupIncompleteInstalls(
+    deadlockPath: string,
+    paths: string[]
+): Promise<number> {
+    const requested = new Set(paths.map(normalizeJournalPath));
+    const targets = findIncompleteInstalls().filter((i) => requested.has(normalizeJournalPath(i.path)));
+    if (targets.length === 0) return 0;
+
+    const mods = await scanMods(deadlockPath);
+    let removed = 0;
+    for (const target of targets) {
+        const mod = mods.find((m) => normalizeJournalPath(m.path) === normalizeJournalPath(target.path));
+        try {
+            if (mod) {
+                await deleteMod(deadlockPath, mod.id);
+            } else if (existsSync(target.path)) {
+                unlinkSync(target.path);
+            }
+            completeInstallWrite(target.path);
+            removed++;
+        } catch (err) {
+            console.warn(`[installJournal] Failed to remove ${target.fileName}:`, err);
+        }
+    }
+    return removed;
+}
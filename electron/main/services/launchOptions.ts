@@ -22,8 +22,8 @@
 
 import { promises as fs, existsSync } from 'fs';
 import { join } from 'path';
-import { homedir } from 'os';
 import { spawn } from 'child_process';
+import { getSteamInstallPaths } from './deadlock';
 
 export const DEADLOCK_STEAM_APP_ID = '1422450';
 
@@ -38,19 +38,7 @@ export interface LaunchOptionsLookup {
 
 /** Per-platform candidate locations for Steam's `userdata` root. */
 function getSteamUserdataRoots(): string[] {
-    const paths: string[] = [];
-    const home = homedir();
-    if (process.platform === 'linux') {
-        paths.push(join(home, '.steam/steam/userdata'));
-        paths.push(join(home, '.local/share/Steam/userdata'));
-        paths.push(join(home, '.var/app/com.valvesoftware.Steam/.steam/steam/userdata'));
-    } else if (process.platform === 'win32') {
-        paths.push('C:\\Program Files (x86)\\Steam\\userdata');
-        paths.push('C:\\Program Files\\Steam\\userdata');
-    } else if (process.platform === 'darwin') {
-        paths.push(join(home, 'Library/Application Support/Steam/userdata'));
-    }
-    return paths;
+    return getSteamInstallPaths().map((steamPath) => join(steamPath, 'userdata'));
 }
 
 /**
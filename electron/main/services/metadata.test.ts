@@ -0,0 +1,46 @@
+/**
+ * Forward-compat guard for the metadata sidecar. A newer build may add fields
+ * to ModMetadata; after a downgrade, the older build still rewrites the file on
+ * every set/rename, and those fields must come back out untouched. Entries are
+ * merged as plain objects rather than rebuilt from the known keys, which is
+ * what keeps them. Paths and settings are mocked onto a temp file so this stays
+ * off electron.
+ */
+import { describe, it, expect, vi } from 'vitest';
+import { mkdtempSync, readFileSync, writeFileSync } from 'fs';
+import { tmpdir } from 'os';
+import { join } from 'path';
+
+const h = vi.hoisted(() => ({ path: '' }));
+
+vi.mock('../utils/paths', () => ({ getMetadataPath: () => h.path }));
+vi.mock('./settings', () => ({ getActiveDeadlockPath: () => null }));
+vi.mock('./deadlock', () => ({
+  getAddonFolderPaths: vi.fn(),
+  getDisabledPath: vi.fn(),
+  metaKeyFor: vi.fn(),
+  QUARANTINE_META_PREFIX: 'quarantine:',
+  LIBRARY_META_PREFIX: 'library:',
+}));
+
+import { migrateModMetadata, setModMetadata } from './metadata';
+
+describe('metadata unknown fields', () => {
+  it('survive a set and a rename', () => {
+    h.path = join(mkdtempSync(join(tmpdir(), 'metadata-test-')), 'mod-metadata.json');
+    writeFileSync(
+      h.path,
+      JSON.stringify({
+        'pak01_dir.vpk': { modName: 'Old Name', futureField: { nested: [1, 2] }, futureFlag: true },
+      })
+    );
+
+    setModMetadata('pak01_dir.vpk', { modName: 'New Name' });
+    migrateModMetadata([{ from: 'pak01_dir.vpk', to: 'pak02_dir.vpk' }]);
+
+    const onDisk = JSON.parse(readFileSync(h.path, 'utf-8'));
+    expect(onDisk).toEqual({
+      'pak02_dir.vpk': { modName: 'New Name', futureField: { nested: [1, 2] }, futureFlag: true },
+    });
+  });
+});
@@ -1,9 +1,12 @@
 import { createHash } from 'crypto';
-import { createReadStream, readFileSync, writeFileSync, existsSync, renameSync, unlinkSync, statSync } from 'fs';
+import { createReadStream, readFileSync, existsSync, renameSync, statSync, mkdirSync, copyFileSync } from 'fs';
 import { promises as fs } from 'fs';
-import { join } from 'path';
-import { getAddonFolderPaths, getDisabledPath, metaKeyFor } from './deadlock';
+import { dirname, join } from 'path';
+import { getAddonFolderPaths, getDisabledPath, metaKeyFor, QUARANTINE_META_PREFIX, LIBRARY_META_PREFIX } from './deadlock';
+import { getActiveDeadlockPath } from './settings';
 import { getMetadataPath } from '../utils/paths';
+import { writeFileAtomicSync } from '../utils/atomicWrite';
+import type { MetadataRepair } from '../../../src/types/mod';
 
 export interface ModMetadata {
     modName?: string;      // The human-readable mod name from GameBanana
@@ -11,6 +14,7 @@ export interface ModMetadata {
     audioUrl?: string;     // GameBanana audio preview URL (Sound mods)
     gameBananaId?: number;
     gameBananaFileId?: number; // The specific file ID that was downloaded
+    remoteDateModified?: number; // GameBanana _tsDateModified (unix seconds) when this file was downloaded; check-mod-updates compares against it
     vpkIndex?: number;      // Size-sorted index inside a multi-VPK GameBanana file
     categoryId?: number;
     categoryName?: string; // Hero/category name from GameBanana
@@ -20,6 +24,7 @@ export interface ModMetadata {
     sha256?: string;       // SHA-256 hash of the installed VPK file contents
     variantLabel?: string;  // User-provided label to disambiguate variants of the same mod
     fileDescription?: string;  // GameBanana file "header" (_sDescription) — author's per-file label, used as fallback when the user hasn't named the variant
+    archiveFileName?: string;  // VPK name inside the archive, set only when it had characters Windows rejects and was remapped on extract
     sourceFileName?: string;   // Original GameBanana filename stem (e.g. "galaxy_rem_gold") — used as a label fallback when the author didn't set a file header
     /** Hero this mod belongs to in the Locker, by canonical hero name (e.g. "Lady Geist").
      *  Two reasons to store it: (1) GameBanana sometimes leaves a Skin under the
@@ -99,8 +104,27 @@ export interface ModMetadata {
      *  when the user wants to stay on a specific version after the author
      *  replaces or rearranges files. */
     ignoreUpdates?: boolean;
+    /** Conflict identities (see modConflictIdentity) of mods the user marked
+     *  as clashing with this one. Stored on both sides of the pair; enabling
+     *  this mod while one of them is enabled warns but does not block. */
+    knownConflicts?: string[];
+    /** Set while the VPK sits in the quarantine folder (stored under a
+     *  quarantine: key); cleared on release. */
+    quarantine?: import('../../../src/types/mod').QuarantineInfo;
+    /** User-defined library tags (trimmed, deduplicated case-insensitively). */
+    tags?: string[];
+    /** Heroes the VPK's files touch (see affectedHeroesFromVpkPaths), cached
+     *  by enrichMod. Only valid while `affectedHeroesStamp` matches. */
+    affectedHeroes?: string[];
+    /** `version:size:mtime` of the VPK `affectedHeroes` was computed from; a
+     *  replaced file or a summarizer change re-runs it. */
+    affectedHeroesStamp?: string;
 }
 
+// Entries may carry fields this build doesn't know (written by a newer version
+// before a downgrade). Writers merge into the stored object instead of
+// rebuilding it from the fields above, so those round-trip untouched; keep it
+// that way (see metadata.test.ts).
 export type ModMetadataMap = Record<string, ModMetadata>;
 
 // In-memory cache of the parsed metadata.json. Without this, every enrichMod
@@ -110,17 +134,44 @@ export type ModMetadataMap = Record<string, ModMetadata>;
 // get picked up, and refreshed eagerly inside saveMetadata to avoid an
 // immediate re-read after we just wrote.
 interface MetadataCacheEntry {
+    path: string;
     mtimeMs: number;
     size: number;
     data: ModMetadataMap;
 }
 let metadataCache: MetadataCacheEntry | null = null;
 
+/**
+ * The metadata file for the active Deadlock install. Metadata is keyed by
+ * pak filename, so two installs sharing one file would leak each other's
+ * names and GameBanana ids onto identically-named VPKs. The first time an
+ * install is seen, the legacy flat file is moved into its namespace (renamed
+ * to .migrated so a second install starts clean instead of inheriting it).
+ */
+export function getActiveMetadataPath(): string {
+    const deadlockPath = getActiveDeadlockPath();
+    const path = getMetadataPath(deadlockPath);
+    if (!deadlockPath || existsSync(path)) return path;
+
+    const legacyPath = getMetadataPath();
+    if (existsSync(legacyPath)) {
+        try {
+            mkdirSync(dirname(path), { recursive: true });
+            copyFileSync(legacyPath, path);
+            renameSync(legacyPath, `${legacyPath}.migrated`);
+            console.log(`[Metadata] Migrated shared metadata into ${path}`);
+        } catch (error) {
+            console.warn('[Metadata] Failed to migrate shared metadata:', error);
+        }
+    }
+    return path;
+}
+
 /**
  * Load mod metadata from disk
  */
 export function loadMetadata(): ModMetadataMap {
-    const path = getMetadataPath();
+    const path = getActiveMetadataPath();
 
     if (!existsSync(path)) {
         metadataCache = null;
@@ -131,6 +182,7 @@ export function loadMetadata(): ModMetadataMap {
         const stat = statSync(path);
         if (
             metadataCache &&
+            metadataCache.path === path &&
             metadataCache.mtimeMs === stat.mtimeMs &&
             metadataCache.size === stat.size
         ) {
@@ -139,7 +191,7 @@ export function loadMetadata(): ModMetadataMap {
 
         const content = readFileSync(path, 'utf-8');
         const data = JSON.parse(content) as ModMetadataMap;
-        metadataCache = { mtimeMs: stat.mtimeMs, size: stat.size, data };
+        metadataCache = { path, mtimeMs: stat.mtimeMs, size: stat.size, data };
         return data;
     } catch (error) {
         console.warn('[Metadata] Failed to load metadata, returning empty:', error);
@@ -148,28 +200,27 @@ export function loadMetadata(): ModMetadataMap {
     }
 }
 
+/**
+ * Drop the parsed metadata so the next read comes from disk. For writers that
+ * replace metadata files behind saveMetadata's back (config bundle restore),
+ * where an unchanged mtime+size would otherwise keep the old copy alive.
+ */
+export function invalidateMetadataCache(): void {
+    metadataCache = null;
+}
+
 /**
  * Save mod metadata to disk atomically (P1 fix #8)
  * Uses write-to-temp-then-rename pattern to prevent corruption on crash
  */
 export function saveMetadata(metadata: ModMetadataMap): void {
-    const path = getMetadataPath();
-    const tempPath = `${path}.tmp`;
-
+    const path = getActiveMetadataPath();
+    writeFileAtomicSync(path, JSON.stringify(metadata, null, 2));
     try {
-        writeFileSync(tempPath, JSON.stringify(metadata, null, 2), 'utf-8');
-        renameSync(tempPath, path);
-        try {
-            const stat = statSync(path);
-            metadataCache = { mtimeMs: stat.mtimeMs, size: stat.size, data: metadata };
-        } catch {
-            metadataCache = null;
-        }
-    } catch (error) {
-        try {
-            if (existsSync(tempPath)) unlinkSync(tempPath);
-        } catch { /* ignore */ }
-        throw error;
+        const stat = statSync(path);
+        metadataCache = { path, mtimeMs: stat.mtimeMs, size: stat.size, data: metadata };
+    } catch {
+        metadataCache = null;
     }
 }
 
@@ -274,6 +325,110 @@ async function hashFileSha256(filePath: string): Promise<string> {
     return hash.digest('hex');
 }
 
+/** Keys that don't name a scanned VPK (Locker selection sets, quarantine,
+ *  the mod library). */
+function isSyntheticMetaKey(key: string): boolean {
+    return key.startsWith('locker:') || key.startsWith(QUARANTINE_META_PREFIX) || key.startsWith(LIBRARY_META_PREFIX);
+}
+
+/** GameBanana file identity of an entry, or null for local mods. */
+function gameBananaFileKey(data: ModMetadata | undefined): string | null {
+    if (!data?.gameBananaId || !data.gameBananaFileId) return null;
+    return `${data.gameBananaId}:${data.gameBananaFileId}:${data.vpkIndex ?? ''}`;
+}
+
+/**
+ * Re-attach metadata stranded by a rename that moved the VPK but not its
+ * entry. Orphaned keys (no VPK on disk) are matched to installed VPKs that
+ * have no entry, or only a bare scan-written one, by the sha256 stored at
+ * install time; the orphan's fields move onto the VPK's key. Orphans whose
+ * GameBanana file is already held by another installed VPK are stale copies
+ * and are dropped. Must run before pruneOrphanMetadata, which would delete
+ * the orphans outright. Hashing only happens when a hashed orphan exists.
+ */
+export async function repairOrphanMetadata(
+    files: Array<{ metaKey: string; path: string }>
+): Promise<MetadataRepair[]> {
+    const metadata = loadMetadata();
+    const liveKeys = new Set(files.map((f) => f.metaKey));
+    const orphans = Object.keys(metadata).filter(
+        (key) => !isSyntheticMetaKey(key) && !liveKeys.has(key),
+    );
+    if (orphans.length === 0) return [];
+
+    const repairs: MetadataRepair[] = [];
+    const orphanByHash = new Map<string, string>();
+    for (const key of orphans) {
+        const sha = metadata[key].sha256?.toLowerCase();
+        if (!sha || !isValidSha256(sha)) continue;
+        // Two orphans with one hash can't be told apart; leave both alone.
+        orphanByHash.set(sha, orphanByHash.has(sha) ? '' : key);
+    }
+
+    if (orphanByHash.size > 0) {
+        const candidates = files.filter((f) => {
+            const data = metadata[f.metaKey];
+            return !data?.gameBananaId && !data?.modName;
+        });
+        for (const file of candidates) {
+            let sha: string;
+            try {
+                sha = await hashFileSha256(file.path);
+            } catch (error) {
+                console.warn(`[Metadata] Failed to hash ${file.path} for repair:`, error);
+                continue;
+            }
+            const orphanKey = orphanByHash.get(sha);
+            if (!orphanKey) continue;
+            const existing = metadata[file.metaKey];
+            metadata[file.metaKey] = { ...metadata[orphanKey], ...existing, sha256: sha };
+            delete metadata[orphanKey];
+            orphanByHash.delete(sha);
+            repairs.push({
+                fromKey: orphanKey,
+                toKey: file.metaKey,
+                action: existing ? 'merged' : 'reassociated',
+                matchedBy: 'sha256',
+            });
+        }
+    }
+
+    const liveGameBananaFiles = new Set(
+        files.map((f) => gameBananaFileKey(metadata[f.metaKey])).filter((key) => key !== null),
+    );
+    for (const key of orphans) {
+        if (!(key in metadata)) continue;
+        const gbKey = gameBananaFileKey(metadata[key]);
+        if (!gbKey || !liveGameBananaFiles.has(gbKey)) continue;
+        delete metadata[key];
+        repairs.push({ fromKey: key, action: 'dropped-duplicate', matchedBy: 'gameBananaId' });
+    }
+
+    if (repairs.length > 0) {
+        saveMetadata(metadata);
+        console.log(`[Metadata] Repaired ${repairs.length} stranded metadata entr${repairs.length === 1 ? 'y' : 'ies'}`);
+    }
+    return repairs;
+}
+
+/**
+ * Apply a patch to many entries with one read and one write, for bulk edits.
+ * `patch` sees each entry's current value (undefined when it has none) and
+ * returns the fields to merge, as setModMetadata would. Metadata writes are
+ * synchronous, so nothing can interleave between the read and the write.
+ */
+export function setModMetadataMany(
+    keys: string[],
+    patch: (current: ModMetadata | undefined) => ModMetadata
+): void {
+    if (keys.length === 0) return;
+    const metadata = loadMetadata();
+    for (const key of keys) {
+        metadata[key] = { ...metadata[key], ...patch(metadata[key]) };
+    }
+    saveMetadata(metadata);
+}
+
 /**
  * Remove metadata for a specific mod
  */
@@ -300,9 +455,9 @@ export function pruneOrphanMetadata(validKeys: Set<string>): void {
     const metadata = loadMetadata();
     // Synthetic `locker:*` keys hold the Locker-managed selection sets (cards /
     // sounds), which live in citadel/grimoire and are NOT scanned filenames, so
-    // they must never be treated as orphans.
+    // they must never be treated as orphans. Same for quarantined VPKs.
     const orphans = Object.keys(metadata).filter(
-        (key) => !key.startsWith('locker:') && !validKeys.has(key),
+        (key) => !isSyntheticMetaKey(key) && !validKeys.has(key),
     );
     if (orphans.length === 0) return;
 
@@ -0,0 +1,137 @@
+import { existsSync, readFileSync, unlinkSync } from 'fs';
+import { join } from 'path';
+import { writeFileAtomicSync } from '../utils/atomicWrite';
+import { getUserDataPath } from '../utils/paths';
+import { loadMetadata } from './metadata';
+import type { MissingDisabledModsReport } from '../../../src/types/mod';
+
+/**
+ * Steam's "verify integrity" can delete addons/.disabled along with every
+ * disabled mod in it. The next scan's orphan prune then drops their metadata,
+ * so the mods just quietly vanished from the list. This records what went
+ * missing (before the prune) so the renderer can say so and offer to
+ * re-download the GameBanana ones.
+ *
+ * Which entries were disabled comes from the previous scan, recorded in
+ * userData, not from the shape of the metadata key: legacy label-first names
+ * (`textures-pak21_dir.vpk`) can sit enabled in addons and read like a
+ * disabled free-form name.
+ */
+
+let report: MissingDisabledModsReport | null | undefined;
+// Metadata keys of the mods the last scan found in .disabled; null until the
+// first scan has been recorded.
+let seenDisabled: Set<string> | null | undefined;
+
+function getReportPath(): string {
+    return join(getUserDataPath(), 'missing-disabled-mods.json');
+}
+
+function getSeenDisabledPath(): string {
+    return join(getUserDataPath(), 'disabled-mod-keys.json');
+}
+
+function writeJson(path: string, value: unknown): void {
+    writeFileAtomicSync(path, JSON.stringify(value, null, 2));
+}
+
+function loadSeenDisabled(): Set<string> | null {
+    if (seenDisabled !== undefined) return seenDisabled;
+    try {
+        const path = getSeenDisabledPath();
+        const raw = existsSync(path) ? JSON.parse(readFileSync(path, 'utf-8')) : null;
+        seenDisabled = Array.isArray(raw) ? new Set(raw.filter((k): k is string => typeof k === 'string')) : null;
+    } catch (err) {
+        console.warn('[missingDisabledMods] Failed to read disabled mod list:', err);
+        seenDisabled = null;
+    }
+    return seenDisabled;
+}
+
+function saveSeenDisabled(keys: Set<string>): void {
+    const previous = loadSeenDisabled();
+    if (previous && previous.size === keys.size && [...keys].every((k) => previous.has(k))) return;
+    seenDisabled = keys;
+    try {
+        writeJson(getSeenDisabledPath(), [...keys].sort());
+    } catch (err) {
+        console.warn('[missingDisabledMods] Failed to write disabled mod list:', err);
+    }
+}
+
+function saveReport(next: MissingDisabledModsReport | null): void {
+    report = next;
+    const path = getReportPath();
+    try {
+        if (!next) {
+            if (existsSync(path)) unlinkSync(path);
+            return;
+        }
+        writeJson(path, next);
+    } catch (err) {
+        console.warn('[missingDisabledMods] Failed to write report:', err);
+    }
+}
+
+/** The standing report, or null when nothing is missing (or it was dismissed). */
+export function getMissingDisabledMods(): MissingDisabledModsReport | null {
+    if (report !== undefined) return report;
+    try {
+        const path = getReportPath();
+        report = existsSync(path) ? (JSON.parse(readFileSync(path, 'utf-8')) as MissingDisabledModsReport) : null;
+    } catch (err) {
+        console.warn('[missingDisabledMods] Failed to read report:', err);
+        report = null;
+    }
+    return report;
+}
+
+/**
+ * Record mods the previous scan saw disabled whose metadata survives but whose
+ * file is not among `scanned`. Must run before pruneOrphanMetadata, which
+ * deletes exactly that evidence. Adds to a report the user hasn't dismissed
+ * yet. Mods enabled, deleted or quarantined through Grimoire move or drop
+ * their metadata key, so they never show up here.
+ */
+export function recordMissingDisabledMods(
+    scanned: Array<{ metaKey: string; enabled: boolean }>,
+    folderMissing: boolean
+): void {
+    const previous = loadSeenDisabled();
+    saveSeenDisabled(new Set(scanned.filter((m) => !m.enabled).map((m) => m.metaKey)));
+    if (!previous) return;
+
+    const scannedKeys = new Set(scanned.map((m) => m.metaKey));
+    const metadata = loadMetadata();
+    const missing = [...previous].filter((key) => metadata[key] && !scannedKeys.has(key));
+    if (missing.length === 0) return;
+
+    const current = getMissingDisabledMods();
+    const known = new Set(current?.mods.map((m) => m.fileName) ?? []);
+    const mods = [...(current?.mods ?? [])];
+    for (const fileName of missing) {
+        if (known.has(fileName)) continue;
+        const meta = metadata[fileName];
+        mods.push({
+            fileName,
+            name: meta.modName,
+            gameBananaId: meta.gameBananaId,
+            gameBananaFileId: meta.gameBananaFileId,
+            sourceSection: meta.sourceSection,
+        });
+    }
+    console.warn(
+        `[missingDisabledMods] ${missing.length} disabled mod(s) missing from disk` +
+            (folderMissing ? ' (.disabled was recreated; a Steam verify may have removed it)' : '')
+    );
+    saveReport({
+        folderMissing: folderMissing || (current?.folderMissing ?? false),
+        detectedAt: new Date().toISOString(),
+        mods,
+    });
+}
+
+/** Clear the report once the user has acknowledged it. */
+export function dismissMissingDisabledMods(): void {
+    saveReport(null);
+}
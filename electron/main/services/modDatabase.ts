@@ -1,7 +1,7 @@
 import Database from 'better-sqlite3';
-import { app } from 'electron';
 import path from 'path';
 import fs from 'fs';
+import { getModCachePath } from '../utils/paths';
 // CachedMod is single-sourced in src/types/electron.ts; re-exported because
 // syncService and searchService import it from this module.
 import type { CachedMod } from '../../../src/types/electron';
@@ -48,8 +48,7 @@ const SEARCH_SCHEMA_SQL = `
  * Get the database file path
  */
 function getDbPath(): string {
-    const userDataPath = app.getPath('userData');
-    return path.join(userDataPath, 'mods-cache.db');
+    return getModCachePath();
 }
 
 /**
@@ -0,0 +1,25 @@
+import { describe, it, expect } from 'vitest';
+import { formatModList, modListRow } from './modListExport';
+
+describe('formatModList', () => {
+  const rows = [
+    modListRow({ name: 'pak03_dir', enabled: false, priority: 3 }, { modName: 'Zed, "the" skin', gameBananaId: 7 }),
+    modListRow({ name: 'pak02_dir', enabled: true, priority: 2 }, { modName: 'Pipe | Sound', gameBananaId: 9, sourceSection: 'Sound', variantLabel: 'v2' }),
+    modListRow({ name: 'local thing', enabled: true, priority: 1 }, undefined),
+  ];
+
+  it('writes RFC 4180 CSV with enabled mods first in load order', () => {
+    expect(formatModList(rows, 'csv')).toBe(
+      'Name,GameBanana,Version,Enabled,Priority\r\n' +
+        'local thing,,,yes,1\r\n' +
+        'Pipe | Sound,https://gamebanana.com/sounds/9,v2,yes,2\r\n' +
+        '"Zed, ""the"" skin",https://gamebanana.com/mods/7,,no,3\r\n'
+    );
+  });
+
+  it('escapes pipes in Markdown cells', () => {
+    const md = formatModList(rows, 'markdown').split('\n');
+    expect(md[0]).toBe('| Name | GameBanana | Version | Enabled | Priority |');
+    expect(md[3]).toBe('| Pipe \\| Sound | https://gamebanana.com/sounds/9 | v2 | Yes | 2 |');
+  });
+});
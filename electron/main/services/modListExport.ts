@@ -0,0 +1,82 @@
+import type { ModListExportFormat } from '../../../src/types/mod';
+import type { ModMetadata } from './metadata';
+
+/**
+ * Shareable installed-mod list (CSV or a Markdown table) for people who post
+ * their setup in a readme or video description. Pure formatting: the IPC
+ * handler scans and looks up metadata, so this stays testable without
+ * electron.
+ */
+
+export interface ModListRow {
+    name: string;
+    /** GameBanana page; empty for local mods. */
+    url: string;
+    /** The downloaded file's label. GameBanana has no per-mod version we
+     *  store, so the file the user picked is what pins the version. */
+    version: string;
+    enabled: boolean;
+    /** pakNN load-order slot; lower loads first. */
+    priority: number;
+}
+
+const COLUMNS = ['Name', 'GameBanana', 'Version', 'Enabled', 'Priority'];
+
+/** Row for one installed mod, from its scanned fields and stored metadata. */
+export function modListRow(
+    mod: { name: string; enabled: boolean; priority: number },
+    metadata: ModMetadata | undefined
+): ModListRow {
+    const id = metadata?.gameBananaId;
+    const page = metadata?.sourceSection === 'Sound' ? 'sounds' : 'mods';
+    return {
+        name: metadata?.modName?.trim() || mod.name,
+        url: typeof id === 'number' && id > 0 ? `https://gamebanana.com/${page}/${id}` : '',
+        version: metadata?.variantLabel || metadata?.fileDescription || metadata?.sourceFileName || '',
+        enabled: mod.enabled,
+        priority: mod.priority,
+    };
+}
+
+function csvField(value: string): string {
+    return /[",\r\n]/.test(value) ? `"${value.replace(/"/g, '""')}"` : value;
+}
+
+function markdownCell(value: string): string {
+    return value.replace(/\|/g, '\\|').replace(/\r?\n/g, ' ');
+}
+
+/**
+ * Format rows as CSV (RFC 4180 quoting) or a Markdown table. Enabled mods come
+ * first in load order, then disabled ones by name.
+ */
+export function formatModList(rows: ModListRow[], format: ModListExportFormat): string {
+    const sorted = [...rows].sort((a, b) => {
+        if (a.enabled !== b.enabled) return a.enabled ? -1 : 1;
+        if (a.enabled && a.priority !== b.priority) return a.priority - b.priority;
+        return a.name.localeCompare(b.name);
+    });
+
+    if (format === 'csv') {
+        const lines = [COLUMNS.join(',')];
+        for (const row of sorted) {
+            lines.push([
+                csvField(row.name),
+                csvField(row.url),
+                csvField(row.version),
+                row.enabled ? 'yes' : 'no',
+                String(row.priority),
+            ].join(','));
+        }
+        return `${lines.join('\r\n')}\r\n`;
+    }
+
+    const lines = [`| ${COLUMNS.join(' | ')} |`, `|${COLUMNS.map(() => ' --- |').join('')}`];
+    for (const row of sorted) {
+        lines.push(
+            `| ${markdownCell(row.name)} | ${row.url} | ${markdownCell(row.version)} | ` +
+            `${row.enabled ? 'Yes' : 'No'} | ${row.priority} |`
+        );
+    }
+    return `${lines.join('\n')}\n`;
+}
@@ -0,0 +1,88 @@
+import { scanMods, type Mod } from './mods';
+import { getModMetadata, setModMetadataMany, type ModMetadata } from './metadata';
+import { fetchModDetails } from './gamebanana';
+import type { GameBananaModDetails } from '../../../src/types/gamebanana';
+// InstalledModUpdate is single-sourced in src/types/mod.ts; re-exported for
+// the IPC layer.
+import type { InstalledModUpdate } from '../../../src/types/mod';
+export type { InstalledModUpdate };
+
+/**
+ * Update check for installed GameBanana mods. Each download stamps the
+ * submission's _tsDateModified into metadata (remoteDateModified); a later
+ * edit on GameBanana moves that timestamp forward, which is what this compares.
+ * Mods installed before the stamp existed get today's value recorded as their
+ * baseline instead, so they only report an update once the mod changes again
+ * (or their file disappears), never a false positive on the first check.
+ */
+
+// Details fetches in flight at once. The GameBanana rate limiter still paces
+// the requests; this only bounds how many responses are held at a time.
+const UPDATE_CHECK_CONCURRENCY = 5;
+
+interface UpdateTarget {
+    section: string;
+    mods: Array<{ mod: Mod; meta: ModMetadata }>;
+}
+
+/**
+ * Installed mods whose GameBanana submission changed since they were
+ * downloaded. One details fetch per GameBanana id (variants share it); mods
+ * with ignoreUpdates set are skipped, and a failed fetch skips that mod
+ * rather than failing the whole check.
+ */
+export async function checkModUpdates(deadlockPath: string): Promise<InstalledModUpdate[]> {
+    const targets = new Map<number, UpdateTarget>();
+    for (const mod of await scanMods(deadlockPath)) {
+        const meta = getModMetadata(mod.metaKey);
+        if (!meta?.gameBananaId || meta.gameBananaId <= 0 || meta.ignoreUpdates) continue;
+        const target = targets.get(meta.gameBananaId) ?? { section: meta.sourceSection ?? 'Mod', mods: [] };
+        target.mods.push({ mod, meta });
+        targets.set(meta.gameBananaId, target);
+    }
+
+    const details = new Map<number, GameBananaModDetails>();
+    const queue = [...targets.entries()];
+    let cursor = 0;
+    const worker = async () => {
+        while (cursor < queue.length) {
+            const [gameBananaId, target] = queue[cursor++];
+            try {
+                details.set(gameBananaId, await fetchModDetails(gameBananaId, target.section));
+            } catch (err) {
+                console.warn(`[modUpdates] Failed to fetch GameBanana mod ${gameBananaId}:`, err);
+            }
+        }
+    };
+    await Promise.all(Array.from({ length: Math.min(UPDATE_CHECK_CONCURRENCY, queue.length) }, worker));
+
+    const updates: InstalledModUpdate[] = [];
+    for (const [gameBananaId, target] of targets) {
+        const remote = details.get(gameBananaId);
+        if (!remote?.dateModified) continue;
+        const remoteDateModified = remote.dateModified;
+        const unstamped = target.mods.filter(({ meta }) => meta.remoteDateModified === undefined);
+        setModMetadataMany(unstamped.map(({ mod }) => mod.metaKey), () => ({ remoteDateModified }));
+
+        const liveFileIds = new Set((remote.files ?? []).filter((f) => !f.isArchived).map((f) => f.id));
+        for (const { mod, meta } of target.mods) {
+            const installed = meta.remoteDateModified ?? remoteDateModified;
+            const fileRemoved =
+                typeof meta.gameBananaFileId === 'number' &&
+                meta.gameBananaFileId > 0 &&
+                liveFileIds.size > 0 &&
+                !liveFileIds.has(meta.gameBananaFileId);
+            if (remoteDateModified <= installed && !fileRemoved) continue;
+            updates.push({
+                modId: mod.id,
+                modName: meta.modName ?? mod.name,
+                fileName: mod.fileName,
+                gameBananaId,
+                installedDateModified: installed,
+                remoteDateModified,
+                fileRemoved,
+            });
+        }
+    }
+    return updates;
+}
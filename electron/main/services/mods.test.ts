@@ -0,0 +1,213 @@
+/**
+ * Display-name derivation for installed VPKs, and the base-folder/.disabled
+ * filename-collision heal. extractModName used to slice a fixed five characters
+ * off anything starting with "pak", which mangled single-digit slots and names
+ * that merely began with the letters. The service dep-chain is mocked so this
+ * stays off the electron graph; metadata is an in-memory map.
+ */
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { mkdtempSync, mkdirSync, writeFileSync, readdirSync, rmSync } from 'fs';
+import { createHash } from 'crypto';
+import { tmpdir } from 'os';
+import { join } from 'path';
+
+const metadataStore = vi.hoisted(() => new Map<string, Record<string, unknown>>());
+
+vi.mock('./deadlock', () => ({}));
+vi.mock('./system', () => ({}));
+vi.mock('./metadata', () => ({
+  getModMetadata: (key: string) => metadataStore.get(key),
+  setModMetadata: (key: string, data: Record<string, unknown>) => {
+    metadataStore.set(key, { ...metadataStore.get(key), ...data });
+  },
+  removeModMetadata: (key: string) => {
+    metadataStore.delete(key);
+  },
+}));
+vi.mock('./settings', () => ({ loadSettings: vi.fn(() => ({})), assertNotReadOnly: vi.fn() }));
+vi.mock('./gameSessionMods', () => ({}));
+
+import {
+  extractModName,
+  moveVpkWithChunks,
+  normalizeLegacyPakName,
+  parseVpkPriority,
+  reconcileEnabledDisabledCollisions,
+  renameWithPriority,
+  resolveModDisplayName,
+} from './mods';
+
+describe('extractModName', () => {
+  it('strips a single-digit pak prefix before the label', () => {
+    expect(extractModName('pak1_x_dir.vpk')).toBe('X');
+    expect(extractModName('pak7_cool_skin_dir.vpk')).toBe('Cool Skin');
+  });
+
+  it('strips a two-digit pak prefix before the label', () => {
+    expect(extractModName('pak20_cool_skin_dir.vpk')).toBe('Cool Skin');
+    expect(extractModName('pak05_haze-rework.vpk')).toBe('Haze Rework');
+  });
+
+  it('keeps the slot name when there is no label', () => {
+    expect(extractModName('pak20_dir.vpk')).toBe('Pak20');
+    expect(extractModName('pak1_dir.vpk')).toBe('Pak1');
+  });
+
+  it('leaves non-pak names intact', () => {
+    expect(extractModName('better_hud.vpk')).toBe('Better Hud');
+    expect(extractModName('pakistan_flag_dir.vpk')).toBe('Pakistan Flag');
+    expect(extractModName('pak123_thing.vpk')).toBe('Pak123 Thing');
+  });
+});
+
+describe('resolveModDisplayName', () => {
+  it('prefers the stored name, then the VPK label, then the filename', () => {
+    expect(resolveModDisplayName('pak03_dir.vpk', ' Cool Skin ', 'Haze Skin')).toEqual({ name: 'Cool Skin', source: 'metadata' });
+    expect(resolveModDisplayName('pak03_dir.vpk', '  ', 'Haze Skin')).toEqual({ name: 'Haze Skin', source: 'vpk' });
+    expect(resolveModDisplayName('pak03_cool_dir.vpk', undefined, null)).toEqual({ name: 'Cool', source: 'filename' });
+  });
+});
+
+describe('renameWithPriority', () => {
+  it('keeps the label and only changes the slot number', () => {
+    expect(renameWithPriority('pak20_cool_dir.vpk', 5)).toBe('pak05_cool_dir.vpk');
+    expect(renameWithPriority('pak20_dir.vpk', 7)).toBe('pak07_dir.vpk');
+  });
+
+  it('renumbers single-digit prefixes', () => {
+    expect(renameWithPriority('pak1_cool_dir.vpk', 12)).toBe('pak12_cool_dir.vpk');
+  });
+
+  it('leaves names without a pak prefix alone', () => {
+    expect(renameWithPriority('cool_skin_dir.vpk', 3)).toBe('cool_skin_dir.vpk');
+  });
+
+  it('moves a legacy label-first name into the pakNN_label form', () => {
+    expect(renameWithPriority('textures-pak21_dir.vpk', 4)).toBe('pak04_textures_dir.vpk');
+  });
+});
+
+describe('legacy label-first pak names', () => {
+  it('reads the slot number from textures-pak21 and its chunks', () => {
+    expect(parseVpkPriority('textures-pak21_dir.vpk')).toBe(21);
+    expect(parseVpkPriority('textures-pak21_003.vpk')).toBe(21);
+    expect(parseVpkPriority('hd-ui-pak07_dir.vpk')).toBe(7);
+    expect(parseVpkPriority('pak21_dir.vpk')).toBe(21);
+  });
+
+  it('does not treat other hyphenated names as pak slots', () => {
+    expect(parseVpkPriority('textures-pak_dir.vpk')).toBeNull();
+    expect(parseVpkPriority('textures-pak211_dir.vpk')).toBeNull();
+    expect(parseVpkPriority('my-cool-skin_dir.vpk')).toBeNull();
+  });
+
+  it('normalizes dir and chunk names, keeping the label', () => {
+    expect(normalizeLegacyPakName('textures-pak21_dir.vpk')).toBe('pak21_textures_dir.vpk');
+    expect(normalizeLegacyPakName('Textures-PAK21_000.vpk')).toBe('pak21_textures_000.vpk');
+    expect(normalizeLegacyPakName('hd-ui-pak07_dir.vpk')).toBe('pak07_hd_ui_dir.vpk');
+    expect(normalizeLegacyPakName('pak21_dir.vpk')).toBeNull();
+  });
+});
+
+describe('reconcileEnabledDisabledCollisions', () => {
+  let root: string;
+  let addons: string;
+  let disabled: string;
+
+  const sha = (contents: string) => createHash('sha256').update(contents).digest('hex');
+
+  beforeEach(() => {
+    metadataStore.clear();
+    root = mkdtempSync(join(tmpdir(), 'grimoire-collide-'));
+    addons = join(root, 'addons');
+    disabled = join(addons, '.disabled');
+    mkdirSync(disabled, { recursive: true });
+  });
+
+  afterEach(() => {
+    rmSync(root, { recursive: true, force: true });
+  });
+
+  it('drops the disabled copy when both files are identical', async () => {
+    writeFileSync(join(addons, 'pak03_dir.vpk'), 'same');
+    writeFileSync(join(disabled, 'pak03_dir.vpk'), 'same');
+    metadataStore.set('pak03_dir.vpk', { modName: 'Cool Skin' });
+
+    await reconcileEnabledDisabledCollisions(addons, disabled);
+
+    expect(readdirSync(disabled)).toEqual([]);
+    expect(metadataStore.get('pak03_dir.vpk')).toMatchObject({ modName: 'Cool Skin' });
+  });
+
+  it('moves the metadata to the disabled copy when its hash matches', async () => {
+    writeFileSync(join(addons, 'pak03_dir.vpk'), 'other mod');
+    writeFileSync(join(disabled, 'pak03_dir.vpk'), 'cool skin');
+    metadataStore.set('pak03_dir.vpk', { modName: 'Cool Skin', sha256: sha('cool skin') });
+
+    await reconcileEnabledDisabledCollisions(addons, disabled);
+
+    expect(readdirSync(disabled)).toEqual(['cool_skin_dir.vpk']);
+    expect(metadataStore.get('cool_skin_dir.vpk')).toMatchObject({ modName: 'Cool Skin' });
+    expect(metadataStore.has('pak03_dir.vpk')).toBe(false);
+  });
+
+  it('keeps the metadata on the enabled copy and pins its hash when none was recorded', async () => {
+    writeFileSync(join(addons, 'pak03_dir.vpk'), 'cool skin');
+    writeFileSync(join(disabled, 'pak03_dir.vpk'), 'other mod');
+    metadataStore.set('pak03_dir.vpk', { modName: 'Cool Skin' });
+
+    await reconcileEnabledDisabledCollisions(addons, disabled);
+
+    expect(readdirSync(disabled)).toEqual(['cool_skin_dir.vpk']);
+    expect(metadataStore.get('pak03_dir.vpk')).toEqual({ modName: 'Cool Skin', sha256: sha('cool skin') });
+    expect(metadataStore.has('cool_skin_dir.vpk')).toBe(false);
+  });
+});
+
+describe('moveVpkWithChunks', () => {
+  let root: string;
+  let addons: string;
+  let disabled: string;
+
+  beforeEach(() => {
+    root = mkdtempSync(join(tmpdir(), 'grimoire-chunks-'));
+    addons = join(root, 'addons');
+    disabled = join(addons, '.disabled');
+    mkdirSync(disabled, { recursive: true });
+  });
+
+  afterEach(() => {
+    rmSync(root, { recursive: true, force: true });
+  });
+
+  it('moves a two-part pak as a unit, renaming the chunk to the new stem', async () => {
+    writeFileSync(join(addons, 'pak12_dir.vpk'), 'index');
+    writeFileSync(join(addons, 'pak12_000.vpk'), 'data');
+    writeFileSync(join(addons, 'pak13_dir.vpk'), 'other mod');
+
+    await moveVpkWithChunks(join(addons, 'pak12_dir.vpk'), join(disabled, 'cool_skin_dir.vpk'));
+
+    expect(readdirSync(disabled).sort()).toEqual(['cool_skin_000.vpk', 'cool_skin_dir.vpk']);
+    expect(readdirSync(addons).filter((n) => n.endsWith('.vpk'))).toEqual(['pak13_dir.vpk']);
+
+    await moveVpkWithChunks(join(disabled, 'cool_skin_dir.vpk'), join(addons, 'pak04_dir.vpk'));
+
+    expect(readdirSync(disabled)).toEqual([]);
+    expect(readdirSync(addons).filter((n) => n.endsWith('.vpk')).sort()).toEqual([
+      'pak04_000.vpk',
+      'pak04_dir.vpk',
+      'pak13_dir.vpk',
+    ]);
+  });
+
+  it('refuses to overwrite a chunk already at the destination', async () => {
+    writeFileSync(join(addons, 'pak12_dir.vpk'), 'index');
+    writeFileSync(join(addons, 'pak12_000.vpk'), 'data');
+    writeFileSync(join(disabled, 'cool_skin_000.vpk'), 'stale');
+
+    await expect(
+      moveVpkWithChunks(join(addons, 'pak12_dir.vpk'), join(disabled, 'cool_skin_dir.vpk'))
+    ).rejects.toThrow(/cool_skin_000\.vpk already exists/);
+    expect(readdirSync(addons).sort()).toEqual(['.disabled', 'pak12_000.vpk', 'pak12_dir.vpk']);
+  });
+});
@@ -1,18 +1,21 @@
 import { promises as fs } from 'fs';
-import { existsSync } from 'fs';
-import { join, dirname, basename } from 'path';
+import { existsSync, constants as fsConstants } from 'fs';
+import { join, dirname, basename, resolve } from 'path';
 import { createHash, randomBytes } from 'crypto';
-import { getAddonsPath, getDisabledPath, getAddonFolderPaths, createNextOverflowFolder, overflowAddonsPath, MAX_ADDON_FOLDERS, metaKeyFor } from './deadlock';
-import { fixGameinfo } from './system';
-import { getModMetadata, setModMetadata, removeModMetadata, migrateModMetadata } from './metadata';
+import { getAddonsPath, getDisabledPath, getQuarantinePath, getAddonFolderPaths, createNextOverflowFolder, overflowAddonsPath, MAX_ADDON_FOLDERS, metaKeyFor, quarantineMetaKey, libraryMetaKey } from './deadlock';
+import { fixGameinfo, getGameinfoStatus, getMountedAddonFolders } from './system';
+import { getModMetadata, setModMetadata, setModMetadataWithHash, removeModMetadata, migrateModMetadata } from './metadata';
 import { compareFileContents, fingerprintFile } from './fileMatch';
-import { loadSettings } from './settings';
+import { loadSettings, assertNotReadOnly, DEFAULT_ADDON_LIMIT } from './settings';
+import type { GameinfoCoverageReport, ModEffectivenessReport, ModEffectivenessEntry } from '../../../src/types/electron';
+import type { AddonLimitCheck, LegacyPakRename, LibraryMod, ModBatchAction, ModBatchFailure, ModFile, ModNameSource, PakCollision, PakRenumber, QuarantinedMod, VpkFolderImportResult } from '../../../src/types/mod';
 import {
     assertCanMoveLoadedGameMod,
     assertCanMoveLoadedGameMods,
     syncRunningGameModSnapshotFromMods,
     beginModMutationRunningScope,
     endModMutationRunningScope,
+    assertModChangesAllowed,
 } from './gameSessionMods';
 
 /** Verbose mod-mutation trace, gated on the `verboseModTrace` setting. Lands in
@@ -54,6 +57,20 @@ const DEFAULT_MOD_PRIORITY = 50;
 export const ENABLE_LIMIT_MESSAGE =
     'You can have at most 990 mods enabled at once. Disable one to make room.';
 
+/**
+ * Thrown by the priority-changing operations while `loadOrderLocked` is set.
+ * The renderer matches on this substring to show an "unlock first" toast.
+ */
+export const LOAD_ORDER_LOCKED_MESSAGE =
+    'Load order is locked. Unlock it to change mod priorities.';
+
+/** Refuse a renumbering operation while the user has locked their load order. */
+function assertLoadOrderUnlocked(): void {
+    if (loadSettings().loadOrderLocked === true) {
+        throw new Error(LOAD_ORDER_LOCKED_MESSAGE);
+    }
+}
+
 type CollisionMetadataOwner = 'enabled' | 'disabled';
 
 /**
@@ -140,9 +157,32 @@ export interface Mod {
 }
 
 /**
- * Parse VPK filename to extract priority (pak##_dir.vpk format)
+ * Legacy label-first pak names from older tools and hand renames:
+ * `textures-pak21_dir.vpk` and its chunks (`textures-pak21_000.vpk`). Groups:
+ * label, slot number, then the `_dir` / `_NNN` suffix.
+ */
+const LEGACY_PAK_NAME = /^([a-z0-9]+(?:-[a-z0-9]+)*)-pak(\d{2})(_dir|_\d{3})\.vpk$/i;
+
+/**
+ * The current `pakNN_<label>` form of a legacy label-first name
+ * (`textures-pak21_dir.vpk` -> `pak21_textures_dir.vpk`), or null when the
+ * name isn't one. The label is kept for the same reason renameWithPriority
+ * keeps it. Exported for tests.
+ */
+export function normalizeLegacyPakName(filename: string): string | null {
+    const match = LEGACY_PAK_NAME.exec(filename);
+    if (!match) return null;
+    const label = match[1].toLowerCase().replace(/-/g, '_');
+    return `pak${match[2]}_${label}${match[3].toLowerCase()}`;
+}
+
+/**
+ * Parse VPK filename to extract priority (pak##_dir.vpk format, or a legacy
+ * label-first `textures-pak21_dir.vpk`). Exported for tests.
  */
-function parseVpkPriority(filename: string): number | null {
+export function parseVpkPriority(filename: string): number | null {
+    const legacy = LEGACY_PAK_NAME.exec(filename);
+    if (legacy) return parseInt(legacy[2], 10);
     if (
         !filename.startsWith('pak') ||
         (!filename.endsWith('_dir.vpk') && !filename.endsWith('.vpk'))
@@ -160,21 +200,39 @@ function parseVpkPriority(filename: string): number | null {
  * and `addons{N}/<file>` for overflow mods, which keeps IDs unique when the same
  * pakNN_dir.vpk name exists in more than one addon folder.
  */
-function generateModId(metaKey: string): string {
+export function generateModId(metaKey: string): string {
     return createHash('md5').update(metaKey).digest('hex').slice(0, 16);
 }
 
 /**
- * Extract a human-readable name from the VPK filename
+ * Display-name precedence, single-sourced: the metadata name (a GameBanana
+ * title or the user's own rename via edit-local-mod), then a label derived
+ * from the VPK's file tree when the caller has one, then the filename.
+ */
+export function resolveModDisplayName(
+    fileName: string,
+    modName: string | undefined,
+    vpkLabel?: string | null
+): { name: string; source: ModNameSource } {
+    const stored = modName?.trim();
+    if (stored) return { name: stored, source: 'metadata' };
+    if (vpkLabel) return { name: vpkLabel, source: 'vpk' };
+    return { name: extractModName(fileName), source: 'filename' };
+}
+
+/**
+ * Extract a human-readable name from the VPK filename. Strips a `pak#`/`pak##`
+ * prefix plus its optional label separator; a bare slot name like
+ * `pak20_dir.vpk` has no label, so it keeps the slot name ("Pak20").
  */
-function extractModName(filename: string): string {
+export function extractModName(filename: string): string {
     // Remove _dir.vpk or .vpk suffix
-    let name = filename.replace(/_dir\.vpk$/, '').replace(/\.vpk$/, '');
+    let name = filename.replace(/_dir\.vpk$/i, '').replace(/\.vpk$/i, '');
 
-    // Remove pak## prefix if present
-    if (name.startsWith('pak') && name.length > 5) {
-        const rest = name.slice(5);
-        name = rest.startsWith('_') ? rest.slice(1) : rest;
+    // Remove pak## prefix if present (1 or 2 digits, label optional)
+    const label = name.replace(/^pak\d{1,2}(?:_|$)/i, '');
+    if (label !== name && label.length > 0) {
+        name = label;
     }
 
     // Convert underscores/dashes to spaces and title case
@@ -290,66 +348,89 @@ function pickEnableSlot(forbidden: Set<number>, preferred: Array<number | undefi
     throw new Error(ENABLE_LIMIT_MESSAGE);
 }
 
+// Parallel stat calls per folder. A cold cache on the Deck's SD card makes
+// one-at-a-time stats the slow part of a 200+ mod scan; a small pool overlaps
+// them without flooding the disk.
+const SCAN_STAT_CONCURRENCY = 16;
+
 /**
- * Scan a folder for VPK mods (async)
+ * Stat one folder entry and build its Mod, or null when it isn't a mod VPK.
+ * An unreadable entry is logged and skipped so it can't fail the whole scan.
  */
-async function scanFolder(folder: string, enabled: boolean): Promise<Mod[]> {
-    const mods: Mod[] = [];
+async function scanFolderEntry(folder: string, entry: string, enabled: boolean, trace: boolean): Promise<Mod | null> {
+    const fullPath = join(folder, entry);
+
+    try {
+        const stats = await fs.stat(fullPath);
+        if (!stats.isFile()) return null;
+
+        if (!isDeadlockModVpk(entry)) {
+            // A .vpk that doesn't end in _dir.vpk is on disk but invisible to
+            // Grimoire (the scan only counts the dir VPK). The likeliest cause
+            // of a local mod "present in the folder but missing from the list".
+            if (trace && entry.toLowerCase().endsWith('.vpk')) {
+                if (entry.toLowerCase().includes('.merge-rebuild')) {
+                    // A finished mergeMods rebuild renames this temp into place and
+                    // deletes it. A leftover means an interrupted/failed rebuild, so
+                    // the merged mod's source list may need extra scrutiny in the
+                    // source reconciliation trace below.
+                    modTrace(`scanFolder ${basename(folder)}: STALE merge-rebuild artifact "${entry}" (interrupted mergeMods rebuild never cleaned up; merged source list may be half-updated)`);
+                } else {
+                    modTrace(`scanFolder ${basename(folder)}: SKIPPED "${entry}" (not *_dir.vpk -> never shown in Grimoire)`);
+                }
+            }
+            return null;
+        }
 
+        const priority = parseVpkPriority(entry) ?? DEFAULT_MOD_PRIORITY;
+        const metaKey = metaKeyFor(fullPath);
+
+        return {
+            id: generateModId(metaKey),
+            name: extractModName(entry),
+            fileName: entry,
+            path: fullPath,
+            metaKey,
+            enabled,
+            priority,
+            size: stats.size,
+            installedAt: stats.mtime.toISOString(),
+        };
+    } catch (err) {
+        // Skip files we can't read. A mod VPK dropping out of the list is
+        // always worth a log line; other entries only under trace.
+        if (isDeadlockModVpk(entry)) {
+            console.warn(`[scanMods] Skipping unreadable ${basename(folder)}/${entry}:`, err);
+        } else if (trace) {
+            modTrace(`scanFolder ${basename(folder)}: unreadable "${entry}": ${String(err)}`);
+        }
+        return null;
+    }
+}
+
+/**
+ * Scan a folder for VPK mods (async). Entries are stat'ed concurrently but
+ * results keep readdir order, so the caller's stable sort sees the same input
+ * a sequential scan would produce.
+ */
+async function scanFolder(folder: string, enabled: boolean): Promise<Mod[]> {
     if (!existsSync(folder)) {
-        return mods;
+        return [];
     }
 
     const entries = await fs.readdir(folder);
     const trace = modTraceEnabled();
-
-    for (const entry of entries) {
-        const fullPath = join(folder, entry);
-
-        try {
-            const stats = await fs.stat(fullPath);
-            if (!stats.isFile()) continue;
-
-            if (!isDeadlockModVpk(entry)) {
-                // A .vpk that doesn't end in _dir.vpk is on disk but invisible to
-                // Grimoire (the scan only counts the dir VPK). The likeliest cause
-                // of a local mod "present in the folder but missing from the list".
-                if (trace && entry.toLowerCase().endsWith('.vpk')) {
-                    if (entry.toLowerCase().includes('.merge-rebuild')) {
-                        // A finished mergeMods rebuild renames this temp into place and
-                        // deletes it. A leftover means an interrupted/failed rebuild, so
-                        // the merged mod's source list may need extra scrutiny in the
-                        // source reconciliation trace below.
-                        modTrace(`scanFolder ${basename(folder)}: STALE merge-rebuild artifact "${entry}" (interrupted mergeMods rebuild never cleaned up; merged source list may be half-updated)`);
-                    } else {
-                        modTrace(`scanFolder ${basename(folder)}: SKIPPED "${entry}" (not *_dir.vpk -> never shown in Grimoire)`);
-                    }
-                }
-                continue;
-            }
-
-            const priority = parseVpkPriority(entry) ?? DEFAULT_MOD_PRIORITY;
-            const metaKey = metaKeyFor(fullPath);
-
-            mods.push({
-                id: generateModId(metaKey),
-                name: extractModName(entry),
-                fileName: entry,
-                path: fullPath,
-                metaKey,
-                enabled,
-                priority,
-                size: stats.size,
-                installedAt: stats.mtime.toISOString(),
-            });
-        } catch (err) {
-            // Skip files we can't read (surfaced under trace: an unreadable VPK
-            // is another way a mod silently drops out of the list).
-            if (trace) modTrace(`scanFolder ${basename(folder)}: unreadable "${entry}": ${String(err)}`);
+    const results: Array<Mod | null> = new Array(entries.length).fill(null);
+    let next = 0;
+    const worker = async (): Promise<void> => {
+        while (next < entries.length) {
+            const index = next++;
+            results[index] = await scanFolderEntry(folder, entries[index], enabled, trace);
         }
-    }
+    };
+    await Promise.all(Array.from({ length: Math.min(SCAN_STAT_CONCURRENCY, entries.length) }, worker));
 
-    return mods;
+    return results.filter((mod): mod is Mod => mod !== null);
 }
 
 /**
@@ -499,7 +580,15 @@ async function cleanupStaleMergeRebuildArtifacts(folders: string[]): Promise<voi
     }
 }
 
-async function reconcileEnabledDisabledCollisions(
+/**
+ * Heal base-folder/.disabled filename collisions (left behind by a failed or
+ * interrupted move). Both copies would otherwise share one bare-filename
+ * metadata entry and overwrite each other's data. Identical copies collapse to
+ * the enabled one; differing copies keep both, with the disabled copy renamed
+ * and the metadata following whichever copy its recorded sha256 matches.
+ * Exported for tests.
+ */
+export async function reconcileEnabledDisabledCollisions(
     addonsPath: string,
     disabledPath: string
 ): Promise<void> {
@@ -543,6 +632,13 @@ async function reconcileEnabledDisabledCollisions(
         // get-mods/get-conflicts/launch scan failed permanently.)
         const metadata = getModMetadata(enabledEntry);
         const owner = await getCollisionMetadataOwner(metadata?.sha256, join(disabledPath, disabledEntry));
+        if (owner === 'enabled' && metadata && !metadata.sha256) {
+            // No hash to arbitrate with, so the entry stays with the enabled copy.
+            // Pin it to that copy's contents so a repeat collision resolves by
+            // hash instead of by this default.
+            const { sha256 } = await fingerprintFile(join(addonsPath, enabledEntry));
+            setModMetadata(enabledEntry, { sha256 });
+        }
         const preferredName = metadata?.modName ?? metadata?.sourceFileName ?? metadata?.variantLabel;
         const renamedFileName = makeDisabledFileName(disabledEntry, takenDisabledNames, preferredName);
         await fs.rename(join(disabledPath, disabledEntry), join(disabledPath, renamedFileName));
@@ -615,7 +711,7 @@ async function moveModToFolderAs(
 
     await fs.mkdir(destinationFolder, { recursive: true });
     const destinationPath = join(destinationFolder, destinationFileName);
-    await renameWithRetry(targetMod.path, destinationPath);
+    await moveVpkWithChunks(targetMod.path, destinationPath);
     const destMetaKey = metaKeyFor(destinationPath);
 
     if (destMetaKey !== targetMod.metaKey) {
@@ -633,6 +729,48 @@ async function moveModToFolderAs(
     };
 }
 
+/**
+ * Move a directory VPK together with its numbered data chunks, renaming the
+ * chunks to the destination stem (`pak12_000.vpk` follows `pak12_dir.vpk` to
+ * `.disabled/cool_skin_000.vpk`). Moving only the dir file left the chunks
+ * behind, so the game saw an index with no data and a later mod in that slot
+ * picked them up. Chunks move first and the dir VPK last, and a failure moves
+ * whatever already went back, so the mod is never split across folders.
+ * Refuses when a destination chunk name is already taken. Exported for tests.
+ */
+export async function moveVpkWithChunks(sourcePath: string, destinationPath: string): Promise<void> {
+    const sourceFolder = dirname(sourcePath);
+    const sourceFileName = basename(sourcePath);
+    const destinationFolder = dirname(destinationPath);
+    const stemLength = sourceFileName.length - '_dir.vpk'.length;
+    const destinationStem = basename(destinationPath).replace(/_dir\.vpk$/i, '');
+
+    const moves: Array<[string, string]> = chunkFileNames(await fs.readdir(sourceFolder), sourceFileName).map(
+        (chunk) => [join(sourceFolder, chunk), join(destinationFolder, `${destinationStem}${chunk.slice(stemLength)}`)]
+    );
+    for (const [from, to] of moves) {
+        if (resolve(from) !== resolve(to) && existsSync(to)) {
+            throw new Error(`Cannot move ${sourceFileName}: ${basename(to)} already exists in ${basename(destinationFolder)}`);
+        }
+    }
+    moves.push([sourcePath, destinationPath]);
+
+    const done: Array<[string, string]> = [];
+    try {
+        for (const [from, to] of moves) {
+            await renameWithRetry(from, to);
+            done.push([from, to]);
+        }
+    } catch (err) {
+        for (const [from, to] of done.reverse()) {
+            await renameWithRetry(to, from).catch((rollbackErr) =>
+                console.warn(`[mods] Failed to move ${basename(to)} back after an interrupted move:`, rollbackErr)
+            );
+        }
+        throw err;
+    }
+}
+
 interface AllocatedSlot {
     /** Absolute path of the chosen addon folder (base citadel/addons or an
      *  overflow citadel/addonsN). */
@@ -690,6 +828,54 @@ async function allocateSlot(
     return { folder: overflowFolder, fileName: `pak${String(MIN_VPK_PRIORITY).padStart(2, '0')}_dir.vpk` };
 }
 
+/**
+ * How many more mods could be enabled right now: free slots in every existing
+ * addon folder (the base folder also excluding legacy pakNN numbers held in
+ * .disabled) plus the full capacity of overflow folders not yet created.
+ * Lets a multi-VPK install check up front instead of failing mid-way.
+ */
+export async function countFreeEnableSlots(deadlockPath: string): Promise<number> {
+    const folders = getAddonFolderPaths(deadlockPath);
+    const disabledUsed = await folderPakNumbers(getDisabledPath(deadlockPath));
+    const slotsPerFolder = MAX_VPK_PRIORITY - MIN_VPK_PRIORITY + 1;
+    let free = Math.max(0, MAX_ADDON_FOLDERS - folders.length) * slotsPerFolder;
+    for (let i = 0; i < folders.length; i++) {
+        const used = await folderPakNumbers(folders[i]);
+        if (i === 0) for (const n of disabledUsed) used.add(n);
+        for (let p = MIN_VPK_PRIORITY; p <= MAX_VPK_PRIORITY; p++) {
+            if (!used.has(p)) free++;
+        }
+    }
+    return free;
+}
+
+/**
+ * Up to `count` free pakNN numbers in [min, max] of the base addons folder, in
+ * ascending order, for "install into the HUD range" style workflows. A number
+ * is free under the same rule enable uses: not taken in addons and not held by
+ * a legacy pakNN file in .disabled (they share the bare-filename id space).
+ */
+export async function findFreeSlots(
+    deadlockPath: string,
+    min: number,
+    max: number,
+    count: number
+): Promise<number[]> {
+    const lo = Math.max(MIN_VPK_PRIORITY, Math.ceil(min));
+    const hi = Math.min(MAX_VPK_PRIORITY, Math.floor(max));
+    const limit = Math.max(0, Math.floor(count));
+    const forbidden = new Set<number>([
+        ...(await folderPakNumbers(getAddonsPath(deadlockPath))),
+        ...(await folderPakNumbers(getDisabledPath(deadlockPath))),
+    ]);
+
+    const free: number[] = [];
+    for (let p = lo; p <= hi && free.length < limit; p++) {
+        if (!forbidden.has(p)) free.push(p);
+    }
+    return free;
+}
+
 /**
  * Absolute path a brand-new ENABLED VPK should be written to (custom local
  * import, merge output), honoring the multi-folder overflow model so the create
@@ -730,8 +916,12 @@ function withModMutationLock<T>(fn: () => Promise<T>): Promise<T> {
     // duration, so applyProfile's per-mod snapshot syncs don't each spawn a
     // pgrep/tasklist (turning N+M+2 spawns into 1).
     const scoped = async (): Promise<T> => {
+        // Checked when the op runs, not when it's queued, so toggling
+        // read-only mode also stops work already waiting in the queue.
+        assertNotReadOnly();
         await beginModMutationRunningScope();
         try {
+            assertModChangesAllowed();
             return await fn();
         } finally {
             endModMutationRunningScope();
@@ -849,8 +1039,8 @@ export function setModsEnabledBatch(
     });
 }
 
-async function enableModImpl(deadlockPath: string, modId: string): Promise<Mod> {
-    const mods = await scanMods(deadlockPath);
+async function enableModImpl(deadlockPath: string, modId: string, scanned?: Mod[]): Promise<Mod> {
+    const mods = scanned ?? await scanMods(deadlockPath);
     await syncRunningGameModSnapshotFromMods(mods);
     const targetMod = mods.find((m) => m.id === modId);
 
@@ -893,8 +1083,27 @@ export function disableMod(deadlockPath: string, modId: string): Promise<Mod> {
     return withModMutationLock(() => disableModImpl(deadlockPath, modId));
 }
 
-async function disableModImpl(deadlockPath: string, modId: string): Promise<Mod> {
-    const mods = await scanMods(deadlockPath);
+/**
+ * Flip a mod between enabled and disabled in one call. The current state is
+ * read from a single scan taken under the mutation lock (and handed to the
+ * enable/disable path), so a renderer holding a stale `enabled` flag can't
+ * turn a toggle into a no-op.
+ */
+export function toggleMod(deadlockPath: string, modId: string): Promise<Mod> {
+    return withModMutationLock(async () => {
+        const mods = await scanMods(deadlockPath);
+        const targetMod = mods.find((m) => m.id === modId);
+        if (!targetMod) {
+            throw new Error(`Mod not found: ${modId}`);
+        }
+        return targetMod.enabled
+            ? disableModImpl(deadlockPath, modId, mods)
+            : enableModImpl(deadlockPath, modId, mods);
+    });
+}
+
+async function disableModImpl(deadlockPath: string, modId: string, scanned?: Mod[]): Promise<Mod> {
+    const mods = scanned ?? await scanMods(deadlockPath);
     await syncRunningGameModSnapshotFromMods(mods);
     const targetMod = mods.find((m) => m.id === modId);
 
@@ -933,8 +1142,8 @@ export function deleteMod(deadlockPath: string, modId: string): Promise<void> {
     return withModMutationLock(() => deleteModImpl(deadlockPath, modId));
 }
 
-async function deleteModImpl(deadlockPath: string, modId: string): Promise<void> {
-    const mods = await scanMods(deadlockPath);
+async function deleteModImpl(deadlockPath: string, modId: string, scanned?: Mod[]): Promise<void> {
+    const mods = scanned ?? await scanMods(deadlockPath);
     await syncRunningGameModSnapshotFromMods(mods);
     const targetMod = mods.find((m) => m.id === modId);
 
@@ -944,6 +1153,14 @@ async function deleteModImpl(deadlockPath: string, modId: string): Promise<void>
     assertCanMoveLoadedGameMod(targetMod);
 
     await fs.unlink(targetMod.path);
+    // The dir VPK is gone, so the mod is deleted; leftover chunks are only
+    // wasted space (and would be picked up by whatever lands in this slot).
+    const folder = dirname(targetMod.path);
+    for (const chunk of chunkFileNames(await fs.readdir(folder), targetMod.fileName)) {
+        await fs.unlink(join(folder, chunk)).catch((err) =>
+            console.warn(`[mods] Failed to delete chunk ${chunk} of ${targetMod.fileName}:`, err)
+        );
+    }
 
     // Metadata is keyed by metaKey. If we leave it behind, the next mod that
     // is assigned the same slot will inherit the deleted mod's gameBananaId,
@@ -952,11 +1169,639 @@ async function deleteModImpl(deadlockPath: string, modId: string): Promise<void>
 }
 
 /**
- * Replace the pak## prefix in a VPK filename with a new priority.
+ * Enable, disable or delete several mods in one call, for bulk actions like
+ * toggling a whole category. Holds the mutation lock once and scans once: each
+ * mod's id and path come from that scan, and moving one mod never renames
+ * another, so the entries stay valid for the whole batch (free slots and
+ * disabled names are still read from disk per move). A failing mod is recorded
+ * against its id and the rest of the batch carries on.
+ */
+export function applyModsBatch(
+    deadlockPath: string,
+    action: ModBatchAction,
+    modIds: string[]
+): Promise<ModBatchFailure[]> {
+    return withModMutationLock(async () => {
+        const mods = await scanMods(deadlockPath);
+        const failures: ModBatchFailure[] = [];
+        for (const modId of new Set(modIds)) {
+            try {
+                if (action === 'enable') await enableModImpl(deadlockPath, modId, mods);
+                else if (action === 'disable') await disableModImpl(deadlockPath, modId, mods);
+                else await deleteModImpl(deadlockPath, modId, mods);
+            } catch (err) {
+                failures.push({ modId, error: err instanceof Error ? err.message : String(err) });
+            }
+        }
+        modTrace(`batch ${action}: ${modIds.length - failures.length} ok, ${failures.length} failed`);
+        return failures;
+    });
+}
+
+/**
+ * Rename every legacy label-first pak file (`textures-pak21_dir.vpk` and its
+ * chunks) in the addon folders and .disabled to the `pakNN_<label>` form the
+ * rest of the app sorts and renumbers, moving metadata with each dir VPK. A
+ * file whose new name is already taken is reported and left alone.
+ */
+export function normalizeLegacyPakNames(deadlockPath: string): Promise<LegacyPakRename[]> {
+    return withModMutationLock(async () => {
+        await syncRunningGameModSnapshotFromMods(await scanMods(deadlockPath));
+        const results: LegacyPakRename[] = [];
+        const folders = [...getAddonFolderPaths(deadlockPath), getDisabledPath(deadlockPath)];
+        for (const folder of folders) {
+            if (!existsSync(folder)) continue;
+            const entries = await fs.readdir(folder);
+            const taken = new Set(entries.map((n) => n.toLowerCase()));
+            // Chunks before their dir VPK, so a mod never appears renamed
+            // while its data still sits under the old stem.
+            const legacy = entries
+                .flatMap((from) => {
+                    const to = normalizeLegacyPakName(from);
+                    return to ? [{ from, to }] : [];
+                })
+                .sort((a, b) => Number(isDeadlockModVpk(a.from)) - Number(isDeadlockModVpk(b.from)));
+            for (const { from, to } of legacy) {
+                if (taken.has(to.toLowerCase())) {
+                    results.push({ from, to, folder, renamed: false, error: `${to} already exists` });
+                    continue;
+                }
+                const fromPath = join(folder, from);
+                const toPath = join(folder, to);
+                try {
+                    await renameWithRetry(fromPath, toPath);
+                    taken.delete(from.toLowerCase());
+                    taken.add(to.toLowerCase());
+                    if (isDeadlockModVpk(from)) {
+                        migrateModMetadata([{ from: metaKeyFor(fromPath), to: metaKeyFor(toPath) }]);
+                    }
+                    modTrace(`legacy-pak: ${fromPath} -> ${to}`);
+                    results.push({ from, to, folder, renamed: true });
+                } catch (err) {
+                    results.push({
+                        from,
+                        to,
+                        folder,
+                        renamed: false,
+                        error: err instanceof Error ? err.message : String(err),
+                    });
+                }
+            }
+        }
+        return results;
+    });
+}
+
+/**
+ * Count the paks the game will mount (every enabled mod, across all addon
+ * folders) against the `addonLimit` setting. The 99-slot naming range is per
+ * folder, so overflow folders can push the total well past what the game
+ * loads reliably; this is the warning for that.
+ */
+export async function checkAddonLimits(deadlockPath: string): Promise<AddonLimitCheck> {
+    const configured = loadSettings().addonLimit;
+    const threshold =
+        typeof configured === 'number' && Number.isInteger(configured) && configured > 0
+            ? configured
+            : DEFAULT_ADDON_LIMIT;
+    const enabledCount = (await scanMods(deadlockPath)).filter((m) => m.enabled).length;
+    return { enabledCount, threshold, exceeded: enabledCount > threshold };
+}
+
+/**
+ * Enabled mods that share a pakNN slot within one addon folder
+ * (`pak05_dir.vpk` next to `pak05_skin_dir.vpk`), left behind by hand edits or
+ * an install that failed between its slot pick and its rename. Slots are per
+ * folder, so the same number in base and an overflow folder is not a collision.
+ */
+export async function findPakCollisions(deadlockPath: string): Promise<PakCollision[]> {
+    const groups = new Map<string, Mod[]>();
+    for (const mod of await scanMods(deadlockPath)) {
+        if (!mod.enabled) continue;
+        const slot = parseVpkPriority(mod.fileName);
+        if (slot === null) continue;
+        const key = `${dirname(mod.path)}#${slot}`;
+        groups.set(key, [...(groups.get(key) ?? []), mod]);
+    }
+    const collisions: PakCollision[] = [];
+    for (const mods of groups.values()) {
+        if (mods.length < 2) continue;
+        collisions.push({
+            folder: dirname(mods[0].path),
+            priority: mods[0].priority,
+            mods: mods.map((m) => ({ id: m.id, name: m.name, fileName: m.fileName })),
+        });
+    }
+    return collisions;
+}
+
+/**
+ * Resolve every findPakCollisions group: the mod installed first keeps the
+ * slot, each other one is renumbered (label and chunks kept) to the nearest
+ * free slot in the same folder, after the shared one when possible so the
+ * load order moves as little as it can. Metadata follows each rename. A mod
+ * whose folder has no free slot is reported and left in place.
+ */
+export function fixPakCollisions(deadlockPath: string): Promise<PakRenumber[]> {
+    return withModMutationLock(async () => {
+        const collisions = await findPakCollisions(deadlockPath);
+        if (collisions.length === 0) return [];
+        const mods = await scanMods(deadlockPath);
+        await syncRunningGameModSnapshotFromMods(mods);
+        const byId = new Map(mods.map((m) => [m.id, m]));
+        const losers = collisions.flatMap((c) =>
+            c.mods
+                .flatMap((entry) => byId.get(entry.id) ?? [])
+                .sort((a, b) => a.installedAt.localeCompare(b.installedAt) || a.fileName.localeCompare(b.fileName))
+                .slice(1)
+        );
+        assertCanMoveLoadedGameMods(losers);
+
+        // Base-folder slots also have to avoid legacy pakNN names in .disabled,
+        // which share the bare-filename id namespace (same rule as enableMod).
+        const disabledUsed = await folderPakNumbers(getDisabledPath(deadlockPath));
+        const basePath = resolve(getAddonsPath(deadlockPath));
+        const results: PakRenumber[] = [];
+        for (const mod of losers) {
+            const folder = dirname(mod.path);
+            const used = await folderPakNumbers(folder);
+            const forbidden = resolve(folder) === basePath ? new Set([...used, ...disabledUsed]) : used;
+            const preferred: number[] = [];
+            for (let d = 1; d <= MAX_VPK_PRIORITY; d++) preferred.push(mod.priority + d, mod.priority - d);
+            let slot: number;
+            try {
+                slot = pickEnableSlot(forbidden, preferred);
+            } catch {
+                results.push({ from: mod.fileName, to: mod.fileName, folder, renamed: false, error: 'No free slot in this folder' });
+                continue;
+            }
+
+            const to = renameWithPriority(mod.fileName, slot);
+            try {
+                // moveModToFolderAs renames the data chunks along with the dir VPK.
+                await moveModToFolderAs(mod, folder, to, true);
+                modTrace(`pak-collision: ${mod.metaKey} -> ${to}`);
+                results.push({ from: mod.fileName, to, folder, renamed: true });
+            } catch (err) {
+                results.push({
+                    from: mod.fileName,
+                    to,
+                    folder,
+                    renamed: false,
+                    error: err instanceof Error ? err.message : String(err),
+                });
+            }
+        }
+        return results;
+    });
+}
+
+/**
+ * Bulk on-ramp for hand-managed setups: install every `*_dir.vpk` in
+ * `sourceFolder` as its own enabled mod, carrying its numbered chunks along
+ * under the new slot name. A file keeps its own pakNN when that slot is free
+ * and otherwise takes the next free one (overflow-aware, like
+ * import-custom-mod). Copies by default; `move` removes each source once it
+ * has landed. One file failing doesn't stop the rest.
+ */
+export function importVpkFolder(
+    deadlockPath: string,
+    sourceFolder: string,
+    move = false
+): Promise<VpkFolderImportResult[]> {
+    return withModMutationLock(() => importVpkFolderImpl(deadlockPath, sourceFolder, move));
+}
+
+async function importVpkFolderImpl(
+    deadlockPath: string,
+    sourceFolder: string,
+    move: boolean
+): Promise<VpkFolderImportResult[]> {
+    const source = resolve(sourceFolder);
+    const managed = [...getAddonFolderPaths(deadlockPath), getDisabledPath(deadlockPath)].map((p) => resolve(p));
+    if (managed.includes(source)) {
+        throw new Error('That folder is already managed by Grimoire');
+    }
+
+    const entries = await fs.readdir(source);
+    const primaries = entries.filter(isDeadlockModVpk).sort();
+    if (primaries.length === 0) {
+        throw new Error('No _dir.vpk files were found in that folder');
+    }
+
+    await syncRunningGameModSnapshotFromMods(await scanMods(deadlockPath));
+    const disabledForbidden = await folderPakNumbers(getDisabledPath(deadlockPath));
+    const results: VpkFolderImportResult[] = [];
+
+    for (const sourceFileName of primaries) {
+        const stem = sourceFileName.replace(/_dir\.vpk$/i, '');
+        const chunks = chunkFileNames(entries, sourceFileName);
+        const written: string[] = [];
+        try {
+            const { folder, fileName } = await allocateSlot(deadlockPath, {
+                disabledForbidden,
+                preferred: [parseVpkPriority(sourceFileName) ?? undefined],
+            });
+            const destPath = join(folder, fileName);
+            await copyVpkWithChunks(source, sourceFileName, chunks, folder, fileName, written);
+
+            // Same scrub as import-custom-mod: a stale entry at this slot would
+            // otherwise merge into the new mod.
+            const metaKey = metaKeyFor(destPath);
+            removeModMetadata(metaKey);
+            const label = extractModName(sourceFileName);
+            await setModMetadataWithHash(metaKey, {
+                // A bare pakNN name has no label; let the VPK tree name it.
+                modName: /^pak\d{1,2}$/i.test(stem) ? undefined : label,
+                sourceFileName,
+            }, destPath);
+
+            if (move) {
+                for (const name of [sourceFileName, ...chunks]) {
+                    await fs.rm(join(source, name), { force: true });
+                }
+            }
+            modTrace(`import-folder: ${sourceFileName} (+${chunks.length} chunks) -> ${metaKey}`);
+            results.push({ sourceFileName, status: 'imported', installedAs: metaKey, chunkCount: chunks.length });
+        } catch (err) {
+            for (const path of written) {
+                await fs.rm(path, { force: true }).catch(() => {});
+            }
+            results.push({
+                sourceFileName,
+                status: 'failed',
+                error: err instanceof Error ? err.message : String(err),
+            });
+        }
+    }
+    return results;
+}
+
+/** Numbered data chunks (`<stem>_NNN.vpk`) among `entries` that belong to the
+ *  directory VPK `dirFileName`. */
+function chunkFileNames(entries: string[], dirFileName: string): string[] {
+    const stem = dirFileName.replace(/_dir\.vpk$/i, '').toLowerCase();
+    return entries.filter((entry) => {
+        const match = /^(.*)_\d{3}\.vpk$/i.exec(entry);
+        return match !== null && match[1].toLowerCase() === stem;
+    });
+}
+
+/**
+ * Copy a directory VPK and its chunks into `destFolder` as `destFileName`,
+ * renaming the chunks to the new stem. Chunks go first so the destination only
+ * looks like a mod once it's complete. Never overwrites; every path written is
+ * pushed onto `written` so the caller can roll back.
+ */
+async function copyVpkWithChunks(
+    sourceFolder: string,
+    sourceFileName: string,
+    chunks: string[],
+    destFolder: string,
+    destFileName: string,
+    written: string[]
+): Promise<void> {
+    const stemLength = sourceFileName.length - '_dir.vpk'.length;
+    const destStem = destFileName.replace(/_dir\.vpk$/i, '');
+    for (const chunk of chunks) {
+        const chunkDest = join(destFolder, `${destStem}${chunk.slice(stemLength)}`);
+        await fs.copyFile(join(sourceFolder, chunk), chunkDest, fsConstants.COPYFILE_EXCL);
+        written.push(chunkDest);
+    }
+    const destPath = join(destFolder, destFileName);
+    await fs.copyFile(join(sourceFolder, sourceFileName), destPath, fsConstants.COPYFILE_EXCL);
+    written.push(destPath);
+}
+
+/**
+ * Every file on disk that makes up a mod: the `_dir.vpk` the scan lists plus
+ * any numbered data chunks next to it (`pak12_dir.vpk` owns `pak12_000.vpk`,
+ * `pak12_001.vpk`, ...). Large mods split their content into chunks that the
+ * scan never shows, so this is the full picture for size and cleanup.
+ */
+export async function getModFiles(deadlockPath: string, modId: string): Promise<ModFile[]> {
+    const mods = await scanMods(deadlockPath);
+    const mod = mods.find((m) => m.id === modId);
+    if (!mod) {
+        throw new Error(`Mod not found: ${modId}`);
+    }
+
+    const files: ModFile[] = [{ fileName: mod.fileName, path: mod.path, size: mod.size, kind: 'dir' }];
+    const stem = mod.fileName.replace(/_dir\.vpk$/i, '').toLowerCase();
+    const folder = dirname(mod.path);
+    for (const entry of await fs.readdir(folder)) {
+        const match = /^(.*)_(\d{3})\.vpk$/i.exec(entry);
+        if (!match || match[1].toLowerCase() !== stem) continue;
+        const path = join(folder, entry);
+        try {
+            const stats = await fs.stat(path);
+            if (!stats.isFile()) continue;
+            files.push({ fileName: entry, path, size: stats.size, kind: 'chunk', chunkIndex: Number(match[2]) });
+        } catch {
+            // Removed between readdir and stat.
+        }
+    }
+    return files.sort((a, b) => (a.chunkIndex ?? -1) - (b.chunkIndex ?? -1));
+}
+
+/**
+ * Set a mod aside as "this broke my game" without deleting it: the VPK moves
+ * to the quarantine folder, out of both the enabled and disabled lists, and
+ * its metadata moves to a quarantine: key with the reason attached.
+ */
+export function quarantineMod(deadlockPath: string, modId: string, reason: string): Promise<QuarantinedMod> {
+    return withModMutationLock(() => quarantineModImpl(deadlockPath, modId, reason));
+}
+
+async function quarantineModImpl(deadlockPath: string, modId: string, reason: string): Promise<QuarantinedMod> {
+    const mods = await scanMods(deadlockPath);
+    await syncRunningGameModSnapshotFromMods(mods);
+    const targetMod = mods.find((m) => m.id === modId);
+
+    if (!targetMod) {
+        throw new Error(`Mod not found: ${modId}`);
+    }
+    const meta = getModMetadata(targetMod.metaKey);
+    // Locker-managed VPKs are rebuilt by the Locker; moving one out would just
+    // have it regenerated (same check as lockerVpk's isLockerManaged, inlined
+    // because lockerVpk imports this module).
+    if (meta?.lockerCosmetics || meta?.lockerSounds) {
+        throw new Error(`Mod not found: ${modId}`);
+    }
+    assertCanMoveLoadedGameMod(targetMod);
+
+    const quarantinePath = getQuarantinePath(deadlockPath);
+    const taken = new Set((await fs.readdir(quarantinePath)).map((n) => n.toLowerCase()));
+    const fileName = makeDisabledFileName(
+        targetMod.fileName,
+        taken,
+        meta?.modName ?? meta?.sourceFileName ?? meta?.variantLabel
+    );
+    const destinationPath = join(quarantinePath, fileName);
+    await moveVpkWithChunks(targetMod.path, destinationPath);
+
+    const key = quarantineMetaKey(fileName);
+    migrateModMetadata([{ from: targetMod.metaKey, to: key }]);
+    const quarantine = {
+        reason: reason.trim(),
+        quarantinedAt: new Date().toISOString(),
+        wasEnabled: targetMod.enabled,
+    };
+    setModMetadata(key, {
+        quarantine,
+        ...(targetMod.enabled ? { lastPriority: targetMod.priority } : {}),
+    });
+    modTrace(`quarantine: "${meta?.modName ?? targetMod.name}" ${targetMod.metaKey} -> ${key}`);
+    return toQuarantinedMod(fileName, targetMod.size);
+}
+
+function toQuarantinedMod(fileName: string, size: number): QuarantinedMod {
+    const meta = getModMetadata(quarantineMetaKey(fileName));
+    return {
+        fileName,
+        name: resolveModDisplayName(fileName, meta?.modName).name,
+        size,
+        gameBananaId: meta?.gameBananaId,
+        thumbnailUrl: meta?.thumbnailUrl,
+        reason: meta?.quarantine?.reason ?? '',
+        quarantinedAt: meta?.quarantine?.quarantinedAt ?? '',
+        wasEnabled: meta?.quarantine?.wasEnabled ?? false,
+    };
+}
+
+/** Every VPK in the quarantine folder, most recently quarantined first. */
+export async function listQuarantinedMods(deadlockPath: string): Promise<QuarantinedMod[]> {
+    const quarantinePath = getQuarantinePath(deadlockPath);
+    const quarantined: QuarantinedMod[] = [];
+    for (const fileName of await listPrimaryVpkFiles(quarantinePath)) {
+        try {
+            const stats = await fs.stat(join(quarantinePath, fileName));
+            quarantined.push(toQuarantinedMod(fileName, stats.size));
+        } catch {
+            // Removed between readdir and stat.
+        }
+    }
+    return quarantined.sort((a, b) => b.quarantinedAt.localeCompare(a.quarantinedAt));
+}
+
+/**
+ * Take a mod out of quarantine. It always returns to the disabled list, even
+ * if it was enabled before, so a crashing mod is only re-enabled on purpose.
+ */
+export function releaseQuarantinedMod(deadlockPath: string, fileName: string): Promise<Mod> {
+    return withModMutationLock(() => releaseQuarantinedModImpl(deadlockPath, fileName));
+}
+
+async function releaseQuarantinedModImpl(deadlockPath: string, fileName: string): Promise<Mod> {
+    if (basename(fileName) !== fileName || !isDeadlockModVpk(fileName)) {
+        throw new Error(`Invalid quarantined file name: ${fileName}`);
+    }
+    const sourcePath = join(getQuarantinePath(deadlockPath), fileName);
+    if (!existsSync(sourcePath)) {
+        throw new Error(`Quarantined mod not found: ${fileName}`);
+    }
+
+    const disabledPath = getDisabledPath(deadlockPath);
+    const taken = new Set((await fs.readdir(disabledPath)).map((n) => n.toLowerCase()));
+    const destinationFileName = makeDisabledFileName(fileName, taken);
+    const destinationPath = join(disabledPath, destinationFileName);
+    await moveVpkWithChunks(sourcePath, destinationPath);
+
+    const destMetaKey = metaKeyFor(destinationPath);
+    migrateModMetadata([{ from: quarantineMetaKey(fileName), to: destMetaKey }]);
+    setModMetadata(destMetaKey, { quarantine: undefined });
+    modTrace(`release quarantine: ${fileName} -> ${destMetaKey}`);
+
+    const stats = await fs.stat(destinationPath);
+    return {
+        id: generateModId(destMetaKey),
+        name: extractModName(destinationFileName),
+        fileName: destinationFileName,
+        path: destinationPath,
+        metaKey: destMetaKey,
+        enabled: false,
+        priority: DEFAULT_MOD_PRIORITY,
+        size: stats.size,
+        installedAt: stats.mtime.toISOString(),
+    };
+}
+
+/**
+ * The configured external mod library (AppSettings.modLibraryPath), created if
+ * missing. Refused when it points into the game's own mod folders, where the
+ * scan would list its files as installed mods.
+ */
+async function getModLibraryPath(deadlockPath: string): Promise<string> {
+    const configured = loadSettings().modLibraryPath;
+    if (!configured) {
+        throw new Error('No mod library folder configured');
+    }
+    const library = resolve(configured);
+    const managed = [...getAddonFolderPaths(deadlockPath), getDisabledPath(deadlockPath), getQuarantinePath(deadlockPath)];
+    if (managed.some((p) => resolve(p) === library)) {
+        throw new Error('The mod library must be outside the game\'s mod folders');
+    }
+    await fs.mkdir(library, { recursive: true });
+    return library;
+}
+
+function toLibraryMod(fileName: string, size: number, chunkCount: number): LibraryMod {
+    const meta = getModMetadata(libraryMetaKey(fileName));
+    return {
+        fileName,
+        name: resolveModDisplayName(fileName, meta?.modName).name,
+        size,
+        chunkCount,
+        gameBananaId: meta?.gameBananaId,
+        thumbnailUrl: meta?.thumbnailUrl,
+    };
+}
+
+/** Every mod in the external library, by name. */
+export async function listLibraryMods(deadlockPath: string): Promise<LibraryMod[]> {
+    const library = await getModLibraryPath(deadlockPath);
+    const entries = await fs.readdir(library);
+    const mods: LibraryMod[] = [];
+    for (const fileName of await listPrimaryVpkFiles(library)) {
+        try {
+            const chunks = chunkFileNames(entries, fileName);
+            let size = 0;
+            for (const name of [fileName, ...chunks]) {
+                size += (await fs.stat(join(library, name))).size;
+            }
+            mods.push(toLibraryMod(fileName, size, chunks.length));
+        } catch {
+            // Removed between readdir and stat.
+        }
+    }
+    return mods.sort((a, b) => a.name.localeCompare(b.name));
+}
+
+/**
+ * Copy a library mod (and its chunks) into the game. It lands in the disabled
+ * list under a fresh name with the library's metadata, and the library keeps
+ * its copy, so the same mod can be installed again later.
+ */
+export function importFromLibrary(deadlockPath: string, fileName: string): Promise<Mod> {
+    return withModMutationLock(() => importFromLibraryImpl(deadlockPath, fileName));
+}
+
+async function importFromLibraryImpl(deadlockPath: string, fileName: string): Promise<Mod> {
+    if (basename(fileName) !== fileName || !isDeadlockModVpk(fileName)) {
+        throw new Error(`Invalid library file name: ${fileName}`);
+    }
+    const library = await getModLibraryPath(deadlockPath);
+    const entries = await fs.readdir(library);
+    if (!entries.includes(fileName)) {
+        throw new Error(`Library mod not found: ${fileName}`);
+    }
+
+    const disabledPath = getDisabledPath(deadlockPath);
+    const taken = new Set((await fs.readdir(disabledPath)).map((n) => n.toLowerCase()));
+    const libraryMeta = getModMetadata(libraryMetaKey(fileName));
+    const destinationFileName = makeDisabledFileName(fileName, taken, libraryMeta?.modName);
+    const destinationPath = join(disabledPath, destinationFileName);
+    const destMetaKey = metaKeyFor(destinationPath);
+    const written: string[] = [];
+    try {
+        await copyVpkWithChunks(library, fileName, chunkFileNames(entries, fileName), disabledPath, destinationFileName, written);
+        removeModMetadata(destMetaKey);
+        if (libraryMeta) {
+            setModMetadata(destMetaKey, { ...libraryMeta });
+        } else {
+            await setModMetadataWithHash(destMetaKey, { modName: extractModName(fileName), sourceFileName: fileName }, destinationPath);
+        }
+        modTrace(`import from library: ${fileName} -> ${destMetaKey}`);
+    } catch (err) {
+        for (const path of written) {
+            await fs.rm(path, { force: true }).catch(() => {});
+        }
+        throw err;
+    }
+
+    const stats = await fs.stat(destinationPath);
+    return {
+        id: generateModId(destMetaKey),
+        name: extractModName(destinationFileName),
+        fileName: destinationFileName,
+        path: destinationPath,
+        metaKey: destMetaKey,
+        enabled: false,
+        priority: DEFAULT_MOD_PRIORITY,
+        size: stats.size,
+        installedAt: stats.mtime.toISOString(),
+    };
+}
+
+/**
+ * Take a mod out of the game but keep it in the external library: its files
+ * (chunks included) are copied there under a pak-free name, its metadata moves
+ * with it, and only then are the game copies removed. Unlike disable, the mod
+ * frees its slot and leaves the mod list entirely.
+ */
+export function moveModToLibrary(deadlockPath: string, modId: string): Promise<LibraryMod> {
+    return withModMutationLock(() => moveModToLibraryImpl(deadlockPath, modId));
+}
+
+async function moveModToLibraryImpl(deadlockPath: string, modId: string): Promise<LibraryMod> {
+    const mods = await scanMods(deadlockPath);
+    await syncRunningGameModSnapshotFromMods(mods);
+    const targetMod = mods.find((m) => m.id === modId);
+    if (!targetMod) {
+        throw new Error(`Mod not found: ${modId}`);
+    }
+    assertCanMoveLoadedGameMod(targetMod);
+
+    const library = await getModLibraryPath(deadlockPath);
+    const taken = new Set((await fs.readdir(library)).map((n) => n.toLowerCase()));
+    const meta = getModMetadata(targetMod.metaKey);
+    const fileName = makeDisabledFileName(
+        targetMod.fileName,
+        taken,
+        meta?.modName ?? meta?.sourceFileName ?? meta?.variantLabel
+    );
+    const sourceFolder = dirname(targetMod.path);
+    const chunks = chunkFileNames(await fs.readdir(sourceFolder), targetMod.fileName);
+
+    // Copy, not rename: the library is usually on another drive.
+    const written: string[] = [];
+    try {
+        await copyVpkWithChunks(sourceFolder, targetMod.fileName, chunks, library, fileName, written);
+    } catch (err) {
+        for (const path of written) {
+            await fs.rm(path, { force: true }).catch(() => {});
+        }
+        throw err;
+    }
+
+    const key = libraryMetaKey(fileName);
+    migrateModMetadata([{ from: targetMod.metaKey, to: key }]);
+    if (targetMod.enabled) {
+        // Lets a later import + enable land back in the same slot.
+        setModMetadata(key, { lastPriority: targetMod.priority });
+    }
+    for (const name of [targetMod.fileName, ...chunks]) {
+        await fs.rm(join(sourceFolder, name), { force: true });
+    }
+    modTrace(`move to library: "${meta?.modName ?? targetMod.name}" ${targetMod.metaKey} -> ${key}`);
+
+    let size = 0;
+    for (const path of written) {
+        size += (await fs.stat(path)).size;
+    }
+    return toLibraryMod(fileName, size, chunks.length);
+}
+
+/**
+ * Replace the pak# / pak## prefix in a VPK filename with a new priority. Only
+ * the number changes: a label after it (`pak20_cool_dir.vpk`) is kept, so two
+ * labeled mods can't collapse to the same bare `pakNN_dir.vpk`. Names without
+ * a pak prefix are returned unchanged; a legacy `textures-pak21` name comes
+ * back in the `pakNN_textures` form. Exported for tests.
  */
-function renameWithPriority(fileName: string, priority: number): string {
+export function renameWithPriority(fileName: string, priority: number): string {
     const priorityStr = String(Math.min(MAX_VPK_PRIORITY, priority)).padStart(2, '0');
-    return fileName.replace(/^pak\d{2}_/, `pak${priorityStr}_`);
+    return (normalizeLegacyPakName(fileName) ?? fileName).replace(/^pak\d{1,2}_/i, `pak${priorityStr}_`);
 }
 
 /**
@@ -968,7 +1813,10 @@ export function setModPriority(
     modId: string,
     newPriority: number
 ): Promise<Mod> {
-    return withModMutationLock(() => setModPriorityImpl(deadlockPath, modId, newPriority));
+    return withModMutationLock(async () => {
+        assertLoadOrderUnlocked();
+        return setModPriorityImpl(deadlockPath, modId, newPriority);
+    });
 }
 
 async function setModPriorityImpl(
@@ -998,10 +1846,16 @@ async function setModPriorityImpl(
     // scan and a merged-mod manifest pointing at it would lose its source.
     // (Overflow folders have a folder-prefixed id namespace, so disabled names
     // can't collide there.)
+    // Compared by slot number, not exact name: labels survive the rename, so
+    // `pak05_cool_dir.vpk` holds slot 5 just as `pak05_dir.vpk` would.
+    const ownFolderSlots = await folderPakNumbers(parentDir);
+    ownFolderSlots.delete(targetMod.priority);
     const collides =
         existsSync(join(parentDir, newFileName)) ||
+        ownFolderSlots.has(newPriority) ||
         (addonFolderIndex(targetMod.path) === 0 &&
-            existsSync(join(getDisabledPath(deadlockPath), newFileName)));
+            (existsSync(join(getDisabledPath(deadlockPath), newFileName)) ||
+                (await folderPakNumbers(getDisabledPath(deadlockPath))).has(newPriority)));
     if (collides) {
         throw new Error(`Priority ${newPriority} is already in use`);
     }
@@ -1025,6 +1879,129 @@ async function setModPriorityImpl(
     };
 }
 
+/**
+ * Enable a disabled mod straight into base-folder slot `priority`, instead of
+ * enable-then-set-priority (which passes through whatever slot enable picked
+ * and fails when the target slot is taken). An enabled mod already in the slot
+ * is swapped out to a free base slot first. A slot held by a legacy pakNN file
+ * in .disabled is refused, as setModPriority does. Already-enabled mods just
+ * get the priority change.
+ */
+export function enableModAt(deadlockPath: string, modId: string, priority: number): Promise<Mod> {
+    return withModMutationLock(async () => {
+        assertLoadOrderUnlocked();
+        return enableModAtImpl(deadlockPath, modId, priority);
+    });
+}
+
+async function enableModAtImpl(deadlockPath: string, modId: string, priority: number): Promise<Mod> {
+    if (!Number.isInteger(priority) || priority < MIN_VPK_PRIORITY || priority > MAX_VPK_PRIORITY) {
+        throw new Error(`Priority must be between ${MIN_VPK_PRIORITY} and ${MAX_VPK_PRIORITY}`);
+    }
+    const mods = await scanMods(deadlockPath);
+    await syncRunningGameModSnapshotFromMods(mods);
+    const targetMod = mods.find((m) => m.id === modId);
+
+    if (!targetMod) {
+        throw new Error(`Mod not found: ${modId}`);
+    }
+    if (targetMod.enabled) {
+        return setModPriorityImpl(deadlockPath, modId, priority);
+    }
+
+    const addonsPath = getAddonsPath(deadlockPath);
+    const disabledUsed = await folderPakNumbers(getDisabledPath(deadlockPath));
+    const ownNumber = parseVpkPriority(targetMod.fileName);
+    if (ownNumber !== null) disabledUsed.delete(ownNumber);
+    if (disabledUsed.has(priority)) {
+        throw new Error(`Priority ${priority} is already in use by a disabled mod`);
+    }
+
+    const occupant = mods.find(
+        (m) => m.enabled && addonFolderIndex(m.path) === 0 && m.priority === priority
+    );
+    if (occupant) {
+        assertCanMoveLoadedGameMod(occupant);
+        const forbidden = new Set<number>([...(await folderPakNumbers(addonsPath)), ...disabledUsed]);
+        let freeSlot: number;
+        try {
+            freeSlot = pickEnableSlot(forbidden, []);
+        } catch {
+            throw new Error(`Priority ${priority} is taken and the base mod folder has no free slot to move it to`);
+        }
+        const occupantPath = join(addonsPath, renameWithPriority(occupant.fileName, freeSlot));
+        await moveVpkWithChunks(occupant.path, occupantPath);
+        migrateModMetadata([{ from: occupant.metaKey, to: metaKeyFor(occupantPath) }]);
+        modTrace(`enable-at: moved ${occupant.metaKey} -> ${metaKeyFor(occupantPath)} to free pri ${priority}`);
+    }
+
+    const result = await moveModToFolderAs(
+        targetMod,
+        addonsPath,
+        `pak${String(priority).padStart(2, '0')}_dir.vpk`,
+        true
+    );
+    modTrace(`enable-at: ${targetMod.metaKey} -> ${result.metaKey} (pri ${result.priority})`);
+    return result;
+}
+
+/**
+ * Throw unless base-folder slot `priority` is free for a new install: in range,
+ * not held by an enabled mod in addons, and not reserved by a pakNN file in
+ * .disabled. `ignoreFileName` skips the installing VPK's own disabled name.
+ * Used by downloads that ask for a specific pak number, so they fail with a
+ * clear message instead of swapping another mod out as enableModAt would.
+ */
+export async function assertPrioritySlotFree(
+    deadlockPath: string,
+    priority: number,
+    ignoreFileName?: string
+): Promise<void> {
+    if (!Number.isInteger(priority) || priority < MIN_VPK_PRIORITY || priority > MAX_VPK_PRIORITY) {
+        throw new Error(`Priority must be between ${MIN_VPK_PRIORITY} and ${MAX_VPK_PRIORITY}`);
+    }
+    assertLoadOrderUnlocked();
+    if ((await folderPakNumbers(getAddonsPath(deadlockPath))).has(priority)) {
+        throw new Error(`Priority ${priority} is already in use by an enabled mod`);
+    }
+    const disabledUsed = await folderPakNumbers(getDisabledPath(deadlockPath));
+    const ownNumber = ignoreFileName ? parseVpkPriority(ignoreFileName) : null;
+    if (ownNumber !== null) disabledUsed.delete(ownNumber);
+    if (disabledUsed.has(priority)) {
+        throw new Error(`Priority ${priority} is already in use by a disabled mod`);
+    }
+}
+
+/**
+ * Move an enabled mod to a relative spot in the load order, for a
+ * higher/lower slider. `fraction` 0 puts it first (wins every conflict), 1
+ * last; values between map proportionally onto its position among the other
+ * enabled mods. The concrete pak number comes from reorderMods' dense layout,
+ * so it never collides with another mod or a reserved disabled slot.
+ */
+export function setModRelativePosition(deadlockPath: string, modId: string, fraction: number): Promise<Mod> {
+    return withModMutationLock(async () => {
+        assertLoadOrderUnlocked();
+        if (!Number.isFinite(fraction) || fraction < 0 || fraction > 1) {
+            throw new Error('Position must be between 0 and 1');
+        }
+        const enabled = (await scanMods(deadlockPath)).filter((m) => m.enabled);
+        const target = enabled.find((m) => m.id === modId);
+        if (!target) {
+            throw new Error(`Mod not found or not enabled: ${modId}`);
+        }
+        const others = enabled.filter((m) => m.id !== modId);
+        const index = Math.round(fraction * others.length);
+        const order = [...others.slice(0, index), target, ...others.slice(index)];
+        await reorderModsImpl(deadlockPath, order.map((m) => m.id));
+
+        const moved = (await scanMods(deadlockPath)).filter((m) => m.enabled)[index];
+        if (!moved) throw new Error(`Mod not found after reorder: ${modId}`);
+        modTrace(`relative-position: ${target.metaKey} -> ${moved.metaKey} (${fraction})`);
+        return moved;
+    });
+}
+
 /**
  * Reorder the enabled mods to match the given order (async).
  *
@@ -1045,7 +2022,10 @@ async function setModPriorityImpl(
  * cross-folder moves and slot swaps collision-free; metadata migrates with each.
  */
 export function reorderMods(deadlockPath: string, orderedIds: string[]): Promise<void> {
-    return withModMutationLock(() => reorderModsImpl(deadlockPath, orderedIds));
+    return withModMutationLock(async () => {
+        assertLoadOrderUnlocked();
+        return reorderModsImpl(deadlockPath, orderedIds);
+    });
 }
 
 // Unlocked: callers that already hold the mutation queue (swapModPriority) call
@@ -1182,7 +2162,10 @@ export function swapModPriority(
     modIdA: string,
     modIdB: string
 ): Promise<void> {
-    return withModMutationLock(() => swapModPriorityImpl(deadlockPath, modIdA, modIdB));
+    return withModMutationLock(async () => {
+        assertLoadOrderUnlocked();
+        return swapModPriorityImpl(deadlockPath, modIdA, modIdB);
+    });
 }
 
 async function swapModPriorityImpl(
@@ -1252,3 +2235,57 @@ async function directSwap(a: Mod, b: Mod): Promise<void> {
         { from: b.metaKey, to: metaKeyFor(steps[1].final) },
     ]);
 }
+
+/**
+ * Answer "why isn't my mod showing up in game": for every enabled mod, check
+ * that gameinfo.gi mounts the addon folder it lives in and that the VPK itself
+ * is readable. Read-only; Fix Configuration is the remedy for unmounted folders.
+ */
+export async function checkModsEffective(deadlockPath: string): Promise<ModEffectivenessReport> {
+    const gameinfo = getGameinfoStatus(deadlockPath);
+    const mounted = getMountedAddonFolders(deadlockPath);
+    const mods = (await scanMods(deadlockPath)).filter((m) => m.enabled);
+
+    const effective: ModEffectivenessEntry[] = [];
+    const ineffective: ModEffectivenessEntry[] = [];
+    for (const mod of mods) {
+        const folder = basename(dirname(mod.path));
+        const entry: ModEffectivenessEntry = { id: mod.id, name: mod.name, fileName: mod.fileName, folder };
+        if (!mounted) {
+            ineffective.push({ ...entry, reason: 'gameinfo-unconfigured' });
+        } else if (!mounted.has(folder.toLowerCase())) {
+            ineffective.push({ ...entry, reason: 'folder-not-mounted' });
+        } else if (!(await isReadableVpk(mod.path))) {
+            ineffective.push({ ...entry, reason: 'unreadable' });
+        } else {
+            effective.push(entry);
+        }
+    }
+
+    return { gameinfo, effective, ineffective };
+}
+
+/**
+ * The gameinfo half of checkModsEffective: which enabled mods a reset or
+ * partial gameinfo.gi (typically a game update) keeps from loading. Empty
+ * `uncovered` means every enabled mod's folder is mounted.
+ */
+export async function checkGameinfoCoverage(deadlockPath: string): Promise<GameinfoCoverageReport> {
+    const { gameinfo, effective, ineffective } = await checkModsEffective(deadlockPath);
+    return {
+        gameinfo,
+        enabledCount: effective.length + ineffective.length,
+        uncovered: ineffective.filter(
+            (e) => e.reason === 'gameinfo-unconfigured' || e.reason === 'folder-not-mounted'
+        ),
+    };
+}
+
+async function isReadableVpk(vpkPath: string): Promise<boolean> {
+    try {
+        await fs.access(vpkPath, fsConstants.R_OK);
+        return (await fs.stat(vpkPath)).size > 0;
+    } catch {
+        return false;
+    }
+}
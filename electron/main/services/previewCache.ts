@@ -15,6 +15,7 @@
  *   - soul-models        -> soulContainerModels.ts (modelDir)
  *   - portrait-cache     -> heroPortraits.ts (getHeroPortraits)
  *   - locker-card-thumbs -> heroCards.ts (getAppliedCardThumbnails)
+ *   - image-cache        -> imageCache.ts (getCachedImage)
  */
 import { promises as fs } from 'fs';
 import { join } from 'path';
@@ -25,6 +26,7 @@ const PREVIEW_CACHE_DIRS = [
     'soul-models',
     'portrait-cache',
     'locker-card-thumbs',
+    'image-cache',
 ] as const;
 
 /** Recursively sum the byte size of a directory tree. A missing directory (the
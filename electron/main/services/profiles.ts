@@ -1,12 +1,14 @@
 import { readFileSync, writeFileSync, existsSync, mkdirSync, renameSync, unlinkSync } from 'fs';
-import { join, dirname } from 'path';
-import { getUserDataPath } from '../utils/paths';
+import { dirname } from 'path';
+import { getProfilesPath } from '../utils/paths';
 import {
     scanMods,
     runExclusiveModMutation,
     enableModUnlocked,
     disableModUnlocked,
     reorderModsUnlocked,
+    resolveModDisplayName,
+    type Mod,
 } from './mods';
 import { getModMetadata } from './metadata';
 import {
@@ -27,15 +29,8 @@ import { generateCrosshairCommands, normalizeCrosshairSettings } from '../../../
 // The Profile wire types are single-sourced in src/types/electron.ts
 // (docstrings included); re-exported because portableProfile.ts and the
 // ipc layer import them from this service.
-import type { Profile, ProfileMod, ProfileCrosshairSettings, ApplyProfileResult } from '../../../src/types/electron';
-export type { Profile, ProfileMod, ProfileCrosshairSettings, ApplyProfileResult };
-
-/**
- * Get the profiles file path
- */
-function getProfilesPath(): string {
-    return join(getUserDataPath(), 'profiles.json');
-}
+import type { Profile, ProfileMod, ProfileCrosshairSettings, ApplyProfileResult, ProfileValidationResult, ProfileDiff, ProfileDiffAction } from '../../../src/types/electron';
+export type { Profile, ProfileMod, ProfileCrosshairSettings, ApplyProfileResult, ProfileValidationResult, ProfileDiff };
 
 /**
  * Generate a unique profile ID
@@ -114,6 +109,42 @@ function dedupeEnabledForProfile<T extends { metaKey: string; fileName: string;
     return resolverDedupeEnabledForProfile(mods, getModMetadata);
 }
 
+/** Bump when ProfileMod gains another identity field worth backfilling. */
+const PROFILE_IDENTITY_VERSION = 1;
+
+/**
+ * One-time upgrade for profiles saved before ProfileMod carried stable ids:
+ * a mod stored by fileName alone only resolves while it keeps that exact
+ * pakNN name, so the first reorder after an update broke the profile. Each
+ * fileName-only entry is matched against the mod currently on disk under that
+ * name and gets its GameBanana ids and vpkIndex copied in. Runs once per
+ * profile (identityVersion marks it), since later a fileName can point at a
+ * different mod. Returns how many profiles were upgraded.
+ */
+export async function migrateProfileIdentities(deadlockPath: string): Promise<number> {
+    const profiles = loadProfiles();
+    const pending = profiles.filter((p) => (p.identityVersion ?? 0) < PROFILE_IDENTITY_VERSION);
+    if (pending.length === 0) return 0;
+
+    const mods = await scanMods(deadlockPath);
+    const byFileName = new Map(mods.map((mod) => [mod.fileName.toLowerCase(), mod]));
+    const inferredVpkIndexes = inferMissingVpkIndexes(mods);
+    for (const profile of pending) {
+        profile.mods = profile.mods.map((entry) => {
+            if (entry.gameBananaId !== undefined) return entry;
+            const mod = byFileName.get(entry.fileName.toLowerCase());
+            if (!mod) return entry;
+            const stable = toProfileMod(mod, entry.enabled, inferredVpkIndexes);
+            if (stable.gameBananaId === undefined) return entry;
+            return { ...entry, ...stable, enabled: entry.enabled, priority: entry.priority };
+        });
+        profile.identityVersion = PROFILE_IDENTITY_VERSION;
+    }
+    saveProfiles(profiles);
+    console.log(`[Profiles] Backfilled stable mod ids on ${pending.length} profile(s)`);
+    return pending.length;
+}
+
 /**
  * Create a new profile from current mod state and provided crosshair settings
  */
@@ -320,6 +351,9 @@ export async function applyProfile(deadlockPath: string, profileId: string): Pro
     let disabledCount = 0;
     let orphanedDisabledCount = 0;
     const failures: string[] = [];
+    const failedModIds = new Set<string>();
+    const matchedMods: Array<{ id: string; fileName: string }> = [];
+    const missing: ApplyProfileResult['missing'] = [];
 
     await runExclusiveModMutation(async () => {
         // Resolve by archive id first, using vpkIndex for multi-VPK siblings.
@@ -335,6 +369,7 @@ export async function applyProfile(deadlockPath: string, profileId: string): Pro
             const resolution = resolveProfileMod(profileMod);
             if (resolution.mod !== undefined) {
                 profileModByCurrentId.set(resolution.mod.id, profileMod);
+                matchedMods.push(resolution.mod);
                 if (resolution.via === 'stable') {
                     stableHits++;
                     console.log(
@@ -350,6 +385,7 @@ export async function applyProfile(deadlockPath: string, profileId: string): Pro
                 }
             } else if (resolution.via === 'refused-crossmatch') {
                 refusedCrossmatches++;
+                if (profileMod.enabled) missing.push({ ...profileMod, refused: true });
                 console.warn(
                     `[profiles] resolve refused: ${describeProfileMod(profileMod)} ` +
                     `would have cross-matched current mod ${resolution.candidateFileName} ` +
@@ -357,6 +393,7 @@ export async function applyProfile(deadlockPath: string, profileId: string): Pro
                 );
             } else {
                 unmatched++;
+                if (profileMod.enabled) missing.push({ ...profileMod, refused: false });
                 console.log(
                     `[profiles] resolve miss: ${describeProfileMod(profileMod)} ` +
                     `(mod not currently installed)`
@@ -396,6 +433,7 @@ export async function applyProfile(deadlockPath: string, profileId: string): Pro
                 await disableModUnlocked(deadlockPath, mod.id);
             } catch (err) {
                 failures.push(`disable ${mod.fileName}: ${String(err)}`);
+                failedModIds.add(mod.id);
                 console.warn(`[profiles] disable failed (continuing): ${mod.fileName}: ${String(err)}`);
                 continue;
             }
@@ -417,6 +455,7 @@ export async function applyProfile(deadlockPath: string, profileId: string): Pro
                 enabledCount++;
             } catch (err) {
                 failures.push(`enable ${mod.fileName}: ${String(err)}`);
+                failedModIds.add(mod.id);
                 console.warn(`[profiles] enable failed (continuing): ${mod.fileName}: ${String(err)}`);
             }
         }
@@ -495,7 +534,156 @@ export async function applyProfile(deadlockPath: string, profileId: string): Pro
     writeAutoexec(deadlockPath, currentAutoexec);
 
     console.log(`[profiles] apply '${profile.name}' complete`);
-    return { profile, failures };
+    const applied = [...new Set(matchedMods.filter((m) => !failedModIds.has(m.id)).map((m) => m.fileName))];
+    return { profile, failures, applied, missing };
+}
+
+/** A profile matched against the installed mods, shared by the previews. */
+interface ProfileMatch {
+    profile: Profile;
+    /** Installed mods apply acts on, in scan order; Locker-managed VPKs excluded. */
+    mods: Mod[];
+    /** The profile entry each installed mod id resolved to. */
+    profileModByCurrentId: Map<string, ProfileMod>;
+    /** Enabled profile entries with no installed match. `refused` is set when a
+     *  fileName match existed but belonged to a different mod. */
+    unmatched: Array<{ profileMod: ProfileMod; refused: boolean }>;
+}
+
+/** Resolve every profile entry the way applyProfile does, without writing. */
+async function matchProfile(deadlockPath: string, profileId: string): Promise<ProfileMatch> {
+    const profile = loadProfiles().find(p => p.id === profileId);
+    if (!profile) {
+        throw new Error(`Profile not found: ${profileId}`);
+    }
+
+    const currentMods = await scanMods(deadlockPath);
+    const resolveProfileMod = buildProfileModResolver(currentMods);
+    const profileModByCurrentId = new Map<string, ProfileMod>();
+    const unmatched: ProfileMatch['unmatched'] = [];
+    for (const profileMod of profile.mods) {
+        const resolution = resolveProfileMod(profileMod);
+        if (resolution.mod !== undefined) {
+            profileModByCurrentId.set(resolution.mod.id, profileMod);
+        } else if (profileMod.enabled) {
+            unmatched.push({ profileMod, refused: resolution.via === 'refused-crossmatch' });
+        }
+    }
+    return {
+        profile,
+        mods: currentMods.filter((mod) => !isLockerManaged(mod.metaKey)),
+        profileModByCurrentId,
+        unmatched,
+    };
+}
+
+/** The name the Installed list shows for `mod`, for the previews below. */
+function profileModDisplayName(mod: Mod): string {
+    return resolveModDisplayName(mod.fileName, getModMetadata(mod.metaKey)?.modName).name;
+}
+
+/**
+ * What apply would do to one installed mod given its matched profile entry
+ * (undefined when the profile doesn't reference it), or null for no change.
+ */
+function plannedProfileChange(mod: Mod, profileMod: ProfileMod | undefined): 'disable' | 'enable' | 'priority' | null {
+    if (mod.enabled && !profileMod?.enabled) return 'disable';
+    if (!mod.enabled && profileMod?.enabled) return 'enable';
+    if (mod.enabled && profileMod && mod.priority !== profileMod.priority) return 'priority';
+    return null;
+}
+
+/**
+ * Report what applying a profile would change without touching any files:
+ * entries that aren't installed, enabled mods the profile would disable, and
+ * matched mods whose priority differs. Same matching as diffProfile.
+ */
+export async function validateProfile(deadlockPath: string, profileId: string): Promise<ProfileValidationResult> {
+    const { mods, profileModByCurrentId, unmatched } = await matchProfile(deadlockPath, profileId);
+    const result: ProfileValidationResult = {
+        profileId,
+        missing: unmatched.map(({ profileMod, refused }) => ({ ...profileMod, refused })),
+        notInProfile: [],
+        priorityChanges: [],
+    };
+
+    for (const mod of mods) {
+        const view = {
+            modId: mod.id,
+            fileName: mod.fileName,
+            name: profileModDisplayName(mod),
+            currentPriority: mod.enabled ? mod.priority : null,
+        };
+        const profileMod = profileModByCurrentId.get(mod.id);
+        const change = plannedProfileChange(mod, profileMod);
+        if (change === 'disable' && !profileMod) {
+            result.notInProfile.push(view);
+        } else if ((change === 'enable' || change === 'priority') && profileMod) {
+            result.priorityChanges.push({ ...view, profilePriority: profileMod.priority });
+        }
+    }
+
+    result.priorityChanges.sort((a, b) => a.profilePriority - b.profilePriority);
+    return result;
+}
+
+/**
+ * The per-mod action plan applyProfile would carry out, without touching any
+ * files. Mirrors apply's passes: disables (including enabled mods the profile
+ * doesn't reference), enables, then load-order moves for mods that stay
+ * enabled. Enabled entries with no installed match become download actions
+ * when they carry GameBanana ids, unavailable otherwise.
+ */
+export async function diffProfile(deadlockPath: string, profileId: string): Promise<ProfileDiff> {
+    const { mods, profileModByCurrentId, unmatched } = await matchProfile(deadlockPath, profileId);
+    const disables: ProfileDiffAction[] = [];
+    const enables: ProfileDiffAction[] = [];
+    const moves: ProfileDiffAction[] = [];
+
+    const absent: ProfileDiffAction[] = unmatched.map(({ profileMod, refused }): ProfileDiffAction => {
+        const downloadable =
+            typeof profileMod.gameBananaId === 'number' && typeof profileMod.gameBananaFileId === 'number';
+        return {
+            kind: downloadable ? 'download' : 'unavailable',
+            fileName: profileMod.fileName,
+            name: profileMod.fileName,
+            currentPriority: null,
+            profilePriority: profileMod.priority,
+            gameBananaId: profileMod.gameBananaId,
+            gameBananaFileId: profileMod.gameBananaFileId,
+            refused: refused || undefined,
+        };
+    });
+
+    for (const mod of mods) {
+        const profileMod = profileModByCurrentId.get(mod.id);
+        // scanMods results don't carry GameBanana ids; they live in metadata.
+        const meta = getModMetadata(mod.metaKey);
+        const action: Omit<ProfileDiffAction, 'kind'> = {
+            modId: mod.id,
+            fileName: mod.fileName,
+            name: profileModDisplayName(mod),
+            currentPriority: mod.enabled ? mod.priority : null,
+            profilePriority: profileMod ? profileMod.priority : null,
+            gameBananaId: meta?.gameBananaId,
+            gameBananaFileId: meta?.gameBananaFileId,
+        };
+        const change = plannedProfileChange(mod, profileMod);
+        if (change === 'disable') {
+            disables.push({ ...action, kind: 'disable', notInProfile: !profileMod || undefined });
+        } else if (change === 'enable') {
+            enables.push({ ...action, kind: 'enable' });
+        } else if (change === 'priority') {
+            moves.push({ ...action, kind: 'priority' });
+        }
+    }
+
+    const byProfilePriority = (a: ProfileDiffAction, b: ProfileDiffAction) =>
+        (a.profilePriority ?? 0) - (b.profilePriority ?? 0);
+    enables.sort(byProfilePriority);
+    moves.sort(byProfilePriority);
+    absent.sort(byProfilePriority);
+    return { profileId, actions: [...disables, ...enables, ...moves, ...absent] };
 }
 
 /**
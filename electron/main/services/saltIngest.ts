@@ -19,10 +19,10 @@
 
 import { promises as fs } from 'fs';
 import { join } from 'path';
-import { homedir } from 'os';
 import { getUserDataPath } from '../utils/paths';
 import { statsApiRateLimiter } from './rateLimiter';
 import { GRIMOIRE_USER_AGENT } from './userAgent';
+import { getSteamInstallPaths } from './deadlock';
 // Status shape is a wire type (crosses IPC), so it lives in the single-source
 // declaration file alongside ElectronAPI (type-only import, erased at build).
 import type { SaltIngestStatus } from '../../../src/types/electron';
@@ -85,21 +85,7 @@ async function saveState(state: PersistedState): Promise<void> {
  * are commonly symlinks to one another, so callers dedupe via realpath.
  */
 function getCandidateCacheDirs(): string[] {
-    const home = homedir();
-    if (process.platform === 'linux') {
-        return [
-            join(home, '.local/share/Steam/appcache/httpcache'),
-            join(home, '.steam/steam/appcache/httpcache'),
-            join(home, '.var/app/com.valvesoftware.Steam/.local/share/Steam/appcache/httpcache'),
-        ];
-    }
-    if (process.platform === 'win32') {
-        return [
-            'C:\\Program Files (x86)\\Steam\\appcache\\httpcache',
-            'C:\\Program Files\\Steam\\appcache\\httpcache',
-        ];
-    }
-    return [join(home, 'Library/Application Support/Steam/appcache/httpcache')];
+    return getSteamInstallPaths().map((steamPath) => join(steamPath, 'appcache', 'httpcache'));
 }
 
 async function resolveCacheDirs(): Promise<string[]> {
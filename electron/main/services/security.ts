@@ -1,3 +1,6 @@
+import { createHash } from 'crypto';
+import { createReadStream } from 'fs';
+
 /**
  * Security utilities for validating and sanitizing inputs
  * Part of P0 security fixes from audit report
@@ -77,12 +80,36 @@ export function validateFilePath(filePath: string): void {
 }
 
 /**
- * Validate minimum file size (to detect incomplete downloads)
+ * Validate a file against GameBanana's MD5 checksum. Files without a checksum
+ * (older uploads, 1-Click URLs) pass; anything else must match, case-insensitively.
+ */
+export async function validateFileMd5(filePath: string, expectedMd5: string | undefined): Promise<void> {
+    const expected = expectedMd5?.trim().toLowerCase();
+    if (!expected || !/^[a-f0-9]{32}$/.test(expected)) return;
+    const hash = createHash('md5');
+    await new Promise<void>((resolve, reject) => {
+        const stream = createReadStream(filePath);
+        stream.on('data', (chunk) => hash.update(chunk));
+        stream.on('error', reject);
+        stream.on('end', resolve);
+    });
+    const actual = hash.digest('hex');
+    if (actual !== expected) {
+        throw new Error(`Checksum mismatch: expected MD5 ${expected}, got ${actual}`);
+    }
+}
+
+/**
+ * Validate a download's size: it must match GameBanana's reported byte count
+ * exactly when one is known (0 means unknown), and clear a minimum either way.
  */
 export function validateFileSize(expectedSize: number, actualSize: number): void {
     if (expectedSize > 0 && actualSize < expectedSize) {
         throw new Error(`Download incomplete: expected ${expectedSize} bytes, got ${actualSize} bytes`);
     }
+    if (expectedSize > 0 && actualSize !== expectedSize) {
+        throw new Error(`Download size mismatch: expected ${expectedSize} bytes, got ${actualSize} bytes`);
+    }
 
     // Minimum reasonable size for a VPK/archive (1KB)
     if (actualSize < 1024) {
@@ -0,0 +1,30 @@
+/**
+ * settings.json schema migration. Files written before versioning load as v0
+ * and must come out at SETTINGS_VERSION with the user's choices carried over.
+ */
+import { describe, it, expect, vi } from 'vitest';
+
+vi.mock('../utils/paths', () => ({ getSettingsPath: () => '/nonexistent/settings.json' }));
+
+import { migrateSettings, SETTINGS_VERSION } from './settings';
+
+describe('migrateSettings', () => {
+  it('upgrades an unversioned file and keeps the NSFW choice', () => {
+    const raw: Record<string, unknown> = { deadlockPath: '/games/deadlock', hideNsfwPreviews: false };
+    expect(migrateSettings(raw)).toBe(true);
+    expect(raw).toEqual({
+      deadlockPath: '/games/deadlock',
+      hideNsfwPreviews: false,
+      browseNsfwContentMode: 'show',
+      installedHideNsfwPreviews: false,
+      settingsVersion: SETTINGS_VERSION,
+    });
+  });
+
+  it('leaves current and newer files alone', () => {
+    expect(migrateSettings({ settingsVersion: SETTINGS_VERSION })).toBe(false);
+    const newer = { settingsVersion: SETTINGS_VERSION + 1, renamedKey: 'x' };
+    expect(migrateSettings(newer)).toBe(false);
+    expect(newer).toEqual({ settingsVersion: SETTINGS_VERSION + 1, renamedKey: 'x' });
+  });
+});
@@ -1,14 +1,68 @@
-import { readFileSync, writeFileSync, existsSync, mkdirSync, renameSync, unlinkSync } from 'fs';
-import { dirname } from 'path';
+import { readFileSync, existsSync } from 'fs';
 import { getSettingsPath } from '../utils/paths';
+import { writeFileAtomicSync } from '../utils/atomicWrite';
 
 // AppSettings is single-sourced in src/types/mod.ts (type-only import:
 // erased at build, so no renderer code is pulled into the main bundle).
 // Re-exported so existing `from './settings'` imports keep working.
-import type { AppSettings } from '../../../src/types/mod';
+import type { AppSettings, InstallPriorityPolicy } from '../../../src/types/mod';
 export type { AppSettings };
 
+/**
+ * Enabled-pak count past which check-addon-limits warns. The engine has no
+ * documented cap, but load failures and crashes at startup have been reported
+ * with a few hundred paks mounted; 200 stays clear of those reports while
+ * leaving two full addon folders of room.
+ */
+export const DEFAULT_ADDON_LIMIT = 200;
+
+/**
+ * Current settings.json schema. Bump it and append a step to
+ * SETTINGS_MIGRATIONS whenever a key is renamed, removed or reinterpreted;
+ * plain additions need neither, since DEFAULT_SETTINGS fills missing keys.
+ */
+export const SETTINGS_VERSION = 1;
+
+/**
+ * SETTINGS_MIGRATIONS[n] upgrades a raw settings object from version n to
+ * n + 1 in place. Steps only ever see older files, so they must not assume
+ * any key is present.
+ */
+const SETTINGS_MIGRATIONS: Array<(raw: Record<string, unknown>) => void> = [
+    // 0 -> 1: the single hideNsfwPreviews switch split into a Browse mode and
+    // an Installed-page flag. Carry an explicit choice over to both.
+    (raw) => {
+        if (raw.browseNsfwContentMode === undefined && raw.hideNsfwPreviews === false) {
+            raw.browseNsfwContentMode = 'show';
+        }
+        if (raw.installedHideNsfwPreviews === undefined && typeof raw.hideNsfwPreviews === 'boolean') {
+            raw.installedHideNsfwPreviews = raw.hideNsfwPreviews;
+        }
+    },
+];
+
+/**
+ * Bring a parsed settings.json up to SETTINGS_VERSION. Returns whether
+ * anything ran, so the caller knows to write the upgraded file back. A file
+ * from a newer build is left untouched rather than guessed at.
+ */
+export function migrateSettings(raw: Record<string, unknown>): boolean {
+    const from = typeof raw.settingsVersion === 'number' ? raw.settingsVersion : 0;
+    if (from > SETTINGS_VERSION) {
+        console.warn(`[Settings] settings.json is from a newer version (v${from}); loading it without migrating`);
+        return false;
+    }
+    if (from === SETTINGS_VERSION) return false;
+    for (let version = from; version < SETTINGS_VERSION; version++) {
+        SETTINGS_MIGRATIONS[version](raw);
+    }
+    raw.settingsVersion = SETTINGS_VERSION;
+    console.log(`[Settings] Migrated settings.json from v${from} to v${SETTINGS_VERSION}`);
+    return true;
+}
+
 const DEFAULT_SETTINGS: AppSettings = {
+    settingsVersion: SETTINGS_VERSION,
     deadlockPath: null,
     devMode: false,
     devDeadlockPath: null,
@@ -19,6 +73,7 @@ const DEFAULT_SETTINGS: AppSettings = {
     lockerCardsExpandedByDefault: false,
     autoDisableSiblingVariants: true,
     autoEnableDownloads: false,
+    installPriorityPolicy: 'next-free',
     steamLaunchOptions: '',
     activeProfileId: null,
     confirmProfileUpdate: true,
@@ -41,6 +96,13 @@ const DEFAULT_SETTINGS: AppSettings = {
     contributeMatchSalts: false,
     unifiedLaunchButton: false,
     verboseModTrace: false,
+    loadOrderLocked: false,
+    readOnlyMode: false,
+    blockModChangesWhileGameRunning: false,
+    offlineMode: false,
+    modLibraryPath: null,
+    addonLimit: DEFAULT_ADDON_LIMIT,
+    extraExtractExtensions: [],
 };
 
 /**
@@ -56,24 +118,54 @@ export function loadSettings(): AppSettings {
 
     try {
         const content = readFileSync(path, 'utf-8');
-        const settings = JSON.parse(content) as Partial<AppSettings>;
-        return {
-            ...DEFAULT_SETTINGS,
-            ...settings,
-            browseNsfwContentMode:
-                settings.browseNsfwContentMode ??
-                (settings.hideNsfwPreviews === false ? 'show' : DEFAULT_SETTINGS.browseNsfwContentMode),
-            installedHideNsfwPreviews:
-                settings.installedHideNsfwPreviews ??
-                settings.hideNsfwPreviews ??
-                DEFAULT_SETTINGS.installedHideNsfwPreviews,
-        };
+        const raw = JSON.parse(content) as Record<string, unknown>;
+        const migrated = migrateSettings(raw);
+        const settings = { ...DEFAULT_SETTINGS, ...(raw as Partial<AppSettings>) };
+        if (migrated) {
+            // Persist the upgrade so later steps never re-run on this file. A
+            // failed write is retried on the next load; the migrated values are
+            // still used for this one.
+            try {
+                saveSettings(settings);
+            } catch (saveError) {
+                console.warn('[Settings] Failed to save migrated settings:', saveError);
+            }
+        }
+        return settings;
     } catch (error) {
         console.warn('[Settings] Failed to load settings, resetting to defaults:', error);
         return { ...DEFAULT_SETTINGS };
     }
 }
 
+/**
+ * Thrown by every game-folder write while `readOnlyMode` is set. The renderer
+ * matches on the "Read-only mode" prefix, so keep it stable.
+ */
+export const READ_ONLY_MODE_MESSAGE =
+    'Read-only mode is on, so the game folder is not modified. Turn it off in Settings to make changes.';
+
+/** Refuse a write to the game folder while read-only mode is on. */
+export function assertNotReadOnly(): void {
+    if (loadSettings().readOnlyMode === true) {
+        throw new Error(READ_ONLY_MODE_MESSAGE);
+    }
+}
+
+/**
+ * Thrown by every GameBanana request while `offlineMode` is set. The renderer
+ * matches on the "Offline mode" prefix, so keep it stable.
+ */
+export const OFFLINE_MODE_MESSAGE =
+    'Offline mode is on, so GameBanana is not contacted. Turn it off in Settings to browse and download mods.';
+
+/** Refuse a network request while offline mode is on. */
+export function assertOnline(): void {
+    if (loadSettings().offlineMode === true) {
+        throw new Error(OFFLINE_MODE_MESSAGE);
+    }
+}
+
 /**
  * The Deadlock path IPC handlers should act on: the dev dummy path when dev
  * mode is active, otherwise the user's configured install. Single-sourced
@@ -92,28 +184,90 @@ export function getActiveDeadlockPath(): string | null {
  * Uses write-to-temp-then-rename pattern to prevent corruption on crash
  */
 export function saveSettings(settings: AppSettings): void {
-    const path = getSettingsPath();
-    const tempPath = `${path}.tmp`;
-    const dir = dirname(path);
-
-    if (!existsSync(dir)) {
-        mkdirSync(dir, { recursive: true });
-    }
+    // Whatever the caller passed (the renderer round-trips the whole object),
+    // the file on disk is always in the current schema.
+    writeFileAtomicSync(getSettingsPath(), JSON.stringify({ ...settings, settingsVersion: SETTINGS_VERSION }, null, 2));
+}
 
-    try {
-        // Write to temp file first
-        writeFileSync(tempPath, JSON.stringify(settings, null, 2), 'utf-8');
+// Every AppSettings key, as a Record so a field added to the interface without
+// being listed here fails to compile. patchSettings rejects anything else.
+const SETTINGS_KEYS: Record<keyof AppSettings, true> = {
+    settingsVersion: true,
+    deadlockPath: true,
+    devMode: true,
+    devDeadlockPath: true,
+    verboseModTrace: true,
+    loadOrderLocked: true,
+    readOnlyMode: true,
+    blockModChangesWhileGameRunning: true,
+    offlineMode: true,
+    modLibraryPath: true,
+    addonLimit: true,
+    extraExtractExtensions: true,
+    hideNsfwPreviews: true,
+    browseNsfwContentMode: true,
+    installedHideNsfwPreviews: true,
+    hideOutdatedMods: true,
+    lockerCardsExpandedByDefault: true,
+    autoDisableSiblingVariants: true,
+    autoEnableDownloads: true,
+    installPriorityPolicy: true,
+    newModsLastCheckedAt: true,
+    steamLaunchOptions: true,
+    activeProfileId: true,
+    confirmProfileUpdate: true,
+    experimentalStats: true,
+    experimentalCrosshair: true,
+    experimentalSocial: true,
+    experimentalUnknownModMatching: true,
+    hasCompletedSetup: true,
+    ignoredConflicts: true,
+    ignoreConflictsByDefault: true,
+    ignoredConflictFiles: true,
+    ignoredConflictFilesGlobal: true,
+    ignoredConflictMods: true,
+    accentColor: true,
+    sidebarHeroHighlight: true,
+    unifiedLaunchButton: true,
+    appearanceBackgrounds: true,
+    dateFormat: true,
+    language: true,
+    zoomFactor: true,
+    discordRpcEnabled: true,
+    contributeMatchSalts: true,
+    experimentalDeadworksServers: true,
+    experimentalFoundry: true,
+    deadworksRelayUrl: true,
+    experimentalPerformanceConfig: true,
+    externalEditorPath: true,
+    windowBounds: true,
+};
 
-        // Atomic rename (on most filesystems, rename is atomic)
-        renameSync(tempPath, path);
-    } catch (error) {
-        // Clean up temp file if rename failed
-        try {
-            if (existsSync(tempPath)) {
-                unlinkSync(tempPath);
-            }
-        } catch { /* ignore cleanup errors */ }
+const INSTALL_PRIORITY_POLICIES: InstallPriorityPolicy[] = ['next-free', 'highest', 'lowest'];
 
-        throw error;
+/**
+ * Merge only the given fields onto the settings on disk and save. Lets the
+ * renderer change one field without a read-modify-write round trip that could
+ * clobber a field another window (or the main process) changed meanwhile.
+ * Unknown keys are rejected so a typo can't silently persist a dead field.
+ */
+export function patchSettings(partial: Partial<AppSettings>): AppSettings {
+    if (!partial || typeof partial !== 'object' || Array.isArray(partial)) {
+        throw new Error('Settings patch must be an object');
+    }
+    const unknown = Object.keys(partial).filter(
+        (key) => !Object.prototype.hasOwnProperty.call(SETTINGS_KEYS, key)
+    );
+    if (unknown.length > 0) {
+        throw new Error(`Unknown settings key(s): ${unknown.join(', ')}`);
+    }
+    if (
+        partial.installPriorityPolicy !== undefined &&
+        !INSTALL_PRIORITY_POLICIES.includes(partial.installPriorityPolicy)
+    ) {
+        throw new Error(`Invalid install priority policy: ${String(partial.installPriorityPolicy)}`);
     }
+    const settings = { ...loadSettings(), ...partial };
+    saveSettings(settings);
+    return settings;
 }
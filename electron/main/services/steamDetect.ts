@@ -3,7 +3,7 @@
 
 import { existsSync, readFileSync } from 'fs'
 import { join } from 'path'
-import { homedir } from 'os'
+import { getSteamInstallPaths } from './deadlock'
 
 export interface SteamUser {
     steamId64: string
@@ -16,21 +16,7 @@ export interface SteamUser {
  * Get platform-specific Steam config paths
  */
 function getSteamConfigPaths(): string[] {
-    const paths: string[] = []
-    const home = homedir()
-
-    if (process.platform === 'linux') {
-        paths.push(join(home, '.steam/steam/config'))
-        paths.push(join(home, '.local/share/Steam/config'))
-        paths.push(join(home, '.var/app/com.valvesoftware.Steam/.steam/steam/config'))
-    } else if (process.platform === 'win32') {
-        paths.push('C:\\Program Files (x86)\\Steam\\config')
-        paths.push('C:\\Program Files\\Steam\\config')
-    } else if (process.platform === 'darwin') {
-        paths.push(join(home, 'Library/Application Support/Steam/config'))
-    }
-
-    return paths
+    return getSteamInstallPaths().map((steamPath) => join(steamPath, 'config'))
 }
 
 /**
@@ -1,7 +1,7 @@
 import { BrowserWindow } from 'electron';
 import { fetchSubmissions } from './gamebanana';
 import { upsertMods, getSyncState, updateSyncState, getModCount, type CachedMod } from './modDatabase';
-import type { GameBananaMod } from '../../../src/types/gamebanana';
+import { getModThumbnail, type GameBananaMod } from '../../../src/types/gamebanana';
 
 const SYNC_PER_PAGE = 50;
 const SECTIONS = ['Mod', 'Sound', 'Gui', 'Model', 'Wip'] as const;
@@ -73,10 +73,7 @@ function timedUpsert(section: string, page: number, mods: CachedMod[]): void {
  * Convert GameBananaMod to CachedMod
  */
 function mapToCache(mod: GameBananaMod, section: string): CachedMod {
-    const thumbnail = mod.previewMedia?.images?.[0];
-    const thumbnailUrl = thumbnail
-        ? `${thumbnail.baseUrl}/${thumbnail.file530 || thumbnail.file || thumbnail.file220}`
-        : null;
+    const thumbnailUrl = getModThumbnail(mod) ?? null;
 
     return {
         id: mod.id,
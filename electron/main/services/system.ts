@@ -1,5 +1,9 @@
 import { readFileSync, writeFileSync, existsSync, readdirSync, unlinkSync } from 'fs';
-import { join, extname } from 'path';
+import { join, extname, dirname, basename } from 'path';
+import { unifiedDiff } from '../utils/unifiedDiff';
+import { writeFileAtomicSync } from '../utils/atomicWrite';
+import { parseSearchPaths, indexOfGamePath, hasGamePathsInOrder } from '../utils/searchPaths';
+import { loadSettings, READ_ONLY_MODE_MESSAGE, assertNotReadOnly } from './settings';
 import { getGameinfoPath, getDisabledPath, getCitadelPath, getGrimoirePath, getOverflowFolderNames, getAddonFolderPaths, hasDeadworksContentRoot, DEADWORKS_SEARCH_PATH } from './deadlock';
 
 // The canonical SearchPaths block for Deadlock with mod support
@@ -102,35 +106,30 @@ function findSearchPathsBlock(
     return null; // unbalanced braces
 }
 
-// True when the SearchPaths body has an active (non-commented) entry pointing the
-// engine at the given citadel-relative folder. Matched as a COMPLETE path token,
-// ignoring // comments, so: a stray path in a comment doesn't read as configured
-// (the false positive that let a DLM-mangled gameinfo.gi look healthy); a
-// subfolder like citadel/addons/profile_default (Deadlock Mod Manager's profile
-// mode) does NOT satisfy citadel/addons; and citadel/addons does NOT satisfy a
-// query for citadel/addons1 (or vice versa).
+// True when the SearchPaths body has an active (non-commented) Game entry
+// pointing the engine at the given citadel-relative folder. Entries are parsed as
+// key/value tokens (see utils/searchPaths.ts), so tabs vs spaces don't matter; a
+// stray path in a comment doesn't read as configured (the false positive that
+// let a DLM-mangled gameinfo.gi look healthy); a subfolder like
+// citadel/addons/profile_default (Deadlock Mod Manager's profile mode) does NOT
+// satisfy citadel/addons; and citadel/addons does NOT satisfy a query for
+// citadel/addons1 (or vice versa).
 function hasActivePath(searchPathsBody: string, relPath: string): boolean {
-    const pattern = relPath.replace(/[/\\]/g, '[\\\\/]+');
-    const re = new RegExp(`${pattern}(?![\\\\/\\w])`, 'i');
-    return searchPathsBody.split(/\r?\n/).some((line) => re.test(line.split('//')[0]));
+    return indexOfGamePath(parseSearchPaths(searchPathsBody), relPath) !== -1;
 }
 
-function hasActiveAddonPath(searchPathsBody: string): boolean {
-    return hasActivePath(searchPathsBody, 'citadel/addons');
-}
-
-// citadel/grimoire is the Grimoire-managed override folder (Locker cards +
-// ability sounds), listed first in the canonical block so it outranks every mod.
-function hasActiveGrimoirePath(searchPathsBody: string): boolean {
-    return hasActivePath(searchPathsBody, 'citadel/grimoire');
-}
-
-// Both required search paths are present and active. The grimoire path is what
-// makes applied Locker cards/sounds win, so an install missing it (e.g. a
-// pre-grimoire 1.13.x user, or a game update that reset gameinfo.gi) reads as
-// not-yet-configured and Fix Configuration rewrites the canonical block.
+// Both required search paths are present, active, and ahead of the base game
+// content. citadel/grimoire is the Grimoire-managed override folder (Locker
+// cards + ability sounds) and has to come before citadel/addons so it outranks
+// every mod; both have to come before `Game citadel` or the stock files win.
+// An install missing or misordering them (e.g. a pre-grimoire 1.13.x user, or a
+// game update that reset gameinfo.gi) reads as not-yet-configured and Fix
+// Configuration rewrites the canonical block.
 function hasRequiredSearchPaths(searchPathsBody: string): boolean {
-    return hasActiveAddonPath(searchPathsBody) && hasActiveGrimoirePath(searchPathsBody);
+    const entries = parseSearchPaths(searchPathsBody);
+    if (!hasGamePathsInOrder(entries, ['citadel/grimoire', 'citadel/addons'])) return false;
+    const base = indexOfGamePath(entries, 'citadel');
+    return base === -1 || base > indexOfGamePath(entries, 'citadel/addons');
 }
 
 // Preserve the first version we touch. Never overwrites an existing backup so the
@@ -146,6 +145,46 @@ function backupGameinfoOnce(gameinfoPath: string, original: string): void {
     }
 }
 
+// Timestamped copies taken before every rewrite (gameinfo.gi.<ISO time>.bak),
+// newest last by name. restoreGameinfo puts the newest one back. Only the last
+// few are kept; the one-time .grimoire-bak original above is never pruned.
+const GAMEINFO_TIMESTAMPED_BACKUP = /^gameinfo\.gi\.\d{4}-\d{2}-\d{2}T[\d-]+Z\.bak$/i;
+const MAX_GAMEINFO_BACKUPS = 5;
+
+function listGameinfoBackups(gameinfoPath: string): string[] {
+    try {
+        return readdirSync(dirname(gameinfoPath))
+            .filter((name) => GAMEINFO_TIMESTAMPED_BACKUP.test(name))
+            .sort();
+    } catch {
+        return [];
+    }
+}
+
+// Snapshot `original` before a rewrite. Skipped when the newest backup already
+// holds the same text, so repeated fixes of an unchanged file don't push the
+// useful copies out. Best-effort like backupGameinfoOnce.
+function backupGameinfo(gameinfoPath: string, original: string): void {
+    backupGameinfoOnce(gameinfoPath, original);
+    const folder = dirname(gameinfoPath);
+    try {
+        const existing = listGameinfoBackups(gameinfoPath);
+        const newest = existing[existing.length - 1];
+        if (newest && readFileSync(join(folder, newest), 'utf-8') === original) return;
+        const stamp = new Date().toISOString().replace(/[:.]/g, '-');
+        writeFileSync(join(folder, `${basename(gameinfoPath)}.${stamp}.bak`), original, 'utf-8');
+        const all = listGameinfoBackups(gameinfoPath);
+        for (const stale of all.slice(0, Math.max(0, all.length - MAX_GAMEINFO_BACKUPS))) {
+            unlinkSync(join(folder, stale));
+        }
+    } catch (err) {
+        console.warn('[gameinfo] Failed to write a timestamped backup:', err);
+    }
+}
+
+// The minimal GameInfo shell the SearchPaths block can be inserted into.
+const EMPTY_GAMEINFO_SKELETON = '"GameInfo"\n{\n\tFileSystem\n\t{\n\t}\n}\n';
+
 // Insert the canonical SearchPaths block just inside the FileSystem section, for
 // the case where another tool stripped SearchPaths out entirely. Returns null if
 // there's no FileSystem block to repair (don't guess at an unknown structure).
@@ -161,6 +200,23 @@ function insertSearchPaths(content: string, block: string): string | null {
 import type { CleanupResult } from '../../../src/types/electron';
 export type { CleanupResult };
 
+/**
+ * Status for a gameinfo.gi that isn't on disk. Reported (not thrown) so the
+ * setup screen can show it next to any leftover gameinfo.* candidates.
+ */
+function missingGameinfoStatus(deadlockPath: string, message: string): GameinfoStatus {
+    return {
+        configured: false,
+        missing: true,
+        message,
+        candidates: findGameinfoCandidates(deadlockPath),
+    };
+}
+
+function isNotFoundError(err: unknown): boolean {
+    return (err as NodeJS.ErrnoException)?.code === 'ENOENT';
+}
+
 /**
  * Check if gameinfo.gi has the required SearchPaths entry
  */
@@ -168,12 +224,7 @@ export function getGameinfoStatus(deadlockPath: string): GameinfoStatus {
     const gameinfoPath = getGameinfoPath(deadlockPath);
 
     if (!existsSync(gameinfoPath)) {
-        return {
-            configured: false,
-            missing: true,
-            message: 'gameinfo.gi not found',
-            candidates: findGameinfoCandidates(deadlockPath),
-        };
+        return missingGameinfoStatus(deadlockPath, 'gameinfo.gi not found');
     }
 
     try {
@@ -236,6 +287,10 @@ export function getGameinfoStatus(deadlockPath: string): GameinfoStatus {
             candidates: findGameinfoCandidates(deadlockPath),
         };
     } catch (err) {
+        // Deleted between the existsSync check and the read.
+        if (isNotFoundError(err)) {
+            return missingGameinfoStatus(deadlockPath, 'gameinfo.gi not found');
+        }
         return {
             configured: false,
             missing: false,
@@ -246,81 +301,133 @@ export function getGameinfoStatus(deadlockPath: string): GameinfoStatus {
 }
 
 /**
- * Replace the SearchPaths section in gameinfo.gi with the canonical block
- * This ensures consistent mod loading regardless of the original file state
+ * The addon folders ('addons', 'addons1', ...) that gameinfo.gi actually
+ * mounts via an active Game search path. Null when gameinfo.gi is missing or
+ * has no parseable SearchPaths block, i.e. nothing is known to load.
  */
-export function fixGameinfo(deadlockPath: string): GameinfoStatus {
+export function getMountedAddonFolders(deadlockPath: string): Set<string> | null {
     const gameinfoPath = getGameinfoPath(deadlockPath);
-
-    if (!existsSync(gameinfoPath)) {
-        return {
-            configured: false,
-            missing: true,
-            message: 'gameinfo.gi not found',
-            candidates: findGameinfoCandidates(deadlockPath),
-        };
-    }
-
+    if (!existsSync(gameinfoPath)) return null;
     try {
-        const content = readFileSync(gameinfoPath, 'utf-8');
-        const block = findSearchPathsBlock(content);
+        const block = findSearchPathsBlock(readFileSync(gameinfoPath, 'utf-8'));
+        if (!block) return null;
+        const mounted = new Set<string>();
+        for (const name of ['addons', ...getOverflowFolderNames(deadlockPath)]) {
+            if (hasActivePath(block.body, `citadel/${name}`)) mounted.add(name.toLowerCase());
+        }
+        return mounted;
+    } catch {
+        return null;
+    }
+}
 
-        // The canonical block includes a Game line for every overflow folder that
-        // currently exists on disk (plus the Deadworks content path when present),
-        // so a user-triggered repair restores them too.
-        const overflow = getOverflowFolderNames(deadlockPath);
-        const includeDeadworks = hasDeadworksContentRoot(deadlockPath);
-        const canonical = buildSearchPathsBlock(overflow, includeDeadworks);
-
-        // Already correct: a real SearchPaths block with the required base paths,
-        // every existing overflow folder's Game line, AND the deadworks path when
-        // server content has been provisioned.
-        if (
-            block &&
-            hasRequiredSearchPaths(block.body) &&
-            overflow.every((name) => hasActivePath(block.body, `citadel/${name}`)) &&
-            (!includeDeadworks || hasActivePath(block.body, DEADWORKS_SEARCH_PATH))
-        ) {
-            return {
+/**
+ * What fixGameinfo would write for `content`: the rewritten file, or the
+ * status to report instead when it is already correct or can't be repaired.
+ * Never writes, so previewGameinfoFix can share it.
+ */
+function planGameinfoFix(
+    deadlockPath: string,
+    original: string
+): { next: string } | { status: GameinfoStatus } {
+    // An empty file (dev mode writes one; no real install ships it) has no
+    // structure to damage, so start it from a bare skeleton instead of
+    // failing on the missing FileSystem section below.
+    const content = original.trim() === '' ? EMPTY_GAMEINFO_SKELETON : original;
+    const block = findSearchPathsBlock(content);
+
+    // The canonical block includes a Game line for every overflow folder that
+    // currently exists on disk (plus the Deadworks content path when present),
+    // so a user-triggered repair restores them too.
+    const overflow = getOverflowFolderNames(deadlockPath);
+    const includeDeadworks = hasDeadworksContentRoot(deadlockPath);
+    const canonical = buildSearchPathsBlock(overflow, includeDeadworks);
+
+    // Already correct: a real SearchPaths block with the required base paths,
+    // every existing overflow folder's Game line, AND the deadworks path when
+    // server content has been provisioned.
+    if (
+        block &&
+        hasRequiredSearchPaths(block.body) &&
+        overflow.every((name) => hasActivePath(block.body, `citadel/${name}`)) &&
+        (!includeDeadworks || hasActivePath(block.body, DEADWORKS_SEARCH_PATH))
+    ) {
+        return {
+            status: {
                 configured: true,
                 missing: false,
                 message: 'Addon search paths were already configured',
                 candidates: [],
-            };
-        }
+            },
+        };
+    }
 
-        let next: string;
-        if (block) {
-            // Canonicalize: swap whatever SearchPaths block is present (including
-            // one a different mod manager rewrote) for our known-good version.
-            next = content.slice(0, block.start) + canonical + content.slice(block.end);
-        } else if (!/SearchPaths/.test(content)) {
-            // Another tool stripped SearchPaths out entirely. Rebuild it inside the
-            // FileSystem section so mods load again without a game reinstall.
-            const rebuilt = insertSearchPaths(content, canonical);
-            if (!rebuilt) {
-                return {
+    let next: string;
+    if (block) {
+        // Canonicalize: swap whatever SearchPaths block is present (including
+        // one a different mod manager rewrote) for our known-good version.
+        next = content.slice(0, block.start) + canonical + content.slice(block.end);
+    } else if (!/SearchPaths/.test(content)) {
+        // Another tool stripped SearchPaths out entirely. Rebuild it inside the
+        // FileSystem section so mods load again without a game reinstall.
+        const rebuilt = insertSearchPaths(content, canonical);
+        if (!rebuilt) {
+            return {
+                status: {
                     configured: false,
                     missing: false,
                     message: 'Could not find a FileSystem section to repair in gameinfo.gi. In Steam, verify the integrity of game files, then try again.',
                     candidates: findGameinfoCandidates(deadlockPath),
-                };
-            }
-            next = rebuilt;
-        } else {
-            // SearchPaths text is present but its braces do not parse (corrupted or
-            // an unusual format). Don't guess; let the user restore a clean file.
-            return {
+                },
+            };
+        }
+        next = rebuilt;
+    } else {
+        // SearchPaths text is present but its braces do not parse (corrupted or
+        // an unusual format). Don't guess; let the user restore a clean file.
+        return {
+            status: {
                 configured: false,
                 missing: false,
                 message: 'The SearchPaths section in gameinfo.gi could not be parsed. In Steam, verify the integrity of game files, then try again.',
                 candidates: findGameinfoCandidates(deadlockPath),
-            };
+            },
+        };
+    }
+    return { next };
+}
+
+/**
+ * Replace the SearchPaths section in gameinfo.gi with the canonical block
+ * This ensures consistent mod loading regardless of the original file state
+ */
+export function fixGameinfo(deadlockPath: string): GameinfoStatus {
+    const gameinfoPath = getGameinfoPath(deadlockPath);
+
+    // Read-only mode reports the current state instead of rewriting it, so the
+    // callers that only need "is it configured" keep working.
+    if (loadSettings().readOnlyMode === true) {
+        const status = getGameinfoStatus(deadlockPath);
+        return status.configured ? status : { ...status, message: READ_ONLY_MODE_MESSAGE };
+    }
+
+    if (!existsSync(gameinfoPath)) {
+        return missingGameinfoStatus(
+            deadlockPath,
+            'gameinfo.gi not found, so there is nothing to repair. In Steam, verify the integrity of game files, then try again.'
+        );
+    }
+
+    try {
+        const content = readFileSync(gameinfoPath, 'utf-8');
+        const plan = planGameinfoFix(deadlockPath, content);
+        if ('status' in plan) {
+            return plan.status;
         }
 
-        // Keep a one-time recovery copy before the first write.
-        backupGameinfoOnce(gameinfoPath, content);
-        writeFileSync(gameinfoPath, next, 'utf-8');
+        // Keep a recovery copy of what we're about to replace.
+        backupGameinfo(gameinfoPath, content);
+        writeFileSync(gameinfoPath, plan.next, 'utf-8');
 
         // Ensure the grimoire override folder exists so its (now-active) search
         // path points at a real directory rather than a missing one.
@@ -342,6 +449,53 @@ export function fixGameinfo(deadlockPath: string): GameinfoStatus {
     }
 }
 
+/**
+ * The change fixGameinfo would make, as a unified diff against the current
+ * gameinfo.gi, without writing anything. Empty when the file is already
+ * configured; throws with fixGameinfo's message when it couldn't repair it.
+ */
+export function previewGameinfoFix(deadlockPath: string): string {
+    const gameinfoPath = getGameinfoPath(deadlockPath);
+    if (!existsSync(gameinfoPath)) {
+        throw new Error('gameinfo.gi not found, so there is nothing to repair.');
+    }
+    const content = readFileSync(gameinfoPath, 'utf-8');
+    const plan = planGameinfoFix(deadlockPath, content);
+    if ('status' in plan) {
+        if (plan.status.configured) return '';
+        throw new Error(plan.status.message);
+    }
+    return unifiedDiff(content, plan.next, 'gameinfo.gi', 'gameinfo.gi (fixed)');
+}
+
+/**
+ * Put the newest timestamped gameinfo.gi backup back, for when a fix produced
+ * a file the game rejects. Falls back to the one-time original copy when no
+ * timestamped backup exists yet. The file being replaced is backed up first,
+ * so a second restore undoes the first. Returns the status of the restored file.
+ */
+export function restoreGameinfo(deadlockPath: string): GameinfoStatus {
+    assertNotReadOnly();
+    const gameinfoPath = getGameinfoPath(deadlockPath);
+    const backups = listGameinfoBackups(gameinfoPath);
+    const newest = backups[backups.length - 1];
+    const backupPath = newest
+        ? join(dirname(gameinfoPath), newest)
+        : `${gameinfoPath}${GAMEINFO_BACKUP_SUFFIX}`;
+    if (!existsSync(backupPath)) {
+        throw new Error('No gameinfo.gi backup to restore');
+    }
+    // Read the chosen backup before backing up the live file, which may
+    // become the newest backup itself.
+    const restored = readFileSync(backupPath, 'utf-8');
+    if (existsSync(gameinfoPath)) {
+        backupGameinfo(gameinfoPath, readFileSync(gameinfoPath, 'utf-8'));
+    }
+    writeFileAtomicSync(gameinfoPath, restored);
+    console.log(`[gameinfo] Restored gameinfo.gi from ${basename(backupPath)}`);
+    return getGameinfoStatus(deadlockPath);
+}
+
 /**
  * Ensure gameinfo.gi mounts the Deadworks content search path before a connect.
  *
@@ -362,6 +516,7 @@ export function ensureDeadworksSearchPath(deadlockPath: string): GameinfoStatus
  * Cleanup addons folder - remove leftover archives
  */
 export function cleanupAddons(deadlockPath: string): CleanupResult {
+    assertNotReadOnly();
     const result: CleanupResult = {
         removedArchives: 0,
     };
@@ -7,6 +7,7 @@ import {
     type GameBananaMod,
     type GameBananaModsResponse,
 } from './gamebanana';
+import { getModThumbnail } from '../../../src/types/gamebanana';
 import { parseVpkDirectory, parseVpkDirectoryCached } from './vpk';
 import { fingerprintFilesInWorkers, type FileFingerprintResult } from './workers';
 import {
@@ -45,6 +46,11 @@ export interface UnknownModCacheMatchInput {
     requestId?: string;
 }
 
+export interface UnknownModBatchInput extends UnknownModCacheMatchInput {
+    /** Per-mod cancel signal, so cancel-unknown-mod-detection stops one entry. */
+    signal?: AbortSignal;
+}
+
 export interface VpkHeroGuess {
     /** Canonical hero name, e.g. "Lady Geist". */
     name: string;
@@ -137,6 +143,10 @@ const CACHE_FINGERPRINT_CONCURRENCY = 8;
 // URLs directly (not the rate-limited GameBanana API), but each one can issue
 // several sequential range requests, so keep this modest.
 const PROBE_CONCURRENCY = 4;
+// Live searches in flight at once during a batch match. Each already fans out
+// PROBE_CONCURRENCY probes and its API calls share the GameBanana rate
+// limiter, so a wider pool would only queue behind it.
+const BATCH_LIVE_SEARCH_CONCURRENCY = 2;
 const CATEGORIES = {
     skins: 33295,
     modelReplacement: 33154,
@@ -626,6 +636,44 @@ export async function detectUnknownModCacheMatches(
     return [...results, ...skipped];
 }
 
+/**
+ * Match many unknown mods in one go: resolve what the local CRC cache already
+ * knows, then run a live GameBanana search for the rest with bounded
+ * concurrency. Every input gets an entry keyed by modId; a failed or cancelled
+ * lookup is recorded as a not-found/error guess instead of failing the batch.
+ */
+export async function detectUnknownModFiltersBatch(
+    inputs: UnknownModBatchInput[],
+    options: Pick<UnknownModDetectionOptions, 'onProgress'> = {}
+): Promise<Record<string, UnknownModFilterGuess>> {
+    const results: Record<string, UnknownModFilterGuess> = {};
+    for (const guess of await detectUnknownModCacheMatches(inputs, options)) {
+        results[guess.modId] = guess;
+    }
+
+    const misses = inputs.filter(
+        (input) =>
+            input.vpkPath.toLowerCase().endsWith('.vpk') &&
+            results[input.modId]?.crcMatch.status !== 'found'
+    );
+    await mapWithConcurrency(misses, BATCH_LIVE_SEARCH_CONCURRENCY, async (input) => {
+        if (input.signal?.aborted) return;
+        try {
+            results[input.modId] = await detectUnknownModFilters(input.modId, input.fileName, input.vpkPath, {
+                signal: input.signal,
+                requestId: input.requestId,
+                onProgress: options.onProgress,
+            });
+        } catch (err) {
+            results[input.modId] = {
+                ...buildUnknownGuessBase(input.modId, input.fileName, []),
+                crcMatch: emptyCrcMatch('error', errorMessage(err)),
+            };
+        }
+    });
+    return results;
+}
+
 function buildUnknownGuessBase(modId: string, fileName: string, paths: string[]): UnknownModFilterBase {
     const normalized = paths.map((path) => path.replace(/\\/g, '/').toLowerCase());
     const hero = findHeroHint(normalized);
@@ -996,8 +1044,7 @@ function toFoundMatch(
 }
 
 function getThumbnailUrl(mod: GameBananaMod): string | null {
-    const thumbnail = mod.previewMedia?.images?.[0];
-    return thumbnail ? `${thumbnail.baseUrl}/${thumbnail.file530 || thumbnail.file || thumbnail.file220}` : null;
+    return getModThumbnail(mod) ?? null;
 }
 
 function describeBucket(bucket: SearchBucket): string {
@@ -1,7 +1,9 @@
-import { openSync, readSync, closeSync, existsSync, statSync } from 'fs';
+import { openSync, readSync, closeSync, existsSync, statSync, writeFileSync } from 'fs';
+import { createHash } from 'crypto';
+import { crc32 } from 'zlib';
 import { heroForSoundCodename } from './heroSoundCodenames';
 import { parseVpksInWorkers } from './workers';
-import type { GlobalModType } from '../../../src/types/mod';
+import type { GlobalModType, VpkContents, VpkEntry, VpkTreeNode } from '../../../src/types/mod';
 
 /**
  * VPK Header Structure (Version 2):
@@ -18,6 +20,31 @@ import type { GlobalModType } from '../../../src/types/mod';
  */
 
 const VPK_SIGNATURE = 0x55AA1234;
+const VPK_HEADER_SIZE = 12;
+
+/** Why a VPK on disk can't be loaded, from checkVpkHeader. */
+export type VpkHeaderProblem = 'empty' | 'truncated' | 'bad-signature';
+
+/**
+ * Cheap sanity check for a VPK file without parsing its tree: null when it
+ * looks intact. Directory VPKs must hold a full header starting with the VPK
+ * magic; numbered chunk files (`_000.vpk`) are raw data and only need to be
+ * non-empty. Shared by the post-extraction check and the install journal.
+ */
+export function checkVpkHeader(filePath: string): VpkHeaderProblem | null {
+    const size = statSync(filePath).size;
+    if (size === 0) return 'empty';
+    if (/_\d{3}\.vpk$/i.test(filePath)) return null;
+    if (size < VPK_HEADER_SIZE) return 'truncated';
+    const fd = openSync(filePath, 'r');
+    try {
+        const header = Buffer.alloc(4);
+        readSync(fd, header, 0, 4, 0);
+        return header.readUInt32LE(0) === VPK_SIGNATURE ? null : 'bad-signature';
+    } finally {
+        closeSync(fd);
+    }
+}
 
 /**
  * Read a null-terminated string from a buffer at the given offset
@@ -209,6 +236,59 @@ export async function parseVpkDirectoriesAsync(
     return results;
 }
 
+/** One directory-tree entry as walkVpkTree reports it. */
+interface VpkTreeEntry {
+    /** Full game path, e.g. `materials/foo/bar.vtex_c`. */
+    path: string;
+    crc: number;
+    preloadBytes: number;
+    archiveIndex: number;
+    entryLength: number;
+}
+
+/**
+ * Walk a VPK directory tree (the bytes after the header), calling `onEntry`
+ * for each file in order. Structure: extension\0 (path\0 (filename\0
+ * entry_data)*)* until an empty extension. Entry data is CRC (4) +
+ * PreloadBytes (2) + ArchiveIndex (2) + EntryOffset (4) + EntryLength (4) +
+ * Terminator (2), followed by the preload bytes. `complete` is false when
+ * the buffer ran out before the tree's terminator (truncated or corrupt);
+ * `offset` is where the walk stopped.
+ */
+function walkVpkTree(tree: Buffer, onEntry: (entry: VpkTreeEntry) => void): { complete: boolean; offset: number } {
+    let offset = 0;
+    for (;;) {
+        if (offset >= tree.length) return { complete: false, offset };
+        const ext = readNullTerminatedString(tree, offset);
+        offset += ext.bytesRead;
+        if (ext.str === '') return { complete: true, offset };
+        for (;;) {
+            if (offset >= tree.length) return { complete: false, offset };
+            const dir = readNullTerminatedString(tree, offset);
+            offset += dir.bytesRead;
+            if (dir.str === '') break;
+            // Space means root directory in VPK format
+            const dirPath = dir.str === ' ' ? '' : dir.str;
+            for (;;) {
+                if (offset >= tree.length) return { complete: false, offset };
+                const name = readNullTerminatedString(tree, offset);
+                offset += name.bytesRead;
+                if (name.str === '') break;
+                if (offset + 18 > tree.length) return { complete: false, offset };
+                const preloadBytes = tree.readUInt16LE(offset + 4);
+                onEntry({
+                    path: dirPath ? `${dirPath}/${name.str}.${ext.str}` : `${name.str}.${ext.str}`,
+                    crc: tree.readUInt32LE(offset),
+                    preloadBytes,
+                    archiveIndex: tree.readUInt16LE(offset + 6),
+                    entryLength: tree.readUInt32LE(offset + 12),
+                });
+                offset += 18 + preloadBytes;
+            }
+        }
+    }
+}
+
 /**
  * Parse VPK directory tree to extract all file paths
  * Returns null if the file is not a valid VPK or can't be parsed
@@ -244,98 +324,242 @@ export function parseVpkDirectory(vpkPath: string): string[] | null {
         closeSync(fd);
 
         const paths: string[] = [];
-        let offset = 0;
-        let properlyTerminated = false;
-
-        // Parse directory tree
-        // Structure: extension\0 (path\0 (filename\0 entry_data)*)* until empty extension
-        while (offset < treeBuffer.length) {
-            // Read extension
-            const extResult = readNullTerminatedString(treeBuffer, offset);
-            offset += extResult.bytesRead;
-
-            if (extResult.str === '') {
-                properlyTerminated = true;
-                break; // End of tree (empty extension = proper termination)
-            }
+        const { complete, offset } = walkVpkTree(treeBuffer, (entry) => paths.push(entry.path));
 
-            const extension = extResult.str;
-            let extensionProperlyTerminated = false;
-
-            // Read paths for this extension
-            while (offset < treeBuffer.length) {
-                const pathResult = readNullTerminatedString(treeBuffer, offset);
-                offset += pathResult.bytesRead;
-
-                if (pathResult.str === '') {
-                    extensionProperlyTerminated = true;
-                    break; // End of paths for this extension
-                }
+        // Validate tree was properly terminated
+        if (!complete) {
+            console.warn(`[parseVpkDirectory] ${vpkPath}: tree did not terminate properly (offset ${offset}/${treeBuffer.length}). Some files may be missing from conflict detection.`);
+        }
 
-                // Space means root directory in VPK format
-                const dirPath = pathResult.str === ' ' ? '' : pathResult.str;
-                let pathProperlyTerminated = false;
+        // Check if there's unexpected data after tree termination
+        if (complete && offset < treeBuffer.length) {
+            const remainingBytes = treeBuffer.length - offset;
+            // Small amount of padding is acceptable, but large amounts suggest parsing error
+            if (remainingBytes > 16) {
+                console.warn(`[parseVpkDirectory] ${vpkPath}: ${remainingBytes} bytes remaining after tree termination.`);
+            }
+        }
 
-                // Read filenames for this path
-                while (offset < treeBuffer.length) {
-                    const nameResult = readNullTerminatedString(treeBuffer, offset);
-                    offset += nameResult.bytesRead;
+        return paths;
+    } catch (error) {
+        console.error(`[parseVpkDirectory] Error parsing ${vpkPath}:`, error);
+        return null;
+    }
+}
 
-                    if (nameResult.str === '') {
-                        pathProperlyTerminated = true;
-                        break; // End of filenames for this path
-                    }
+/** One file for writeVpkDirectory: its game path and contents. */
+export interface VpkWriteFile {
+    /** Game path with forward slashes, e.g. `materials/foo/bar.vmat_c`. */
+    path: string;
+    data: Buffer;
+}
 
-                    const filename = nameResult.str;
+// Entry data lives in the _dir.vpk itself rather than a numbered chunk.
+const VPK_DIR_ARCHIVE_INDEX = 0x7fff;
 
-                    // Build full path
-                    const fullPath = dirPath
-                        ? `${dirPath}/${filename}.${extension}`
-                        : `${filename}.${extension}`;
+/**
+ * Write `files` as a single-file v2 VPK (`*_dir.vpk`) at `destPath`: every
+ * entry's data is stored in the directory file after the tree, with the
+ * tree/archive/whole-file MD5 section Valve's tools write. Throws on an empty
+ * file list or a path given twice.
+ */
+export function writeVpkDirectory(files: VpkWriteFile[], destPath: string): void {
+    if (files.length === 0) {
+        throw new Error('Cannot write an empty VPK');
+    }
 
-                    paths.push(fullPath);
+    // extension -> directory -> [name, data]; ' ' stands for "none" in both.
+    const tree = new Map<string, Map<string, Array<[string, Buffer]>>>();
+    const seen = new Set<string>();
+    for (const file of files) {
+        const path = file.path.replace(/\\/g, '/').replace(/^\/+/, '');
+        if (!path || seen.has(path.toLowerCase())) {
+            throw new Error(`Invalid or duplicate VPK path: ${file.path}`);
+        }
+        seen.add(path.toLowerCase());
+        const slash = path.lastIndexOf('/');
+        const dir = slash >= 0 ? path.slice(0, slash) : ' ';
+        const base = path.slice(slash + 1);
+        const dot = base.lastIndexOf('.');
+        const name = dot > 0 ? base.slice(0, dot) : base;
+        const ext = dot > 0 ? base.slice(dot + 1) : ' ';
+        if (!tree.has(ext)) tree.set(ext, new Map());
+        const dirs = tree.get(ext)!;
+        if (!dirs.has(dir)) dirs.set(dir, []);
+        dirs.get(dir)!.push([name, file.data]);
+    }
 
-                    // Skip the entry data (18 bytes for version 2)
-                    // CRC (4) + PreloadBytes (2) + ArchiveIndex (2) + EntryOffset (4) + EntryLength (4) + Terminator (2)
-                    offset += 18;
+    const cstr = (s: string) => Buffer.concat([Buffer.from(s, 'utf-8'), Buffer.from([0])]);
+    const treeParts: Buffer[] = [];
+    const dataParts: Buffer[] = [];
+    let dataOffset = 0;
+    for (const [ext, dirs] of tree) {
+        treeParts.push(cstr(ext));
+        for (const [dir, entries] of dirs) {
+            treeParts.push(cstr(dir));
+            for (const [name, data] of entries) {
+                const entry = Buffer.alloc(18);
+                entry.writeUInt32LE(crc32(data), 0);
+                entry.writeUInt16LE(0, 4); // no preload bytes
+                entry.writeUInt16LE(VPK_DIR_ARCHIVE_INDEX, 6);
+                entry.writeUInt32LE(dataOffset, 8);
+                entry.writeUInt32LE(data.length, 12);
+                entry.writeUInt16LE(0xffff, 16);
+                treeParts.push(cstr(name), entry);
+                dataParts.push(data);
+                dataOffset += data.length;
+            }
+            treeParts.push(Buffer.from([0]));
+        }
+        treeParts.push(Buffer.from([0]));
+    }
+    treeParts.push(Buffer.from([0]));
+    const treeBuffer = Buffer.concat(treeParts);
+
+    const header = Buffer.alloc(28);
+    header.writeUInt32LE(VPK_SIGNATURE, 0);
+    header.writeUInt32LE(2, 4);
+    header.writeUInt32LE(treeBuffer.length, 8);
+    header.writeUInt32LE(dataOffset, 12); // file data section size
+    header.writeUInt32LE(0, 16); // archive MD5 section size
+    header.writeUInt32LE(48, 20); // other MD5 section size
+    header.writeUInt32LE(0, 24); // signature section size
+
+    const md5 = (...parts: Buffer[]) => {
+        const hash = createHash('md5');
+        for (const part of parts) hash.update(part);
+        return hash.digest();
+    };
+    const treeChecksum = md5(treeBuffer);
+    const archiveMd5Checksum = md5(Buffer.alloc(0));
+    const wholeFileChecksum = md5(header, treeBuffer, ...dataParts, treeChecksum, archiveMd5Checksum);
+
+    writeFileSync(
+        destPath,
+        Buffer.concat([header, treeBuffer, ...dataParts, treeChecksum, archiveMd5Checksum, wholeFileChecksum])
+    );
+}
 
-                    // Skip preload data if any
-                    // PreloadBytes is at offset 4 in the entry (after CRC), so offset - 14 after skipping 18
-                    if (offset - 14 >= 0 && offset - 14 < treeBuffer.length - 1) {
-                        const preloadBytes = treeBuffer.readUInt16LE(offset - 14);
-                        offset += preloadBytes;
-                    }
-                }
+/**
+ * Every entry in a VPK directory with its size, for showing a user what a mod
+ * contains. Unlike parseVpkDirectory (which returns null and logs), every
+ * failure is a thrown Error saying what is wrong: missing file, not a VPK, an
+ * unsupported version, or a tree cut short by truncation.
+ */
+export function readVpkEntries(vpkPath: string): { version: number; entries: VpkEntry[] } {
+    if (!existsSync(vpkPath)) {
+        throw new Error(`VPK not found: ${vpkPath}`);
+    }
+    const fd = openSync(vpkPath, 'r');
+    let version: number;
+    let treeBuffer: Buffer;
+    try {
+        const headerBuffer = Buffer.alloc(12);
+        if (readSync(fd, headerBuffer, 0, 12, 0) < 12 || headerBuffer.readUInt32LE(0) !== VPK_SIGNATURE) {
+            throw new Error('Not a VPK file (bad or missing header)');
+        }
+        version = headerBuffer.readUInt32LE(4);
+        if (version !== 1 && version !== 2) {
+            throw new Error(`Unsupported VPK version ${version}`);
+        }
+        const headerSize = version === 2 ? 28 : 12;
+        treeBuffer = Buffer.alloc(headerBuffer.readUInt32LE(8));
+        if (readSync(fd, treeBuffer, 0, treeBuffer.length, headerSize) < treeBuffer.length) {
+            throw new Error('VPK is truncated (directory tree is incomplete)');
+        }
+    } finally {
+        closeSync(fd);
+    }
 
-                // Warn if filename loop exited due to buffer exhaustion instead of proper termination
-                if (!pathProperlyTerminated) {
-                    console.warn(`[parseVpkDirectory] Warning: Filename section for path "${dirPath}" (ext: ${extension}) did not terminate properly - buffer exhausted at offset ${offset}/${treeBuffer.length}`);
-                }
-            }
+    const entries: VpkEntry[] = [];
+    const { complete } = walkVpkTree(treeBuffer, (entry) =>
+        entries.push({
+            path: entry.path,
+            size: entry.preloadBytes + entry.entryLength,
+            archiveIndex: entry.archiveIndex,
+        })
+    );
+    if (!complete) {
+        throw new Error('VPK directory tree is truncated or corrupt');
+    }
+    return { version, entries };
+}
 
-            // Warn if path loop exited due to buffer exhaustion instead of proper termination
-            if (!extensionProperlyTerminated) {
-                console.warn(`[parseVpkDirectory] Warning: Path section for extension "${extension}" did not terminate properly - buffer exhausted at offset ${offset}/${treeBuffer.length}`);
+/** Fold a flat entry list into a folder tree with per-folder totals. */
+export function buildVpkTree(entries: VpkEntry[]): VpkTreeNode[] {
+    const root: VpkTreeNode = { name: '', path: '', size: 0, fileCount: 0, children: [] };
+    for (const entry of entries) {
+        const parts = entry.path.split('/');
+        let node = root;
+        node.size += entry.size;
+        node.fileCount++;
+        for (let i = 0; i < parts.length - 1; i++) {
+            const path = parts.slice(0, i + 1).join('/');
+            let child = node.children!.find((c) => c.children && c.name === parts[i]);
+            if (!child) {
+                child = { name: parts[i], path, size: 0, fileCount: 0, children: [] };
+                node.children!.push(child);
             }
+            child.size += entry.size;
+            child.fileCount++;
+            node = child;
         }
+        node.children!.push({ name: parts[parts.length - 1], path: entry.path, size: entry.size, fileCount: 1 });
+    }
+    const sort = (nodes: VpkTreeNode[]): VpkTreeNode[] => {
+        nodes.sort((a, b) => Number(!a.children) - Number(!b.children) || a.name.localeCompare(b.name));
+        for (const node of nodes) if (node.children) sort(node.children);
+        return nodes;
+    };
+    return sort(root.children!);
+}
 
-        // Validate tree was properly terminated
-        if (!properlyTerminated) {
-            console.warn(`[parseVpkDirectory] ${vpkPath}: tree did not terminate properly (offset ${offset}/${treeBuffer.length}). Some files may be missing from conflict detection.`);
-        }
+/** readVpkEntries plus the folder tree and totals list-vpk-contents returns. */
+export function listVpkContents(vpkPath: string): VpkContents {
+    const { version, entries } = readVpkEntries(vpkPath);
+    return {
+        version,
+        fileCount: entries.length,
+        totalSize: entries.reduce((sum, e) => sum + e.size, 0),
+        tree: buildVpkTree(entries),
+    };
+}
 
-        // Check if there's unexpected data after tree termination
-        if (properlyTerminated && offset < treeBuffer.length) {
-            const remainingBytes = treeBuffer.length - offset;
-            // Small amount of padding is acceptable, but large amounts suggest parsing error
-            if (remainingBytes > 16) {
-                console.warn(`[parseVpkDirectory] ${vpkPath}: ${remainingBytes} bytes remaining after tree termination.`);
+/**
+ * Content fingerprint of every entry in a VPK, keyed by full path: the entry's
+ * CRC32 plus its preload and data lengths. Two mods shipping the same path
+ * with equal fingerprints ship the same bytes. Uncached; only the shared-
+ * texture reconcile reads it. Returns null if the file isn't a valid VPK.
+ */
+export function readVpkEntryFingerprints(vpkPath: string): Map<string, string> | null {
+    if (!existsSync(vpkPath)) {
+        return null;
+    }
+
+    try {
+        const fd = openSync(vpkPath, 'r');
+        let treeBuffer: Buffer;
+        try {
+            const headerBuffer = Buffer.alloc(12);
+            readSync(fd, headerBuffer, 0, 12, 0);
+            if (headerBuffer.readUInt32LE(0) !== VPK_SIGNATURE) {
+                return null;
             }
+            const headerSize = headerBuffer.readUInt32LE(4) === 2 ? 28 : 12;
+            treeBuffer = Buffer.alloc(headerBuffer.readUInt32LE(8));
+            readSync(fd, treeBuffer, 0, treeBuffer.length, headerSize);
+        } finally {
+            closeSync(fd);
         }
 
-        return paths;
+        // A truncated tree keeps the fingerprints read before the cut.
+        const fingerprints = new Map<string, string>();
+        walkVpkTree(treeBuffer, (entry) =>
+            fingerprints.set(entry.path, `${entry.crc.toString(16)}:${entry.preloadBytes}:${entry.entryLength}`)
+        );
+        return fingerprints;
     } catch (error) {
-        console.error(`[parseVpkDirectory] Error parsing ${vpkPath}:`, error);
+        console.error(`[readVpkEntryFingerprints] Error parsing ${vpkPath}:`, error);
         return null;
     }
 }
@@ -419,6 +643,57 @@ export function inferHeroFromVpkPaths(paths: string[]): string | null {
     return heroes.size === 1 ? [...heroes][0] : null;
 }
 
+/**
+ * Folder codenames that differ from the sound-path namespace
+ * HERO_SOUND_CODENAMES is keyed by: Abrams' models live under `atlas/`, and a
+ * few heroes still ship files under a legacy name.
+ */
+const HERO_CODENAME_ALIASES: Readonly<Record<string, string>> = {
+    atlas: 'abrams',
+    krill: 'mokrill',
+    geist: 'ghost',
+    archer: 'orion',
+    pocket: 'synth',
+};
+
+/**
+ * Bump when affectedHeroesFromVpkPaths changes. enrichMod folds it into the
+ * cached summary's stamp, so every mod is re-summarized on the next scan.
+ */
+export const AFFECTED_HEROES_VERSION = 1;
+
+/**
+ * Every hero a VPK's files touch, by display name, deduplicated and sorted.
+ * Skin paths (models/materials/particles under heroes) and sound paths both
+ * count. Unlike inferHeroFromVpkPaths this keeps going on a multi-hero pack:
+ * it is a library summary, not a single Locker tag. Folders that map to no
+ * known hero are dropped rather than shown as raw codenames.
+ */
+export function affectedHeroesFromVpkPaths(paths: string[]): string[] {
+    const heroes = new Set<string>();
+    for (const filePath of paths) {
+        const codenames: string[] = [];
+        const heroFolder = extractHeroFromPath(filePath);
+        if (heroFolder) codenames.push(heroFolder);
+        const soundMatch = SOUND_HERO_PATTERNS.map((pattern) => filePath.match(pattern)).find(Boolean);
+        if (soundMatch) codenames.push(soundMatch[1].toLowerCase());
+        for (const codename of codenames) {
+            const hero = heroForSoundCodename(HERO_CODENAME_ALIASES[codename] ?? codename);
+            if (hero) heroes.add(hero);
+        }
+    }
+    return [...heroes].sort((a, b) => a.localeCompare(b));
+}
+
+/**
+ * affectedHeroesFromVpkPaths over a VPK on disk (cached parse). Null when the
+ * directory can't be read, so callers don't cache a failure as "no heroes".
+ */
+export function affectedHeroesFromVpk(vpkPath: string): string[] | null {
+    const paths = parseVpkDirectoryCached(vpkPath);
+    return paths ? affectedHeroesFromVpkPaths(paths) : null;
+}
+
 /**
  * Convenience wrapper: parse the VPK directory and run the path-based
  * inference. Returns null when the VPK can't be parsed or no hero matches.
@@ -0,0 +1,44 @@
+import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
+import { existsSync, mkdtempSync, readFileSync, rmSync, writeFileSync } from 'fs';
+import { tmpdir } from 'os';
+import { join } from 'path';
+
+vi.mock('fs', async (importOriginal) => {
+  const actual = await importOriginal<typeof import('fs')>();
+  return { ...actual, renameSync: vi.fn(actual.renameSync) };
+});
+
+import { renameSync } from 'fs';
+import { writeFileAtomicSync } from './atomicWrite';
+
+describe('writeFileAtomicSync', () => {
+  let dir: string;
+
+  beforeEach(() => {
+    dir = mkdtempSync(join(tmpdir(), 'grimoire-atomic-'));
+  });
+
+  afterEach(() => {
+    rmSync(dir, { recursive: true, force: true });
+  });
+
+  it('replaces the file contents', () => {
+    const path = join(dir, 'settings.json');
+    writeFileSync(path, '{"old":true}');
+    writeFileAtomicSync(path, '{"new":true}');
+    expect(readFileSync(path, 'utf-8')).toBe('{"new":true}');
+    expect(existsSync(`${path}.tmp`)).toBe(false);
+  });
+
+  it('leaves the old file intact when interrupted between write and rename', () => {
+    const path = join(dir, 'settings.json');
+    writeFileSync(path, '{"old":true}');
+    vi.mocked(renameSync).mockImplementationOnce(() => {
+      throw new Error('killed mid-save');
+    });
+
+    expect(() => writeFileAtomicSync(path, '{"new":true}')).toThrow('killed mid-save');
+    expect(readFileSync(path, 'utf-8')).toBe('{"old":true}');
+    expect(existsSync(`${path}.tmp`)).toBe(false);
+  });
+});
@@ -0,0 +1,42 @@
+import { closeSync, existsSync, fsyncSync, mkdirSync, openSync, renameSync, unlinkSync, writeSync } from 'fs';
+import { dirname } from 'path';
+
+/**
+ * Replace `path` with `data` so a crash or power loss leaves either the old
+ * file or the new one, never a truncated mix. The data goes to `<path>.tmp`
+ * in the same folder and is fsynced before the rename: without the fsync a
+ * Steam Deck suspended right after the rename could come back with the new
+ * name pointing at empty blocks, which is the corruption the rename alone was
+ * meant to prevent. The temp file is removed when any step fails.
+ */
+export function writeFileAtomicSync(path: string, data: string | Buffer): void {
+    const tempPath = `${path}.tmp`;
+    mkdirSync(dirname(path), { recursive: true });
+    try {
+        const fd = openSync(tempPath, 'w');
+        try {
+            writeSync(fd, typeof data === 'string' ? Buffer.from(data, 'utf-8') : data);
+            fsyncSync(fd);
+        } finally {
+            closeSync(fd);
+        }
+        renameSync(tempPath, path);
+    } catch (error) {
+        try {
+            if (existsSync(tempPath)) unlinkSync(tempPath);
+        } catch { /* ignore cleanup errors */ }
+        throw error;
+    }
+    // Persist the rename itself. Directories can't be opened for fsync on
+    // Windows, where the rename is already durable enough.
+    if (process.platform !== 'win32') {
+        try {
+            const dirFd = openSync(dirname(path), 'r');
+            try {
+                fsyncSync(dirFd);
+            } finally {
+                closeSync(dirFd);
+            }
+        } catch { /* best-effort */ }
+    }
+}
@@ -0,0 +1,35 @@
+import { app } from 'electron';
+import { existsSync, mkdirSync } from 'fs';
+import { dirname, join, resolve } from 'path';
+
+/**
+ * Relocate the userData directory (settings, metadata, profiles, caches) for
+ * portable installs and SD-card configs. Imported for its side effect as the
+ * very first module in index.ts: every service derives its paths from
+ * app.getPath('userData'), and some read it at import time.
+ *
+ * Precedence:
+ *   1. GRIMOIRE_DATA_DIR env var (created if missing).
+ *   2. A `grimoire-data` folder next to the executable (or the AppImage file),
+ *      so a portable install just ships with that folder alongside it.
+ *   3. Electron's default.
+ */
+function resolveDataDirOverride(): string | null {
+    const fromEnv = process.env.GRIMOIRE_DATA_DIR?.trim();
+    if (fromEnv) return resolve(fromEnv);
+
+    const exeDir = process.env.APPIMAGE ? dirname(process.env.APPIMAGE) : dirname(process.execPath);
+    const portableDir = join(exeDir, 'grimoire-data');
+    return existsSync(portableDir) ? portableDir : null;
+}
+
+const dataDir = resolveDataDirOverride();
+if (dataDir) {
+    try {
+        mkdirSync(dataDir, { recursive: true });
+        app.setPath('userData', dataDir);
+        console.log(`[dataDir] Using custom data directory: ${dataDir}`);
+    } catch (err) {
+        console.warn(`[dataDir] Could not use ${dataDir}, falling back to default:`, err);
+    }
+}
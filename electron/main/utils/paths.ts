@@ -1,5 +1,6 @@
 import { app } from 'electron';
-import { join } from 'path';
+import { createHash } from 'crypto';
+import { join, resolve } from 'path';
 
 /**
  * Get the app's user data directory
@@ -16,10 +17,38 @@ export function getSettingsPath(): string {
 }
 
 /**
- * Get the mod metadata file path
+ * Get the mod metadata file path. With a Deadlock install path, returns that
+ * install's own file under mod-metadata/ (keyed by a hash of the path);
+ * without one, the legacy flat file shared by every install.
  */
-export function getMetadataPath(): string {
-    return join(getUserDataPath(), 'mod-metadata.json');
+export function getMetadataPath(deadlockPath?: string | null): string {
+    if (!deadlockPath) {
+        return join(getUserDataPath(), 'mod-metadata.json');
+    }
+    const normalized = process.platform === 'win32' ? resolve(deadlockPath).toLowerCase() : resolve(deadlockPath);
+    const key = createHash('sha256').update(normalized).digest('hex').slice(0, 16);
+    return join(getUserDataPath(), 'mod-metadata', `${key}.json`);
+}
+
+/**
+ * Get the profiles file path
+ */
+export function getProfilesPath(): string {
+    return join(getUserDataPath(), 'profiles.json');
+}
+
+/**
+ * Get the GameBanana browse cache (SQLite) file path
+ */
+export function getModCachePath(): string {
+    return join(getUserDataPath(), 'mods-cache.db');
+}
+
+/**
+ * Get the folder holding auxiliary (non-VPK) files kept from a mod's archive
+ */
+export function getModExtrasPath(gameBananaId: number): string {
+    return join(getUserDataPath(), 'mod-extras', String(gameBananaId));
 }
 
 /**
@@ -0,0 +1,41 @@
+import { describe, it, expect } from 'vitest';
+import { parseSearchPaths, indexOfGamePath, hasGamePathsInOrder } from './searchPaths';
+
+const REQUIRED = ['citadel/grimoire', 'citadel/addons', 'citadel'];
+
+const TAB_INDENTED = [
+  '\t\tGame\t\t\t\tcitadel/grimoire',
+  '\t\tGame\t\t\t\tcitadel/addons',
+  '\t\tMod\t\t\t\tcitadel',
+  '\t\tGame\t\t\t\tcitadel',
+  '\t\tAddonRoot\t\t\tcitadel_addons',
+].join('\n');
+
+const SPACE_INDENTED = [
+  '    Game  "citadel/grimoire"   // Locker overrides',
+  '    Game                citadel\\addons\\',
+  '    Game+Mod citadel',
+  '    AddonRoot citadel_addons',
+].join('\r\n');
+
+describe('parseSearchPaths', () => {
+  it('reads tab- and space-indented bodies the same way', () => {
+    expect(hasGamePathsInOrder(parseSearchPaths(TAB_INDENTED), REQUIRED)).toBe(true);
+    expect(hasGamePathsInOrder(parseSearchPaths(SPACE_INDENTED), REQUIRED)).toBe(true);
+  });
+
+  it('ignores commented-out entries and subfolders', () => {
+    const entries = parseSearchPaths('// Game citadel/addons\nGame citadel/addons/profile_default\n');
+    expect(indexOfGamePath(entries, 'citadel/addons')).toBe(-1);
+  });
+
+  it('only counts Game keys, and skips nested blocks', () => {
+    const entries = parseSearchPaths('Mod citadel/addons\nExtra { Game citadel/addons }\n');
+    expect(indexOfGamePath(entries, 'citadel/addons')).toBe(-1);
+  });
+
+  it('rejects the required paths out of order', () => {
+    const swapped = 'Game citadel/addons\nGame citadel/grimoire\nGame citadel\n';
+    expect(hasGamePathsInOrder(parseSearchPaths(swapped), REQUIRED)).toBe(false);
+  });
+});
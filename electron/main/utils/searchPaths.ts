@@ -0,0 +1,86 @@
+/**
+ * Structural reader for the body of gameinfo.gi's SearchPaths block. Valve's
+ * file mixes tabs and spaces, and hand-edited copies vary further, so entries
+ * are read as KeyValues tokens (key, then value) instead of matched as literal
+ * lines. Electron-free so it can be tested on its own.
+ */
+
+export interface SearchPathEntry {
+    /** Lowercased key, e.g. `game`, `mod`, `game+mod`. */
+    key: string;
+    /** Path as written, quotes removed. */
+    value: string;
+}
+
+// Quoted strings, braces, or bare words; `//` comments are dropped first.
+const TOKEN = /"([^"]*)"|([{}])|([^\s{}"]+)/g;
+
+function stripComment(line: string): string {
+    let inQuotes = false;
+    for (let i = 0; i < line.length; i++) {
+        if (line[i] === '"') inQuotes = !inQuotes;
+        else if (!inQuotes && line[i] === '/' && line[i + 1] === '/') return line.slice(0, i);
+    }
+    return line;
+}
+
+/**
+ * The key/value entries of a SearchPaths body, in file order. Nested blocks
+ * (`Key { ... }`) are skipped along with their contents, since only top-level
+ * entries are search paths.
+ */
+export function parseSearchPaths(body: string): SearchPathEntry[] {
+    const tokens: Array<{ text: string; brace: boolean }> = [];
+    for (const line of body.split(/\r?\n/)) {
+        for (const match of stripComment(line).matchAll(TOKEN)) {
+            if (match[2]) tokens.push({ text: match[2], brace: true });
+            else tokens.push({ text: match[1] ?? match[3], brace: false });
+        }
+    }
+
+    const entries: SearchPathEntry[] = [];
+    let depth = 0;
+    for (let i = 0; i < tokens.length; i++) {
+        const token = tokens[i];
+        if (token.brace) {
+            depth = token.text === '{' ? depth + 1 : Math.max(0, depth - 1);
+            continue;
+        }
+        const next = tokens[i + 1];
+        if (!next || next.brace) continue; // block name, or a dangling key
+        if (depth === 0) entries.push({ key: token.text.toLowerCase(), value: next.text });
+        i++;
+    }
+    return entries;
+}
+
+/** Lowercase, forward slashes, no doubled or trailing separators. */
+export function normalizeSearchPath(path: string): string {
+    return path.trim().toLowerCase().replace(/[\\/]+/g, '/').replace(/\/$/, '');
+}
+
+/**
+ * Position of the first `Game` entry (including combined keys like
+ * `Game+Mod`) mounting exactly `relPath`, or -1. A subfolder such as
+ * citadel/addons/profile_default does not count as citadel/addons.
+ */
+export function indexOfGamePath(entries: SearchPathEntry[], relPath: string): number {
+    const wanted = normalizeSearchPath(relPath);
+    return entries.findIndex(
+        (entry) => entry.key.split('+').includes('game') && normalizeSearchPath(entry.value) === wanted
+    );
+}
+
+/**
+ * True when every path in `relPaths` is mounted by a Game entry and they
+ * appear in that relative order (earlier entries win in Source 2).
+ */
+export function hasGamePathsInOrder(entries: SearchPathEntry[], relPaths: string[]): boolean {
+    let previous = -1;
+    for (const relPath of relPaths) {
+        const index = indexOfGamePath(entries, relPath);
+        if (index <= previous) return false;
+        previous = index;
+    }
+    return true;
+}
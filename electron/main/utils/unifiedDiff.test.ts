@@ -0,0 +1,20 @@
+import { describe, it, expect } from 'vitest';
+import { unifiedDiff } from './unifiedDiff';
+
+describe('unifiedDiff', () => {
+  it('is empty when nothing changes', () => {
+    expect(unifiedDiff('a\nb\n', 'a\nb\n')).toBe('');
+  });
+
+  it('emits one hunk with context around a replaced line', () => {
+    const before = ['1', '2', '3', '4', '5', '6', '7', '8'].join('\n');
+    const after = ['1', '2', '3', '4', 'five', '6', '7', '8'].join('\n');
+    expect(unifiedDiff(before, after, 'old', 'new')).toBe(
+      ['--- old', '+++ new', '@@ -2,7 +2,7 @@', ' 2', ' 3', ' 4', '-5', '+five', ' 6', ' 7', ' 8', ''].join('\n')
+    );
+  });
+
+  it('numbers a pure insertion against the line before it', () => {
+    expect(unifiedDiff('', 'added\n', 'a', 'b', 0)).toBe(['--- a', '+++ b', '@@ -0,0 +1,1 @@', '+added', ''].join('\n'));
+  });
+});
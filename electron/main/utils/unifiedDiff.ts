@@ -0,0 +1,85 @@
+/**
+ * Minimal line-based unified diff (`diff -u` style), for showing users what a
+ * config rewrite would change before it happens. LCS over whole lines, so it
+ * is meant for small files like gameinfo.gi, not large documents.
+ */
+
+type DiffOp = { kind: ' ' | '-' | '+'; line: string; oldNo: number; newNo: number };
+
+function splitLines(text: string): string[] {
+    const lines = text.replace(/\r\n/g, '\n').split('\n');
+    // A trailing newline shouldn't read as an extra empty line.
+    if (lines.length > 0 && lines[lines.length - 1] === '') lines.pop();
+    return lines;
+}
+
+function diffOps(a: string[], b: string[]): DiffOp[] {
+    // lcs[i][j] = LCS length of a[i..] and b[j..].
+    const lcs: number[][] = Array.from({ length: a.length + 1 }, () => new Array<number>(b.length + 1).fill(0));
+    for (let i = a.length - 1; i >= 0; i--) {
+        for (let j = b.length - 1; j >= 0; j--) {
+            lcs[i][j] = a[i] === b[j] ? lcs[i + 1][j + 1] + 1 : Math.max(lcs[i + 1][j], lcs[i][j + 1]);
+        }
+    }
+
+    const ops: DiffOp[] = [];
+    let i = 0;
+    let j = 0;
+    while (i < a.length || j < b.length) {
+        if (i < a.length && j < b.length && a[i] === b[j]) {
+            ops.push({ kind: ' ', line: a[i], oldNo: i, newNo: j });
+            i++;
+            j++;
+        } else if (i < a.length && (j === b.length || lcs[i + 1][j] >= lcs[i][j + 1])) {
+            // Deletions before insertions, like diff -u.
+            ops.push({ kind: '-', line: a[i], oldNo: i, newNo: j });
+            i++;
+        } else {
+            ops.push({ kind: '+', line: b[j], oldNo: i, newNo: j });
+            j++;
+        }
+    }
+    return ops;
+}
+
+/**
+ * Unified diff turning `oldText` into `newText`, with `context` unchanged
+ * lines around each hunk. Empty string when the texts match line for line.
+ */
+export function unifiedDiff(
+    oldText: string,
+    newText: string,
+    oldLabel = 'a',
+    newLabel = 'b',
+    context = 3
+): string {
+    const ops = diffOps(splitLines(oldText), splitLines(newText));
+    const changed = ops.flatMap((op, index) => (op.kind === ' ' ? [] : [index]));
+    if (changed.length === 0) return '';
+
+    // Group changes whose context windows touch into one hunk.
+    const ranges: Array<[number, number]> = [];
+    for (const index of changed) {
+        const start = Math.max(0, index - context);
+        const end = Math.min(ops.length - 1, index + context);
+        const last = ranges[ranges.length - 1];
+        if (last && start <= last[1] + 1) {
+            last[1] = Math.max(last[1], end);
+        } else {
+            ranges.push([start, end]);
+        }
+    }
+
+    const out = [`--- ${oldLabel}`, `+++ ${newLabel}`];
+    for (const [start, end] of ranges) {
+        const hunk = ops.slice(start, end + 1);
+        const oldCount = hunk.filter((op) => op.kind !== '+').length;
+        const newCount = hunk.filter((op) => op.kind !== '-').length;
+        // diff -u numbers an empty side by the line before it.
+        const oldStart = oldCount > 0 ? hunk[0].oldNo + 1 : hunk[0].oldNo;
+        const newStart = newCount > 0 ? hunk[0].newNo + 1 : hunk[0].newNo;
+        out.push(`@@ -${oldStart},${oldCount} +${newStart},${newCount} @@`);
+        for (const op of hunk) out.push(`${op.kind}${op.line}`);
+    }
+    return `${out.join('\n')}\n`;
+}
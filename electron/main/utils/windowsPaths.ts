@@ -0,0 +1,41 @@
+import { resolve } from 'path';
+
+/**
+ * Windows path helpers for files that come out of mod archives. Names that are
+ * fine on Linux (`a:b.vpk`, `con.vpk`, trailing dots) and deep archive folder
+ * trees past MAX_PATH used to make rename/copy fail mid-install with a bare
+ * ENOENT/EINVAL. Electron-free so extract.ts (and its tests) can use it.
+ */
+
+// CreateDirectory's limit: MAX_PATH (260) minus room for an 8.3 file name.
+const WINDOWS_MAX_DIR_PATH = 248;
+
+const WINDOWS_RESERVED_NAME = /^(con|prn|aux|nul|com[0-9]|lpt[0-9])(\..*)?$/i;
+
+/**
+ * A filename Windows accepts: characters it rejects (`<>:"/\|?*` and control
+ * characters) become `_`, trailing dots and spaces are dropped, and reserved
+ * device names (`con`, `nul`, `com1`, ...) get a leading `_`. Applied on every
+ * platform so an archive extracts to the same names everywhere.
+ */
+export function toWindowsSafeFileName(name: string): string {
+    let safe = Array.from(name, (c) => (c.charCodeAt(0) < 0x20 ? '_' : c))
+        .join('')
+        .replace(/[<>:"/\\|?*]/g, '_')
+        .replace(/[. ]+$/, '');
+    if (!safe) safe = '_';
+    if (WINDOWS_RESERVED_NAME.test(safe)) safe = `_${safe}`;
+    return safe;
+}
+
+/**
+ * `p` with the `\\?\` long-path prefix on Windows once it gets near MAX_PATH,
+ * so fs calls on deep extraction or mod folders keep working. Short paths and
+ * every other platform get `p` back unchanged.
+ */
+export function toLongPath(p: string): string {
+    if (process.platform !== 'win32') return p;
+    const abs = resolve(p);
+    if (abs.length < WINDOWS_MAX_DIR_PATH || abs.startsWith('\\\\?\\')) return p;
+    return abs.startsWith('\\\\') ? `\\\\?\\UNC\\${abs.slice(2)}` : `\\\\?\\${abs}`;
+}
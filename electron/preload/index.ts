@@ -55,7 +55,10 @@ import type {
     DownloadProgressData,
     DownloadEventData,
     DownloadErrorData,
+    InstallPhaseData,
     ModsAutoDisabledData,
+    DownloadCancelledData,
+    DownloadDuplicateData,
     DownloadQueueData,
     OneClickInstallData,
     OneClickSuspiciousFilesData,
@@ -65,7 +68,7 @@ import type {
     LockerImageVariant,
     CropRect,
 } from '../../src/types/electron';
-import type { AppearanceSurface } from '../../src/types/mod';
+import type { AppearanceSurface, ModListExportFormat } from '../../src/types/mod';
 import type { DeadworksConnectProgress } from '../../src/types/deadworks';
 import type { DmmMigrationRequest } from '../../src/lib/dmmMigration';
 import type {
@@ -85,6 +88,17 @@ contextBridge.exposeInMainWorld('electronAPI', {
     createDevDeadlockPath: () => ipcRenderer.invoke('create-dev-deadlock-path'),
     getSettings: () => ipcRenderer.invoke('get-settings'),
     setSettings: (settings: AppSettings) => ipcRenderer.invoke('set-settings', settings),
+    patchSettings: (partial: Partial<AppSettings>) => ipcRenderer.invoke('patch-settings', partial),
+    setLoadOrderLocked: (locked: boolean) => ipcRenderer.invoke('set-load-order-locked', locked),
+    setReadOnlyMode: (enabled: boolean) => ipcRenderer.invoke('set-read-only-mode', enabled),
+    setExtraExtractExtensions: (extensions: string[]) => ipcRenderer.invoke('set-extra-extract-extensions', extensions),
+    exportConfigBundle: (destPath: string) => ipcRenderer.invoke('export-config-bundle', destPath),
+    inspectConfigBundle: (srcPath: string) => ipcRenderer.invoke('inspect-config-bundle', srcPath),
+    importConfigBundle: (args: { srcPath: string; confirmOverwrite: boolean }) =>
+        ipcRenderer.invoke('import-config-bundle', args),
+    backupAddons: (destPath: string) => ipcRenderer.invoke('backup-addons', destPath),
+    restoreAddons: (args: { srcPath: string; confirmOverwrite: boolean }) =>
+        ipcRenderer.invoke('restore-addons', args),
 
     // Deadlock Mod Manager migration (adopt DMM's on-disk VPKs; no cloud)
     dmmMigrate: {
@@ -109,12 +123,33 @@ contextBridge.exposeInMainWorld('electronAPI', {
     getMods: () => ipcRenderer.invoke('get-mods'),
     enableMod: (modId: string) => ipcRenderer.invoke('enable-mod', modId),
     disableMod: (modId: string) => ipcRenderer.invoke('disable-mod', modId),
+    toggleMod: (modId: string) => ipcRenderer.invoke('toggle-mod', modId),
     deleteMod: (modId: string) => ipcRenderer.invoke('delete-mod', modId),
+    enableMods: (modIds: string[]) => ipcRenderer.invoke('enable-mods', modIds),
+    disableMods: (modIds: string[]) => ipcRenderer.invoke('disable-mods', modIds),
+    deleteMods: (modIds: string[]) => ipcRenderer.invoke('delete-mods', modIds),
+    getModFiles: (modId: string) => ipcRenderer.invoke('get-mod-files', modId),
+    exportModList: (format: ModListExportFormat) => ipcRenderer.invoke('export-mod-list', format),
+    normalizeLegacyPakNames: () => ipcRenderer.invoke('normalize-legacy-pak-names'),
+    checkAddonLimits: () => ipcRenderer.invoke('check-addon-limits'),
+    listVpkContents: (modId: string) => ipcRenderer.invoke('list-vpk-contents', modId),
+    findPakCollisions: () => ipcRenderer.invoke('find-pak-collisions'),
+    fixPakCollisions: () => ipcRenderer.invoke('fix-pak-collisions'),
+    quarantineMod: (modId: string, reason: string) => ipcRenderer.invoke('quarantine-mod', modId, reason),
+    listQuarantinedMods: () => ipcRenderer.invoke('list-quarantined-mods'),
+    releaseQuarantinedMod: (fileName: string) => ipcRenderer.invoke('release-quarantined-mod', fileName),
+    getMissingDisabledMods: () => ipcRenderer.invoke('get-missing-disabled-mods'),
+    dismissMissingDisabledMods: () => ipcRenderer.invoke('dismiss-missing-disabled-mods'),
+    listLibraryMods: () => ipcRenderer.invoke('list-library-mods'),
+    importFromLibrary: (fileName: string) => ipcRenderer.invoke('import-from-library', fileName),
+    moveModToLibrary: (modId: string) => ipcRenderer.invoke('move-mod-to-library', modId),
     revealModInFolder: (modId: string) => ipcRenderer.invoke('reveal-mod-in-folder', modId),
     detectUnknownModFilters: (modId: string, requestId?: string) =>
         ipcRenderer.invoke('detect-unknown-mod-filters', modId, requestId),
     detectUnknownModCacheBulk: (requests: Array<{ modId: string; requestId?: string }>) =>
         ipcRenderer.invoke('detect-unknown-mod-cache-bulk', requests),
+    matchUnknownMods: (requests: Array<{ modId: string; requestId?: string }>) =>
+        ipcRenderer.invoke('match-unknown-mods', requests),
     cancelUnknownModDetection: (modId: string) =>
         ipcRenderer.invoke('cancel-unknown-mod-detection', modId),
     onUnknownModDetectionProgress: (callback: (progress: UnknownModDetectionProgress) => void) => {
@@ -132,6 +167,8 @@ contextBridge.exposeInMainWorld('electronAPI', {
         ipcRenderer.invoke('list-unknown-mod-files', modId),
     editLocalMod: (modId: string, args: EditLocalModArgs) =>
         ipcRenderer.invoke('edit-local-mod', modId, args),
+    resetModMetadata: (modId: string) => ipcRenderer.invoke('reset-mod-metadata', modId),
+    repairMetadata: () => ipcRenderer.invoke('repair-metadata'),
     setVariantLabel: (modId: string, label: string) =>
         ipcRenderer.invoke('set-variant-label', modId, label),
     setModLockerHero: (modId: string, heroName: string | null) =>
@@ -232,12 +269,26 @@ contextBridge.exposeInMainWorld('electronAPI', {
         ipcRenderer.invoke('set-mod-global-type', modId, globalType),
     setModIgnoreUpdates: (modId: string, ignore: boolean) =>
         ipcRenderer.invoke('set-mod-ignore-updates', modId, ignore),
+    setModsCategory: (modIds: string[], categoryId: number, categoryName: string) =>
+        ipcRenderer.invoke('set-mods-category', modIds, categoryId, categoryName),
+    addTagToMods: (modIds: string[], tag: string) =>
+        ipcRenderer.invoke('add-tag-to-mods', modIds, tag),
+    removeTagFromMods: (modIds: string[], tag: string) =>
+        ipcRenderer.invoke('remove-tag-from-mods', modIds, tag),
     backfillGameBananaFileId: (
         modId: string,
         payload: { gameBananaFileId: number; fileDescription?: string; sourceFileName?: string }
     ) => ipcRenderer.invoke('backfill-gamebanana-file-id', modId, payload),
     setModPriority: (modId: string, priority: number) =>
         ipcRenderer.invoke('set-mod-priority', modId, priority),
+    enableModAt: (modId: string, priority: number) =>
+        ipcRenderer.invoke('enable-mod-at', modId, priority),
+    setModRelativePosition: (modId: string, fraction: number) =>
+        ipcRenderer.invoke('set-mod-relative-position', modId, fraction),
+    findFreeSlots: (min: number, max: number, count: number) =>
+        ipcRenderer.invoke('find-free-slots', min, max, count),
+    refreshModNames: () => ipcRenderer.invoke('refresh-mod-names'),
+    refreshModMetadata: (modIds: string[]) => ipcRenderer.invoke('refresh-mod-metadata', modIds),
     reorderMods: (orderedIds: string[]) =>
         ipcRenderer.invoke('reorder-mods', orderedIds),
     applyModToggleBatch: (enableIds: string[], disableIds: string[]) =>
@@ -246,6 +297,8 @@ contextBridge.exposeInMainWorld('electronAPI', {
         ipcRenderer.invoke('swap-mod-priority', modIdA, modIdB),
     importCustomMod: (args: ImportCustomModArgs) =>
         ipcRenderer.invoke('import-custom-mod', args),
+    importVpkFolder: (folderPath: string, move?: boolean) =>
+        ipcRenderer.invoke('import-vpk-folder', folderPath, move),
     importSoulContainerGlb: (args: ImportSoulContainerGlbArgs) =>
         ipcRenderer.invoke('import-soul-container-glb', args),
     exportSoulContainerGlb: (args: ImportSoulContainerGlbArgs) =>
@@ -329,15 +382,30 @@ contextBridge.exposeInMainWorld('electronAPI', {
 
     // GameBanana
     browseMods: (args: BrowseModsArgs) => ipcRenderer.invoke('browse-mods', args),
+    getLastBrowseResult: (args: Omit<BrowseModsArgs, 'page'>) => ipcRenderer.invoke('get-last-browse-result', args),
+    clearBrowseCache: () => ipcRenderer.invoke('clear-browse-cache'),
+    getFeaturedMods: (limit?: number) => ipcRenderer.invoke('get-featured-mods', limit),
+    fetchNewModsSince: (args: { since?: number; section?: string }) =>
+        ipcRenderer.invoke('fetch-new-mods-since', args),
     getModDetails: (args: GetModDetailsArgs) => ipcRenderer.invoke('get-mod-details', args),
     getModFileList: (args: GetModDetailsArgs) => ipcRenderer.invoke('get-mod-file-list', args),
     getModComments: (args: GetModCommentsArgs) => ipcRenderer.invoke('get-mod-comments', args),
     getModUpdates: (args: GetModUpdatesArgs) => ipcRenderer.invoke('get-mod-updates', args),
+    checkModUpdates: () => ipcRenderer.invoke('check-mod-updates'),
     getSubmitterLinks: (memberId: number) => ipcRenderer.invoke('get-submitter-links', memberId),
+    fetchSubmitterProfile: (args: { submitterId: number; section?: string }) =>
+        ipcRenderer.invoke('fetch-submitter-profile', args),
     downloadMod: (args: DownloadModArgs) => ipcRenderer.invoke('download-mod', args),
+    retryDownload: (args: { modId: number; fileId: number }) => ipcRenderer.invoke('retry-download', args),
+    getDownloadSize: (args: { modId: number; fileId: number; section?: string }) =>
+        ipcRenderer.invoke('get-download-size', args),
+    estimateDownloadTime: (fileSize: number) => ipcRenderer.invoke('estimate-download-time', fileSize),
     getGameBananaSections: () => ipcRenderer.invoke('get-gamebanana-sections'),
     getGameBananaCategories: (args: GetCategoriesArgs) =>
         ipcRenderer.invoke('get-gamebanana-categories', args),
+    getTopCategories: (args: GetCategoriesArgs & { limit?: number }) =>
+        ipcRenderer.invoke('get-top-categories', args),
+    getCategoryIcon: (url: string) => ipcRenderer.invoke('get-category-icon', url),
     getCollection: (args: { collectionId: number }) =>
         ipcRenderer.invoke('get-collection', args),
     getCollectionItems: (args: { collectionId: number; page?: number }) =>
@@ -347,8 +415,15 @@ contextBridge.exposeInMainWorld('electronAPI', {
     copyImageToClipboard: (source: string) =>
         ipcRenderer.invoke('copy-image-to-clipboard', source),
     cleanupAddons: () => ipcRenderer.invoke('cleanup-addons'),
+    findIncompleteInstalls: () => ipcRenderer.invoke('find-incomplete-installs'),
+    checkModsEffective: () => ipcRenderer.invoke('check-mods-effective'),
+    getGameinfoCoverage: () => ipcRenderer.invoke('get-gameinfo-coverage'),
+    fixGameinfoCoverage: () => ipcRenderer.invoke('fix-gameinfo-coverage'),
+    cleanupIncompleteInstalls: (paths: string[]) => ipcRenderer.invoke('cleanup-incomplete-installs', paths),
     getGameinfoStatus: () => ipcRenderer.invoke('get-gameinfo-status'),
     fixGameinfo: () => ipcRenderer.invoke('fix-gameinfo'),
+    previewGameinfoFix: () => ipcRenderer.invoke('preview-gameinfo-fix'),
+    restoreGameinfo: () => ipcRenderer.invoke('restore-gameinfo'),
     getPerformanceConfigStatus: () => ipcRenderer.invoke('get-performance-config-status'),
     applyPerformanceConfig: () => ipcRenderer.invoke('apply-performance-config'),
     removePerformanceConfig: () => ipcRenderer.invoke('remove-performance-config'),
@@ -398,17 +473,33 @@ contextBridge.exposeInMainWorld('electronAPI', {
         ipcRenderer.on('download-error', handler);
         return () => ipcRenderer.removeListener('download-error', handler);
     },
+    onInstallPhase: (callback: (data: InstallPhaseData) => void) => {
+        const handler = (_event: Electron.IpcRendererEvent, data: InstallPhaseData) => callback(data);
+        ipcRenderer.on('install-phase', handler);
+        return () => ipcRenderer.removeListener('install-phase', handler);
+    },
     onModsAutoDisabled: (callback: (data: ModsAutoDisabledData) => void) => {
         const handler = (_event: Electron.IpcRendererEvent, data: ModsAutoDisabledData) => callback(data);
         ipcRenderer.on('mods-auto-disabled', handler);
         return () => ipcRenderer.removeListener('mods-auto-disabled', handler);
     },
+    onDownloadDuplicate: (callback: (data: DownloadDuplicateData) => void) => {
+        const handler = (_event: Electron.IpcRendererEvent, data: DownloadDuplicateData) => callback(data);
+        ipcRenderer.on('download-duplicate', handler);
+        return () => ipcRenderer.removeListener('download-duplicate', handler);
+    },
+    onDownloadCancelled: (callback: (data: DownloadCancelledData) => void) => {
+        const handler = (_event: Electron.IpcRendererEvent, data: DownloadCancelledData) => callback(data);
+        ipcRenderer.on('download-cancelled', handler);
+        return () => ipcRenderer.removeListener('download-cancelled', handler);
+    },
 
     // Download Queue
     getDownloadQueue: () => ipcRenderer.invoke('get-download-queue'),
     getCurrentDownload: () => ipcRenderer.invoke('get-current-download'),
     removeFromQueue: (modId: number) => ipcRenderer.invoke('remove-from-queue', modId),
     cancelActiveDownload: () => ipcRenderer.invoke('cancel-active-download'),
+    cancelDownload: (modId: number, fileId: number) => ipcRenderer.invoke('cancel-download', modId, fileId),
     onDownloadQueueUpdated: (callback: (data: DownloadQueueData) => void) => {
         const handler = (_event: Electron.IpcRendererEvent, data: DownloadQueueData) => callback(data);
         ipcRenderer.on('download-queue-updated', handler);
@@ -440,7 +531,11 @@ contextBridge.exposeInMainWorld('electronAPI', {
         ipcRenderer.invoke('multi-vpk-pick-response', { requestId, selected }),
 
     // Conflicts
-    getConflicts: () => ipcRenderer.invoke('get-conflicts'),
+    getConflicts: (forceRescan?: boolean) => ipcRenderer.invoke('get-conflicts', forceRescan),
+    findModsContainingPath: (query: string) => ipcRenderer.invoke('find-mods-containing-path', query),
+    findShadowedMods: () => ipcRenderer.invoke('find-shadowed-mods'),
+    rankModsByOverrideWins: () => ipcRenderer.invoke('rank-mods-by-override-wins'),
+    reconcileSharedTextures: () => ipcRenderer.invoke('reconcile-shared-textures'),
     getIgnoredConflicts: () => ipcRenderer.invoke('get-ignored-conflicts'),
     ignoreConflict: (modA: string, modB: string) =>
         ipcRenderer.invoke('ignore-conflict', modA, modB),
@@ -456,6 +551,10 @@ contextBridge.exposeInMainWorld('electronAPI', {
         ipcRenderer.invoke('ignore-conflict-file-global', filePath),
     unignoreConflictFileGlobal: (filePath: string) =>
         ipcRenderer.invoke('unignore-conflict-file-global', filePath),
+    markKnownConflict: (modIdA: string, modIdB: string) =>
+        ipcRenderer.invoke('mark-known-conflict', modIdA, modIdB),
+    clearKnownConflict: (modIdA: string, modIdB: string) =>
+        ipcRenderer.invoke('clear-known-conflict', modIdA, modIdB),
     getIgnoredConflictMods: () => ipcRenderer.invoke('get-ignored-conflict-mods'),
     ignoreConflictMod: (identity: string) =>
         ipcRenderer.invoke('ignore-conflict-mod', identity),
@@ -469,6 +568,8 @@ contextBridge.exposeInMainWorld('electronAPI', {
         ipcRenderer.invoke('create-profile-from-gamebanana-ids', args),
     updateProfile: (profileId: string, crosshairSettings?: ProfileCrosshairSettings) => ipcRenderer.invoke('update-profile', profileId, crosshairSettings),
     applyProfile: (profileId: string) => ipcRenderer.invoke('apply-profile', profileId),
+    validateProfile: (profileId: string) => ipcRenderer.invoke('validate-profile', profileId),
+    diffProfile: (profileId: string) => ipcRenderer.invoke('diff-profile', profileId),
     deleteProfile: (profileId: string) => ipcRenderer.invoke('delete-profile', profileId),
     renameProfile: (profileId: string, newName: string) => ipcRenderer.invoke('rename-profile', profileId, newName),
     exportPortableProfile: (profileId: string) => ipcRenderer.invoke('export-portable-profile', profileId),
@@ -540,6 +641,9 @@ contextBridge.exposeInMainWorld('electronAPI', {
     diagnostics: {
         buildReport: (description: string, options?: { includeFullLog?: boolean }) =>
             ipcRenderer.invoke('diagnostics:buildReport', description, options),
+        getAppPaths: () => ipcRenderer.invoke('diagnostics:getAppPaths'),
+        exportSupportBundle: (destPath: string, description: string) =>
+            ipcRenderer.invoke('diagnostics:exportSupportBundle', destPath, description),
     },
 
     // Grimoire Social
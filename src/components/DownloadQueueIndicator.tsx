@@ -113,8 +113,8 @@ export default function DownloadQueueIndicator({ className = '' }: DownloadQueue
         };
     }, []);
 
-    const handleCancelQueued = async (modId: number) => {
-        await window.electronAPI.removeFromQueue(modId);
+    const handleCancelQueued = async (modId: number, fileId: number) => {
+        await window.electronAPI.cancelDownload(modId, fileId);
     };
 
     const handleCancelActive = async () => {
@@ -311,7 +311,7 @@ export default function DownloadQueueIndicator({ className = '' }: DownloadQueue
                                             type="button"
                                             onClick={(e) => {
                                                 e.stopPropagation();
-                                                void handleCancelQueued(item.modId);
+                                                void handleCancelQueued(item.modId, item.fileId);
                                             }}
                                             className="rounded-md p-1 text-text-secondary opacity-0 transition-opacity hover:text-state-danger group-hover:opacity-100 cursor-pointer"
                                             title={t('downloadQueue.removeFromQueue')}
@@ -146,7 +146,7 @@ function skipReasonLabel(reason: SkipReason): string {
 // Render file preview thumbnail via the same getter as collection items so
 // the picker UI stays visually consistent.
 function previewThumb(media: GameBananaPreviewMedia | undefined): string | undefined {
-  return getModThumbnail({ previewMedia: media } as Parameters<typeof getModThumbnail>[0]);
+  return getModThumbnail({ previewMedia: media });
 }
 
 export default function ImportCollectionModal({
@@ -11,7 +11,7 @@ import { Button } from './common/ui';
 import { ConfirmModal } from './common/PageComponents';
 import { ToastStack } from './common/ToastStack';
 import { showToast } from '../stores/toastStore';
-import { getSettings, setSettings, getGameinfoStatus, fixGameinfo } from '../lib/api';
+import { getSettings, setSettings, getGameinfoCoverage, fixGameinfoCoverage } from '../lib/api';
 import { getActiveDeadlockPath } from '../lib/appSettings';
 import { applyAccentColor } from '../lib/accentColor';
 import { useAppStore } from '../stores/appStore';
@@ -28,6 +28,7 @@ export default function Layout() {
   const [loading, setLoading] = useState(true);
   const [gameinfoAlert, setGameinfoAlert] = useState<string | null>(null);
   const [isFixingGameinfo, setIsFixingGameinfo] = useState(false);
+  const [uncoveredModCount, setUncoveredModCount] = useState(0);
   // Normal one-click download progress is handled by DownloadQueueIndicator.
   // This only catches failures before a download can be queued.
   const [suspiciousPrompt, setSuspiciousPrompt] = useState<OneClickSuspiciousFilesData | null>(null);
@@ -57,8 +58,9 @@ export default function Layout() {
         const activePath = getActiveDeadlockPath(settings);
         if (activePath) {
           try {
-            const status = await getGameinfoStatus();
-            setGameinfoAlert(status.configured ? null : status.message);
+            const coverage = await getGameinfoCoverage();
+            setGameinfoAlert(coverage.gameinfo.configured ? null : coverage.gameinfo.message);
+            setUncoveredModCount(coverage.uncovered.length);
           } catch (err) {
             setGameinfoAlert(`Failed to check gameinfo.gi: ${err}`);
           }
@@ -162,8 +164,9 @@ export default function Layout() {
   const handleFixGameinfo = async () => {
     setIsFixingGameinfo(true);
     try {
-      const result = await fixGameinfo();
-      setGameinfoAlert(result.configured ? null : result.message);
+      const coverage = await fixGameinfoCoverage();
+      setGameinfoAlert(coverage.gameinfo.configured ? null : coverage.gameinfo.message);
+      setUncoveredModCount(coverage.uncovered.length);
     } catch (err) {
       setGameinfoAlert(`Failed to fix gameinfo.gi: ${err}`);
     } finally {
@@ -209,6 +212,7 @@ export default function Layout() {
               <AlertTriangle className="h-5 w-5 text-yellow-400" />
               <div className="flex-1 text-sm">
                 <span className="font-semibold">{t('layout.gameinfoIssue')}</span> {gameinfoAlert}
+                {uncoveredModCount > 0 && ` ${t('layout.gameinfoUncoveredMods', { count: uncoveredModCount })}`}
               </div>
               <div className="flex items-center gap-2">
                 <Button variant="warning" size="sm" onClick={handleFixGameinfo} isLoading={isFixingGameinfo}>
@@ -455,6 +455,22 @@ export default function Sidebar() {
     return unsub;
   }, [loadMods, navigate, t]);
 
+  // A download that matched an installed mod byte for byte was dropped rather
+  // than installed twice; say which mod the user already has.
+  useEffect(() => {
+    const unsub = window.electronAPI.onDownloadDuplicate((data) => {
+      setToast({
+        kind: 'info',
+        text: t('sidebar.toast.duplicateDownload', { name: data.existingModName }),
+        action: {
+          label: t('common.view'),
+          onClick: () => navigate('/'),
+        },
+      });
+    });
+    return unsub;
+  }, [navigate, t]);
+
   const navItems = useMemo(() => {
     type BadgeTone = 'muted' | 'warning' | 'info';
     type NavItem = {
@@ -1,4 +1,4 @@
-import type { Mod, AppSettings, GlobalModType, UnknownModFilterGuess, UnknownModDetectionProgress, ApplyUnknownModMatchArgs, ApplyUnknownCustomModArgs, AssociateUnknownModArgs, UnknownModFileList, EditLocalModArgs, MergeModsArgs, UnmergeModResult, ExtractMergeSourceResult, ApplyHeroCardResult, HeroAbilitySlot, AbilitySlot, AbilitySoundParams, ActiveHeroSound, ApplyHeroSoundResult, ActiveHeroColor, ApplyHeroColorResult, ApplyHeroPrismResult, ActiveTrippySkin, ApplyTrippySkinResult, ApplyTrippyVfxResult, TrippySpriteOptions, TrippySpriteResult, TrippyVfxChoice, LockerOverview, LockerCardThumbnail, LockerClearScope, AppearanceSurface } from '../types/mod';
+import type { Mod, AppSettings, GlobalModType, UnknownModFilterGuess, UnknownModDetectionProgress, ApplyUnknownModMatchArgs, ApplyUnknownCustomModArgs, AssociateUnknownModArgs, UnknownModFileList, EditLocalModArgs, MergeModsArgs, UnmergeModResult, ExtractMergeSourceResult, ApplyHeroCardResult, HeroAbilitySlot, AbilitySlot, AbilitySoundParams, ActiveHeroSound, ApplyHeroSoundResult, ActiveHeroColor, ApplyHeroColorResult, ApplyHeroPrismResult, ActiveTrippySkin, ApplyTrippySkinResult, ApplyTrippyVfxResult, TrippySpriteOptions, TrippySpriteResult, TrippyVfxChoice, LockerOverview, LockerCardThumbnail, LockerClearScope, AppearanceSurface, ModPathMatch, ModNameResolution, ShadowedMod, SharedTextureReport, ModOverrideStats, MetadataRepair, QuarantinedMod, LibraryMod, MissingDisabledModsReport, ModFile, ModListExportFormat, VpkFolderImportResult, LegacyPakRename, PakCollision, PakRenumber, AddonLimitCheck, VpkContents, InstalledModUpdate, ModBatchResult } from '../types/mod';
 import type { DmmMigrationRequest, DmmMigrationReport } from './dmmMigration';
 import type {
   HeroPortrait,
@@ -10,6 +10,7 @@ import type {
 } from '../types/portrait';
 import type {
   GameBananaModsResponse,
+  GameBananaNewModsResponse,
   GameBananaModDetails,
   GameBananaModFileList,
   GameBananaSection,
@@ -20,6 +21,7 @@ import type {
   GameBananaCollection,
   GameBananaCollectionItemsResponse,
   GameBananaArtistLink,
+  GameBananaSubmitterProfile,
 } from '../types/gamebanana';
 import type { DownloadedLocale, LocaleManifest } from '../types/locales';
 import { parseFeModel, type ClothModel } from './feModel';
@@ -50,6 +52,7 @@ async function withGameRunningWarning<T>(operation: () => Promise<T>): Promise<T
 // Re-export types for convenience
 export type {
   GameBananaModsResponse,
+  GameBananaNewModsResponse,
   GameBananaModDetails,
   GameBananaModFileList,
   GameBananaSection,
@@ -58,6 +61,7 @@ export type {
   GameBananaModUpdatesResponse,
   GameBananaCollection,
   GameBananaCollectionItemsResponse,
+  GameBananaSubmitterProfile,
 };
 
 // Settings
@@ -81,6 +85,44 @@ export async function setSettings(settings: AppSettings): Promise<void> {
   return window.electronAPI.setSettings(settings);
 }
 
+export async function patchSettings(partial: Partial<AppSettings>): Promise<AppSettings> {
+  return window.electronAPI.patchSettings(partial);
+}
+
+export async function setLoadOrderLocked(locked: boolean): Promise<boolean> {
+  return window.electronAPI.setLoadOrderLocked(locked);
+}
+
+export async function setReadOnlyMode(enabled: boolean): Promise<boolean> {
+  return window.electronAPI.setReadOnlyMode(enabled);
+}
+
+export async function setExtraExtractExtensions(extensions: string[]): Promise<string[]> {
+  return window.electronAPI.setExtraExtractExtensions(extensions);
+}
+
+export async function exportConfigBundle(destPath: string): Promise<ConfigBundleManifest> {
+  return window.electronAPI.exportConfigBundle(destPath);
+}
+
+export async function inspectConfigBundle(srcPath: string): Promise<ConfigBundleManifest> {
+  return window.electronAPI.inspectConfigBundle(srcPath);
+}
+
+export async function importConfigBundle(srcPath: string, confirmOverwrite: boolean): Promise<ConfigBundleManifest> {
+  return window.electronAPI.importConfigBundle({ srcPath, confirmOverwrite });
+}
+
+/** Zip every addon folder plus mod metadata to `destPath`. */
+export async function backupAddons(destPath: string): Promise<AddonsBackupManifest> {
+  return window.electronAPI.backupAddons(destPath);
+}
+
+/** Replace every installed mod with a backup's contents. */
+export async function restoreAddons(srcPath: string, confirmOverwrite: boolean): Promise<AddonsBackupManifest> {
+  return withGameRunningWarning(() => window.electronAPI.restoreAddons({ srcPath, confirmOverwrite }));
+}
+
 // Deadlock Mod Manager migration (adopt DMM's on-disk VPKs; no cloud)
 export async function dmmMigrateScan(req: DmmMigrationRequest): Promise<DmmMigrationReport> {
   return window.electronAPI.dmmMigrate.scan(req);
@@ -103,10 +145,88 @@ export async function disableMod(modId: string): Promise<Mod> {
   return withGameRunningWarning(() => window.electronAPI.disableMod(modId));
 }
 
+export async function toggleMod(modId: string): Promise<Mod> {
+  return withGameRunningWarning(() => window.electronAPI.toggleMod(modId));
+}
+
 export async function deleteMod(modId: string): Promise<void> {
   return withGameRunningWarning(() => window.electronAPI.deleteMod(modId));
 }
 
+/** Enable several mods in one call; check `failures` for the ids that didn't. */
+export async function enableMods(modIds: string[]): Promise<ModBatchResult> {
+  return withGameRunningWarning(() => window.electronAPI.enableMods(modIds));
+}
+
+export async function disableMods(modIds: string[]): Promise<ModBatchResult> {
+  return withGameRunningWarning(() => window.electronAPI.disableMods(modIds));
+}
+
+export async function deleteMods(modIds: string[]): Promise<ModBatchResult> {
+  return withGameRunningWarning(() => window.electronAPI.deleteMods(modIds));
+}
+
+export async function getModFiles(modId: string): Promise<ModFile[]> {
+  return window.electronAPI.getModFiles(modId);
+}
+
+export async function exportModList(format: ModListExportFormat): Promise<string> {
+  return window.electronAPI.exportModList(format);
+}
+
+/** Rename legacy `textures-pak21_dir.vpk`-style files to `pak21_textures_dir.vpk`. */
+export async function normalizeLegacyPakNames(): Promise<LegacyPakRename[]> {
+  return withGameRunningWarning(() => window.electronAPI.normalizeLegacyPakNames());
+}
+
+export async function checkAddonLimits(): Promise<AddonLimitCheck> {
+  return window.electronAPI.checkAddonLimits();
+}
+
+export async function listVpkContents(modId: string): Promise<VpkContents> {
+  return window.electronAPI.listVpkContents(modId);
+}
+
+export async function findPakCollisions(): Promise<PakCollision[]> {
+  return window.electronAPI.findPakCollisions();
+}
+
+export async function fixPakCollisions(): Promise<PakRenumber[]> {
+  return withGameRunningWarning(() => window.electronAPI.fixPakCollisions());
+}
+
+export async function quarantineMod(modId: string, reason: string): Promise<QuarantinedMod> {
+  return withGameRunningWarning(() => window.electronAPI.quarantineMod(modId, reason));
+}
+
+export async function listQuarantinedMods(): Promise<QuarantinedMod[]> {
+  return window.electronAPI.listQuarantinedMods();
+}
+
+export async function releaseQuarantinedMod(fileName: string): Promise<Mod> {
+  return window.electronAPI.releaseQuarantinedMod(fileName);
+}
+
+export async function getMissingDisabledMods(): Promise<MissingDisabledModsReport | null> {
+  return window.electronAPI.getMissingDisabledMods();
+}
+
+export async function dismissMissingDisabledMods(): Promise<void> {
+  return window.electronAPI.dismissMissingDisabledMods();
+}
+
+export async function listLibraryMods(): Promise<LibraryMod[]> {
+  return window.electronAPI.listLibraryMods();
+}
+
+export async function importFromLibrary(fileName: string): Promise<Mod> {
+  return window.electronAPI.importFromLibrary(fileName);
+}
+
+export async function moveModToLibrary(modId: string): Promise<LibraryMod> {
+  return withGameRunningWarning(() => window.electronAPI.moveModToLibrary(modId));
+}
+
 export async function revealModInFolder(modId: string): Promise<void> {
   return window.electronAPI.revealModInFolder(modId);
 }
@@ -121,6 +241,12 @@ export async function detectUnknownModCacheBulk(
   return window.electronAPI.detectUnknownModCacheBulk(requests);
 }
 
+export async function matchUnknownMods(
+  requests: Array<{ modId: string; requestId?: string }>
+): Promise<Record<string, UnknownModFilterGuess>> {
+  return window.electronAPI.matchUnknownMods(requests);
+}
+
 export async function cancelUnknownModDetection(modId: string): Promise<void> {
   return window.electronAPI.cancelUnknownModDetection(modId);
 }
@@ -151,6 +277,14 @@ export async function editLocalMod(modId: string, args: EditLocalModArgs): Promi
   return window.electronAPI.editLocalMod(modId, args);
 }
 
+export async function resetModMetadata(modId: string): Promise<Mod> {
+  return window.electronAPI.resetModMetadata(modId);
+}
+
+export async function repairMetadata(): Promise<MetadataRepair[]> {
+  return window.electronAPI.repairMetadata();
+}
+
 export async function setVariantLabel(modId: string, label: string): Promise<Mod> {
   return window.electronAPI.setVariantLabel(modId, label);
 }
@@ -428,6 +562,25 @@ export async function setModIgnoreUpdates(
   return window.electronAPI.setModIgnoreUpdates(modId, ignore);
 }
 
+/** Put every given mod under one GameBanana category. Returns the updated mods. */
+export async function setModsCategory(
+  modIds: string[],
+  categoryId: number,
+  categoryName: string
+): Promise<Mod[]> {
+  return window.electronAPI.setModsCategory(modIds, categoryId, categoryName);
+}
+
+/** Add a library tag to every given mod (no-op where it's already tagged). */
+export async function addTagToMods(modIds: string[], tag: string): Promise<Mod[]> {
+  return window.electronAPI.addTagToMods(modIds, tag);
+}
+
+/** Drop a library tag (matched case-insensitively) from every given mod. */
+export async function removeTagFromMods(modIds: string[], tag: string): Promise<Mod[]> {
+  return window.electronAPI.removeTagFromMods(modIds, tag);
+}
+
 export async function backfillGameBananaFileId(
   modId: string,
   payload: { gameBananaFileId: number; fileDescription?: string; sourceFileName?: string }
@@ -439,6 +592,26 @@ export async function setModPriority(modId: string, priority: number): Promise<M
   return withGameRunningWarning(() => window.electronAPI.setModPriority(modId, priority));
 }
 
+export async function enableModAt(modId: string, priority: number): Promise<Mod> {
+  return withGameRunningWarning(() => window.electronAPI.enableModAt(modId, priority));
+}
+
+export async function setModRelativePosition(modId: string, fraction: number): Promise<Mod> {
+  return withGameRunningWarning(() => window.electronAPI.setModRelativePosition(modId, fraction));
+}
+
+export async function findFreeSlots(min: number, max: number, count: number): Promise<number[]> {
+  return window.electronAPI.findFreeSlots(min, max, count);
+}
+
+export async function refreshModNames(): Promise<ModNameResolution[]> {
+  return window.electronAPI.refreshModNames();
+}
+
+export async function refreshModMetadata(modIds: string[]): Promise<Mod[]> {
+  return window.electronAPI.refreshModMetadata(modIds);
+}
+
 export async function reorderMods(orderedIds: string[]): Promise<Mod[]> {
   return withGameRunningWarning(() => window.electronAPI.reorderMods(orderedIds));
 }
@@ -465,6 +638,12 @@ export async function importCustomMod(args: {
   return window.electronAPI.importCustomMod(args);
 }
 
+/** Install every loose `_dir.vpk` (and its chunks) in a folder as its own mod.
+ *  `move` deletes each source file once it has been imported. */
+export async function importVpkFolder(folderPath: string, move = false): Promise<VpkFolderImportResult[]> {
+  return withGameRunningWarning(() => window.electronAPI.importVpkFolder(folderPath, move));
+}
+
 /** Build a soul-container override VPK from a user GLB and install it as a
  *  tracked local mod. Returns the full enriched mod list after install. */
 export async function importSoulContainerGlb(
@@ -737,6 +916,30 @@ export async function browseMods(
   return window.electronAPI.browseMods({ page, perPage, search, section, categoryId, sort, submitterId });
 }
 
+export async function getLastBrowseResult(
+  perPage: number,
+  search?: string,
+  section?: string,
+  categoryId?: number,
+  sort?: string,
+  submitterId?: number
+): Promise<LastBrowseResult | null> {
+  return window.electronAPI.getLastBrowseResult({ perPage, search, section, categoryId, sort, submitterId });
+}
+
+export async function clearBrowseCache(): Promise<void> {
+  return window.electronAPI.clearBrowseCache();
+}
+
+export async function getFeaturedMods(limit?: number): Promise<GameBananaModsResponse> {
+  return window.electronAPI.getFeaturedMods(limit);
+}
+
+/** Mods added since `since` (unix seconds), or since the last check when omitted. */
+export async function fetchNewModsSince(since?: number, section?: string): Promise<GameBananaNewModsResponse> {
+  return window.electronAPI.fetchNewModsSince({ since, section });
+}
+
 export async function getModFileList(modId: number, section?: string): Promise<GameBananaModFileList> {
   return window.electronAPI.getModFileList({ modId, section });
 }
@@ -757,19 +960,41 @@ export async function getModUpdates(modId: number, section?: string, page = 1):
   return window.electronAPI.getModUpdates({ modId, section, page });
 }
 
+/** Installed GameBanana mods that changed upstream since they were downloaded. */
+export async function checkModUpdates(): Promise<InstalledModUpdate[]> {
+  return window.electronAPI.checkModUpdates();
+}
+
 export async function getSubmitterLinks(memberId: number): Promise<GameBananaArtistLink[]> {
   return window.electronAPI.getSubmitterLinks(memberId);
 }
 
+export async function fetchSubmitterProfile(submitterId: number, section?: string): Promise<GameBananaSubmitterProfile> {
+  return window.electronAPI.fetchSubmitterProfile({ submitterId, section });
+}
+
 export async function downloadMod(
   modId: number,
   fileId: number,
   fileName: string,
   section?: string,
   categoryId?: number,
-  modName?: string
-): Promise<void> {
-  return withGameRunningWarning(() => window.electronAPI.downloadMod({ modId, fileId, fileName, section, categoryId, modName }));
+  modName?: string,
+  force?: boolean
+): Promise<DownloadModResult> {
+  return withGameRunningWarning(() => window.electronAPI.downloadMod({ modId, fileId, fileName, section, categoryId, modName, force }));
+}
+
+export async function retryDownload(modId: number, fileId: number): Promise<DownloadModResult> {
+  return withGameRunningWarning(() => window.electronAPI.retryDownload({ modId, fileId }));
+}
+
+export async function getDownloadSize(modId: number, fileId: number, section?: string): Promise<DownloadSizeInfo> {
+  return window.electronAPI.getDownloadSize({ modId, fileId, section });
+}
+
+export async function estimateDownloadTime(fileSize: number): Promise<DownloadEstimate> {
+  return window.electronAPI.estimateDownloadTime(fileSize);
 }
 
 export async function getGamebananaSections(): Promise<GameBananaSection[]> {
@@ -782,6 +1007,17 @@ export async function getGamebananaCategories(
   return window.electronAPI.getGameBananaCategories({ categoryModelName });
 }
 
+export async function getTopCategories(
+  categoryModelName: string,
+  limit?: number
+): Promise<GameBananaCategoryNode[]> {
+  return window.electronAPI.getTopCategories({ categoryModelName, limit });
+}
+
+export async function getCategoryIcon(url: string): Promise<CachedImage> {
+  return window.electronAPI.getCategoryIcon(url);
+}
+
 export async function getCollection(collectionId: number): Promise<GameBananaCollection> {
   return window.electronAPI.getCollection({ collectionId });
 }
@@ -799,6 +1035,26 @@ export async function cleanupAddons(): Promise<{
   return window.electronAPI.cleanupAddons();
 }
 
+export async function findIncompleteInstalls(): Promise<IncompleteInstall[]> {
+  return window.electronAPI.findIncompleteInstalls();
+}
+
+export async function checkModsEffective(): Promise<ModEffectivenessReport> {
+  return window.electronAPI.checkModsEffective();
+}
+
+export async function getGameinfoCoverage(): Promise<GameinfoCoverageReport> {
+  return window.electronAPI.getGameinfoCoverage();
+}
+
+export async function fixGameinfoCoverage(): Promise<GameinfoCoverageReport> {
+  return window.electronAPI.fixGameinfoCoverage();
+}
+
+export async function cleanupIncompleteInstalls(paths: string[]): Promise<number> {
+  return window.electronAPI.cleanupIncompleteInstalls(paths);
+}
+
 export async function getGameinfoStatus(): Promise<{ configured: boolean; message: string; missing: boolean; candidates: string[] }> {
   return window.electronAPI.getGameinfoStatus();
 }
@@ -807,6 +1063,14 @@ export async function fixGameinfo(): Promise<{ configured: boolean; message: str
   return window.electronAPI.fixGameinfo();
 }
 
+export async function previewGameinfoFix(): Promise<string> {
+  return window.electronAPI.previewGameinfoFix();
+}
+
+export async function restoreGameinfo(): Promise<{ configured: boolean; message: string; missing: boolean; candidates: string[] }> {
+  return window.electronAPI.restoreGameinfo();
+}
+
 export async function getPerformanceConfigStatus(): Promise<PerformanceConfigStatus> {
   return window.electronAPI.getPerformanceConfigStatus();
 }
@@ -851,6 +1115,14 @@ export async function buildDiagnosticReport(
   return window.electronAPI.diagnostics.buildReport(description, options);
 }
 
+export async function getAppPaths(): Promise<AppPaths> {
+  return window.electronAPI.diagnostics.getAppPaths();
+}
+
+export async function exportSupportBundle(destPath: string, description: string): Promise<string> {
+  return window.electronAPI.diagnostics.exportSupportBundle(destPath, description);
+}
+
 // Dialog helper for Settings page
 export async function showOpenDialog(options: {
   directory?: boolean;
@@ -891,18 +1163,28 @@ export interface ModConflict {
    *  (after subtracting any individually ignored files). Undefined for
    *  `priority` conflicts. */
   files?: string[];
+  /** pakNN slot and addon folder ('addons', 'addons1', ...) of each side. */
+  modAPriority: number;
+  modAFolder: string;
+  modBPriority: number;
+  modBFolder: string;
+  /** For `file` conflicts: id of the mod whose copy the game loads. Undefined
+   *  for `priority` conflicts (shared slot, order undefined). */
+  winner?: string;
 }
 
 // Conflict detection re-parses every enabled VPK on the main process, so
 // firing it twice for the same store update (Sidebar badge + Installed
 // page) doubled the freeze window. Concurrent callers share the in-flight
 // promise; once it resolves, the next call starts a fresh scan so any
-// state change since then is picked up immediately.
+// state change since then is picked up immediately. An unchanged library is
+// answered from the main process's fingerprinted cache; `forceRescan` always
+// reparses, so it never joins a cached in-flight call.
 let conflictsInFlight: Promise<ModConflict[]> | null = null;
 
-export async function getConflicts(): Promise<ModConflict[]> {
-  if (conflictsInFlight) return conflictsInFlight;
-  const promise = window.electronAPI.getConflicts();
+export async function getConflicts(forceRescan = false): Promise<ModConflict[]> {
+  if (conflictsInFlight && !forceRescan) return conflictsInFlight;
+  const promise = window.electronAPI.getConflicts(forceRescan);
   conflictsInFlight = promise;
   promise.finally(() => {
     if (conflictsInFlight === promise) conflictsInFlight = null;
@@ -910,6 +1192,22 @@ export async function getConflicts(): Promise<ModConflict[]> {
   return promise;
 }
 
+export async function findModsContainingPath(query: string): Promise<ModPathMatch[]> {
+  return window.electronAPI.findModsContainingPath(query);
+}
+
+export async function findShadowedMods(): Promise<ShadowedMod[]> {
+  return window.electronAPI.findShadowedMods();
+}
+
+export async function rankModsByOverrideWins(): Promise<ModOverrideStats[]> {
+  return window.electronAPI.rankModsByOverrideWins();
+}
+
+export async function reconcileSharedTextures(): Promise<SharedTextureReport> {
+  return window.electronAPI.reconcileSharedTextures();
+}
+
 export async function getIgnoredConflicts(): Promise<string[]> {
   return window.electronAPI.getIgnoredConflicts();
 }
@@ -953,6 +1251,14 @@ export async function unignoreConflictFileGlobal(filePath: string): Promise<stri
   return window.electronAPI.unignoreConflictFileGlobal(filePath);
 }
 
+export async function markKnownConflict(modIdA: string, modIdB: string): Promise<void> {
+  return window.electronAPI.markKnownConflict(modIdA, modIdB);
+}
+
+export async function clearKnownConflict(modIdA: string, modIdB: string): Promise<void> {
+  return window.electronAPI.clearKnownConflict(modIdA, modIdB);
+}
+
 export async function getIgnoredConflictMods(): Promise<string[]> {
   return window.electronAPI.getIgnoredConflictMods();
 }
@@ -978,8 +1284,8 @@ export function conflictPairKey(a: string, b: string): string {
 
 // Profile wire types are single-sourced in types/electron.ts; re-exported
 // here to preserve this module's existing import surface.
-export type { Profile, ProfileMod, ProfileCrosshairSettings, ApplyProfileResult } from '../types/electron';
-import type { Profile, ProfileCrosshairSettings, ApplyProfileResult, PerformanceConfigStatus, EditorCandidate, LockerImageVariant, LockerImageEdit, CropRect } from '../types/electron';
+export type { Profile, ProfileMod, ProfileCrosshairSettings, ApplyProfileResult, ProfileValidationResult, ProfileDiff, ProfileDiffAction } from '../types/electron';
+import type { Profile, ProfileCrosshairSettings, ApplyProfileResult, ProfileValidationResult, ProfileDiff, PerformanceConfigStatus, EditorCandidate, LockerImageVariant, LockerImageEdit, CropRect, IncompleteInstall, ModEffectivenessReport, GameinfoCoverageReport, AppPaths, ConfigBundleManifest, AddonsBackupManifest, DownloadModResult, LastBrowseResult, CachedImage, DownloadSizeInfo, DownloadEstimate } from '../types/electron';
 
 export async function getProfiles(): Promise<Profile[]> {
   return window.electronAPI.getProfiles();
@@ -1004,6 +1310,14 @@ export async function applyProfile(profileId: string): Promise<ApplyProfileResul
   return withGameRunningWarning(() => window.electronAPI.applyProfile(profileId));
 }
 
+export async function validateProfile(profileId: string): Promise<ProfileValidationResult> {
+  return window.electronAPI.validateProfile(profileId);
+}
+
+export async function diffProfile(profileId: string): Promise<ProfileDiff> {
+  return window.electronAPI.diffProfile(profileId);
+}
+
 export async function deleteProfile(profileId: string): Promise<void> {
   return window.electronAPI.deleteProfile(profileId);
 }
@@ -61,6 +61,8 @@ import {
 } from '../components/common/BrandIcons';
 import {
   browseMods,
+  getLastBrowseResult,
+  clearBrowseCache,
   getModDetails,
   getSubmitterLinks,
   downloadMod,
@@ -77,6 +79,7 @@ import type {
   GameBananaSection,
   GameBananaCategoryNode,
   GameBananaArtistLink,
+  GameBananaModsResponse,
 } from '../types/gamebanana';
 import { getModThumbnail, getSoundPreviewUrl, getPrimaryFile, formatDate, isModOutdated } from '../types/gamebanana';
 import {
@@ -1704,18 +1707,27 @@ export default function Browse() {
     setError(null);
 
     try {
-      const response = await browseMods(
-        page,
-        perPage,
-        // In artist mode the grid is scoped by submitter; text search and
-        // category don't combine cleanly with that on the API, so they're
-        // dropped while viewing an artist.
-        submitter ? undefined : (effectiveSearch || undefined),
-        section,
-        submitter ? undefined : effectiveCategoryId,
-        sort !== 'default' ? sort : undefined,
-        submitter?.id
-      );
+      // In artist mode the grid is scoped by submitter; text search and
+      // category don't combine cleanly with that on the API, so they're
+      // dropped while viewing an artist.
+      const querySearch = submitter ? undefined : (effectiveSearch || undefined);
+      const queryCategoryId = submitter ? undefined : effectiveCategoryId;
+      const querySort = sort !== 'default' ? sort : undefined;
+
+      // A cold page-1 load (fresh remount) first asks the main process for
+      // the pages it already served for this query, so remounting Browse
+      // doesn't fire a burst of identical requests at GameBanana.
+      const remembered = page === 1 && modsRef.current.length === 0
+        ? await getLastBrowseResult(perPage, querySearch, section, queryCategoryId, querySort, submitter?.id)
+          .catch(() => null)
+        : null;
+      const loadedPage = remembered ? remembered.pages.length : page;
+      const response: GameBananaModsResponse = remembered
+        ? {
+          ...remembered.pages[remembered.pages.length - 1],
+          records: remembered.pages.flatMap((p) => p.records),
+        }
+        : await browseMods(page, perPage, querySearch, section, queryCategoryId, querySort, submitter?.id);
 
       // Enrich results with cached NSFW status from local database
       // The API doesn't reliably return NSFW flags in list responses
@@ -1742,19 +1754,27 @@ export default function Browse() {
         page === 1
           ? dedupeModsById(enrichedRecords)
           : appendUniqueModsById(modsRef.current, enrichedRecords);
-      const nextHasMore = response.records.length === perPage && page * perPage < response.totalCount;
-
+      // A restored result holds every remembered page; a normal fetch holds one.
+      const requestedCount = remembered ? loadedPage * perPage : perPage;
+      const nextHasMore = response.records.length === requestedCount && loadedPage * perPage < response.totalCount;
+
+      if (loadedPage !== page) {
+        // Restored several pages at once: advance the page counter without
+        // letting the effect re-fetch the last restored page.
+        lastFetchedStampRef.current = `${loadedPage}|${fetchFilterStamp}`;
+        setPage(loadedPage);
+      }
       setMods(nextMods);
       setTotalCount(response.totalCount);
       setHasMore(nextHasMore);
       modsRef.current = nextMods;
-      pageRef.current = page;
+      pageRef.current = loadedPage;
       totalCountRef.current = response.totalCount;
       hasMoreRef.current = nextHasMore;
       const cachedScrollTop = browseScrollCacheRef.current.get(fetchFilterStamp) ?? latestScrollTopRef.current;
       browseResultsCacheRef.current.set(fetchFilterStamp, {
         mods: nextMods,
-        page,
+        page: loadedPage,
         hasMore: nextHasMore,
         totalCount: response.totalCount,
         scrollTop: cachedScrollTop,
@@ -2239,6 +2259,8 @@ export default function Browse() {
     try {
       // Sync current section from GameBanana API to local DB
       await window.electronAPI.syncSection(section);
+      // A manual refresh must reach GameBanana, not the main-process page cache.
+      await clearBrowseCache();
       // Reset state and force re-fetch
       setMods([]);
       setHasMore(true);
@@ -2354,7 +2376,14 @@ export default function Browse() {
     }
 
     try {
-      await downloadMod(selectedMod.id, fileId, fileName, section, effectiveCategoryId);
+      const result = await downloadMod(selectedMod.id, fileId, fileName, section, effectiveCategoryId);
+      // Nothing was queued, so no download-complete event will clear the UI.
+      if (result.alreadyInstalled) {
+        setDownloading((cur) =>
+          cur && cur.modId === selectedMod.id && cur.fileId === fileId ? null : cur
+        );
+        setDownloadProgress(null);
+      }
     } catch (err) {
       setError(String(err));
       // Reset the active UI only if the file that failed is the active one.
@@ -2374,7 +2403,11 @@ export default function Browse() {
       setExtracting(false);
     }
     try {
-      await downloadMod(modId, file.id, file.fileName, section, effectiveCategoryId);
+      const result = await downloadMod(modId, file.id, file.fileName, section, effectiveCategoryId);
+      if (result.alreadyInstalled) {
+        setDownloading((cur) => (cur && cur.modId === modId && cur.fileId === file.id ? null : cur));
+        setDownloadProgress(null);
+      }
     } catch (err) {
       setError(String(err));
       if (downloading?.modId === modId) {
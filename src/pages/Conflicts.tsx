@@ -199,7 +199,7 @@ export default function Conflicts() {
   const [pendingPair, setPendingPair] = useState<string | null>(null);
   const { loadMods } = useAppStore();
 
-  const loadConflicts = async () => {
+  const loadConflicts = async (forceRescan = false) => {
     setLoading(true);
     setError(null);
     try {
@@ -211,7 +211,7 @@ export default function Conflicts() {
         ignoredFilesGlobalResult,
         ignoredModsResult,
       ] = await Promise.all([
-        getConflicts(),
+        getConflicts(forceRescan),
         getMods(),
         getIgnoredConflicts(),
         getIgnoredConflictFiles(),
@@ -554,7 +554,7 @@ export default function Conflicts() {
           description={error ?? undefined}
           variant="error"
           action={
-            <Button onClick={loadConflicts}>
+            <Button onClick={() => loadConflicts()}>
               <Tx k="common.actions.retry" fallback="Retry" />
             </Button>
           }
@@ -584,7 +584,7 @@ export default function Conflicts() {
             />
           }
           action={
-            <Button variant="secondary" onClick={loadConflicts} icon={RefreshCw}>
+            <Button variant="secondary" onClick={() => loadConflicts(true)} icon={RefreshCw}>
               <Tx k="common.actions.refresh" fallback="Refresh" />
             </Button>
           }
@@ -638,7 +638,7 @@ export default function Conflicts() {
                 <Tx k="conflicts.actions.ignoreAll" fallback="Ignore all" />
               </Button>
             )}
-            <Button variant="secondary" onClick={loadConflicts} icon={RefreshCw}>
+            <Button variant="secondary" onClick={() => loadConflicts(true)} icon={RefreshCw}>
               <Tx k="common.actions.refresh" fallback="Refresh" />
             </Button>
           </div>
@@ -302,7 +302,7 @@ export default function Profiles() {
   const handleApplyProfile = async (profileId: string) => {
     setApplyingId(profileId);
     try {
-      const { profile, failures } = await applyProfile(profileId);
+      const { profile, failures, missing } = await applyProfile(profileId);
 
       // Update local crosshair store if profile has settings
       if (profile.crosshair) {
@@ -320,6 +320,10 @@ export default function Profiles() {
       // missing mods.
       if (failures.length > 0) {
         setError(t('profiles.errors.applyPartial', { count: failures.length }));
+      } else if (missing.length > 0) {
+        // Entries whose mod was deleted since the profile was saved are
+        // skipped, not fatal; say how many so the gap isn't silent.
+        setError(t('profiles.errors.applyMissing', { count: missing.length }));
       }
     } catch (err) {
       setError(String(err));
@@ -10,6 +10,7 @@ import {
   cleanupAddons,
   createDevDeadlockPath,
   fixGameinfo,
+  previewGameinfoFix,
   getGameinfoStatus,
   openGameFolder,
   validateDeadlockPath,
@@ -79,6 +80,8 @@ export default function Settings() {
   const [gameinfoCandidates, setGameinfoCandidates] = useState<string[]>([]);
   const [isFixingGameinfo, setIsFixingGameinfo] = useState(false);
   const [openGameinfoConfirm, setOpenGameinfoConfirm] = useState(false);
+  // Unified diff of the pending gameinfo.gi rewrite, shown for confirmation.
+  const [gameinfoFixDiff, setGameinfoFixDiff] = useState<string | null>(null);
   const [syncStatus, setSyncStatus] = useState<Record<string, { lastSync: number; count: number } | null> | null>(null);
   const [isSyncing, setIsSyncing] = useState(false);
   const [syncProgress, setSyncProgress] = useState<{ section: string; modsProcessed: number; totalMods: number } | null>(null);
@@ -393,7 +396,27 @@ export default function Settings() {
     }
   };
 
+  // Show what the fix would change before writing it, so a hand-edited
+  // gameinfo.gi isn't rewritten blind. No diff means nothing to change; a
+  // preview error falls through to fixGameinfo, which reports its own status.
+  const handlePreviewGameinfoFix = async () => {
+    setIsFixingGameinfo(true);
+    let diff = '';
+    try {
+      diff = await previewGameinfoFix();
+    } catch {
+      diff = '';
+    }
+    if (diff) {
+      setGameinfoFixDiff(diff);
+      setIsFixingGameinfo(false);
+      return;
+    }
+    await handleFixGameinfo();
+  };
+
   const handleFixGameinfo = async () => {
+    setGameinfoFixDiff(null);
     setIsFixingGameinfo(true);
     setGameinfoStatus(null);
     try {
@@ -770,7 +793,7 @@ export default function Settings() {
                     <Tx k="settings.gameinfo.openFile" fallback="Open gameinfo.gi" />
                   </Button>
                   <Button
-                    onClick={handleFixGameinfo}
+                    onClick={handlePreviewGameinfoFix}
                     disabled={isFixingGameinfo || !activeDeadlockPath}
                     isLoading={isFixingGameinfo}
                     variant={gameinfoConfigured ? 'secondary' : 'primary'}
@@ -1687,6 +1710,28 @@ export default function Settings() {
         variant="primary"
       />
 
+      <ConfirmModal
+        isOpen={gameinfoFixDiff !== null}
+        onCancel={() => setGameinfoFixDiff(null)}
+        onConfirm={handleFixGameinfo}
+        title={<Tx k="settings.gameinfo.previewTitle" fallback="Apply this gameinfo.gi change?" />}
+        message={
+          <div className="space-y-2">
+            <p>
+              <Tx
+                k="settings.gameinfo.previewMessage"
+                fallback="Grimoire will rewrite the SearchPaths block as shown: lines starting with - are removed, lines starting with + are added. The current file is backed up before every change."
+              />
+            </p>
+            <pre className="max-h-72 overflow-auto rounded-sm bg-black/40 p-2 font-mono text-xs text-text-primary whitespace-pre">
+              {gameinfoFixDiff}
+            </pre>
+          </div>
+        }
+        confirmLabel={<Tx k="settings.gameinfo.applyFix" fallback="Apply Fix" />}
+        variant="primary"
+      />
+
       <ConfirmModal
         isOpen={openGameinfoConfirm}
         onCancel={() => setOpenGameinfoConfirm(false)}
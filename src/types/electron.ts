@@ -33,9 +33,28 @@ import type {
     LockerClearScope,
     SoulImportStatus,
     AppearanceSurface,
+    ModPathMatch,
+    ShadowedMod,
+    SharedTextureReport,
+    ModOverrideStats,
+    MetadataRepair,
+    QuarantinedMod,
+    LibraryMod,
+    MissingDisabledModsReport,
+    ModFile,
+    ModListExportFormat,
+    VpkFolderImportResult,
+    LegacyPakRename,
+    PakCollision,
+    AddonLimitCheck,
+    VpkContents,
+    PakRenumber,
+    InstalledModUpdate,
+    ModBatchResult,
 } from './mod';
 import type {
     GameBananaModsResponse,
+    GameBananaNewModsResponse,
     GameBananaModDetails,
     GameBananaModFileList,
     GameBananaModUpdatesResponse,
@@ -45,6 +64,7 @@ import type {
     GameBananaCollectionItemsResponse,
     GameBananaCommentsResponse,
     GameBananaArtistLink,
+    GameBananaSubmitterProfile,
 } from './gamebanana';
 import type { HeroPortrait, CustomCardSlot, SoulModelInfo, HeroPoseInfo, HeroPoseSkinSource, HeroEffectInfo } from './portrait';
 import type {
@@ -66,6 +86,21 @@ export interface BrowseModsArgs {
     submitterId?: number;
 }
 
+/** A GameBanana image held in the local image cache. */
+export interface CachedImage {
+    /** On-disk path of the cached file. */
+    path: string;
+    /** `grimoire-img:` URL for an <img> src (allowed by the prod CSP). */
+    url: string;
+}
+
+/** Browse pages already fetched for one query, served on remount. */
+export interface LastBrowseResult {
+    /** Pages 1..n in order. */
+    pages: GameBananaModsResponse[];
+    fetchedAt: number;
+}
+
 export interface GetModDetailsArgs {
     modId: number;
     section?: string;
@@ -91,6 +126,46 @@ export interface DownloadModArgs {
     modName?: string;
     section?: string;
     categoryId?: number;
+    /** Download even when this GameBanana file is already installed. */
+    force?: boolean;
+    /**
+     * Install enabled at this pak number (1-99) instead of disabled. Fails if
+     * the slot is taken or the archive holds more than one VPK.
+     */
+    targetPriority?: number;
+}
+
+/** The installed mod a download-mod call short-circuited to. */
+export interface AlreadyInstalledMod {
+    id: string;
+    name: string;
+    fileName: string;
+}
+
+export interface DownloadModResult {
+    /** Set when the file was already installed and nothing was downloaded. */
+    alreadyInstalled?: AlreadyInstalledMod;
+}
+
+/** A GameBanana file's size, checked before a download starts. */
+export interface DownloadSizeInfo {
+    fileName: string;
+    /** Size in bytes as reported by GameBanana. */
+    fileSize: number;
+    /** Free bytes on the Deadlock drive; null when unknown. */
+    freeBytes: number | null;
+    /** Whether the drive has room for the archive plus its extracted VPKs;
+     *  null when free space couldn't be read. */
+    enoughSpace: boolean | null;
+}
+
+/** Upfront ETA for a download from the recent measured throughput. */
+export interface DownloadEstimate {
+    /** Average rate over recent downloads; null with no history yet. */
+    bytesPerSecond: number | null;
+    seconds: number | null;
+    /** How many recent downloads the average is based on. */
+    samples: number;
 }
 
 export interface GetCategoriesArgs {
@@ -101,6 +176,46 @@ export interface CleanupResult {
     removedArchives: number;
 }
 
+/** One enabled mod in a check-mods-effective report. */
+export interface ModEffectivenessEntry {
+    id: string;
+    name: string;
+    fileName: string;
+    /** Addon folder the VPK lives in ('addons', 'addons1', ...). */
+    folder: string;
+    /** Why the game won't load it; absent for effective mods. */
+    reason?: 'gameinfo-unconfigured' | 'folder-not-mounted' | 'unreadable';
+}
+
+/** Which enabled mods will actually load, given the current gameinfo.gi. */
+export interface ModEffectivenessReport {
+    gameinfo: GameinfoStatus;
+    effective: ModEffectivenessEntry[];
+    ineffective: ModEffectivenessEntry[];
+}
+
+/** gameinfo.gi status joined with the enabled mods it leaves unloaded, so a
+ *  patch-day reset reads as "these 12 mods aren't active". */
+export interface GameinfoCoverageReport {
+    gameinfo: GameinfoStatus;
+    enabledCount: number;
+    /** Enabled mods the game won't mount until gameinfo.gi is fixed
+     *  ('gameinfo-unconfigured' or 'folder-not-mounted'). */
+    uncovered: ModEffectivenessEntry[];
+}
+
+/** A VPK left behind by an install interrupted in an earlier session. */
+export interface IncompleteInstall {
+    path: string;
+    fileName: string;
+    metaKey: string;
+    size: number;
+    /** 'truncated' = smaller than a VPK header; 'bad-signature' = wrong magic. */
+    reason: 'truncated' | 'bad-signature';
+    /** ISO timestamp of when the interrupted write started. */
+    interruptedAt: string;
+}
+
 export interface GameinfoStatus {
     configured: boolean;
     message: string;
@@ -279,18 +394,78 @@ export interface DownloadProgressData {
     modId: number;
     fileId: number;
     downloaded: number;
+    /** Content-Length of the response; 0 when the server didn't send one. */
     total: number;
+    /** True when `total` is unknown, so a UI should show a spinner rather
+     *  than a percentage. */
+    indeterminate?: boolean;
 }
 
 export interface DownloadEventData {
     modId: number;
     fileId: number;
+    /** download-complete only: archive entries that weren't installed because
+     *  they aren't VPKs (configs, readmes). Absent when nothing was skipped. */
+    skippedFiles?: string[];
+    /** download-complete only: archive-relative paths of auxiliary files kept
+     *  per the extraExtractExtensions setting, saved under mod-extras/<modId>. */
+    extraFiles?: string[];
+    /** download-complete only: extracted VPKs that were empty or failed the
+     *  header check and were deleted, as "name (reason)". */
+    invalidVpks?: string[];
+    /** download-complete only: final (disabled-folder) names of the VPKs
+     *  that were installed. */
+    installedVpks?: string[];
+    /** download-complete only: VPKs from a multi-VPK archive the user chose
+     *  not to install in the picker. Absent when nothing was left out. */
+    discardedVpks?: string[];
+}
+
+/** A download stopped by cancel-download or cancel-active-download
+ *  (download-cancelled event). Its partial file is already removed. */
+export interface DownloadCancelledData {
+    modId: number;
+    fileId: number;
+}
+
+/** A downloaded VPK that was byte-identical to an installed mod and so was
+ *  not installed (download-duplicate event). */
+export interface DownloadDuplicateData {
+    modId: number;
+    fileId: number;
+    /** Name the skipped VPK would have been installed under. */
+    fileName: string;
+    existingModId: string;
+    existingModName: string;
+    existingFileName: string;
+}
+
+/** Install steps in order: fetch the file, check its size, unpack, move VPKs into place. */
+export type InstallPhase = 'download' | 'verify' | 'extract' | 'place';
+
+export interface InstallPhaseData {
+    modId: number;
+    fileId: number;
+    phase: InstallPhase;
+    /** Phase-specific context: the file being fetched, the archive being
+     *  unpacked, or the VPK names being placed. */
+    detail?: string;
 }
 
 export interface DownloadErrorData {
     modId: number;
     fileId: number;
-    errorCode: 'MISSING_7ZIP' | 'EXTRACTION_FAILED' | 'CANCELLED_BY_USER' | 'UNKNOWN';
+    /** Failure category, so the UI can tailor retry advice. */
+    errorCode:
+        | 'NETWORK'
+        | 'FILE_NOT_FOUND'
+        | 'CHECKSUM'
+        | 'DISK_ERROR'
+        | 'MISSING_7ZIP'
+        | 'EXTRACTION_FAILED'
+        | 'DISABLED_BY_AUTHOR'
+        | 'CANCELLED_BY_USER'
+        | 'UNKNOWN';
     message: string;
     helpUrl?: string;
 }
@@ -504,6 +679,15 @@ export interface ElectronAPI {
     createDevDeadlockPath: () => Promise<string>;
     getSettings: () => Promise<AppSettings>;
     setSettings: (settings: AppSettings) => Promise<void>;
+    patchSettings: (partial: Partial<AppSettings>) => Promise<AppSettings>;
+    setLoadOrderLocked: (locked: boolean) => Promise<boolean>;
+    setReadOnlyMode: (enabled: boolean) => Promise<boolean>;
+    setExtraExtractExtensions: (extensions: string[]) => Promise<string[]>;
+    exportConfigBundle: (destPath: string) => Promise<ConfigBundleManifest>;
+    inspectConfigBundle: (srcPath: string) => Promise<ConfigBundleManifest>;
+    importConfigBundle: (args: { srcPath: string; confirmOverwrite: boolean }) => Promise<ConfigBundleManifest>;
+    backupAddons: (destPath: string) => Promise<AddonsBackupManifest>;
+    restoreAddons: (args: { srcPath: string; confirmOverwrite: boolean }) => Promise<AddonsBackupManifest>;
 
     // Deadlock Mod Manager migration (adopt DMM's on-disk VPKs; no cloud)
     dmmMigrate: {
@@ -527,10 +711,30 @@ export interface ElectronAPI {
     getMods: () => Promise<Mod[]>;
     enableMod: (modId: string) => Promise<Mod>;
     disableMod: (modId: string) => Promise<Mod>;
+    toggleMod: (modId: string) => Promise<Mod>;
     deleteMod: (modId: string) => Promise<void>;
+    enableMods: (modIds: string[]) => Promise<ModBatchResult>;
+    disableMods: (modIds: string[]) => Promise<ModBatchResult>;
+    deleteMods: (modIds: string[]) => Promise<ModBatchResult>;
+    getModFiles: (modId: string) => Promise<ModFile[]>;
+    exportModList: (format: ModListExportFormat) => Promise<string>;
+    normalizeLegacyPakNames: () => Promise<LegacyPakRename[]>;
+    checkAddonLimits: () => Promise<AddonLimitCheck>;
+    listVpkContents: (modId: string) => Promise<VpkContents>;
+    findPakCollisions: () => Promise<PakCollision[]>;
+    fixPakCollisions: () => Promise<PakRenumber[]>;
+    quarantineMod: (modId: string, reason: string) => Promise<QuarantinedMod>;
+    listQuarantinedMods: () => Promise<QuarantinedMod[]>;
+    releaseQuarantinedMod: (fileName: string) => Promise<Mod>;
+    getMissingDisabledMods: () => Promise<MissingDisabledModsReport | null>;
+    dismissMissingDisabledMods: () => Promise<void>;
+    listLibraryMods: () => Promise<LibraryMod[]>;
+    importFromLibrary: (fileName: string) => Promise<Mod>;
+    moveModToLibrary: (modId: string) => Promise<LibraryMod>;
     revealModInFolder: (modId: string) => Promise<void>;
     detectUnknownModFilters: (modId: string, requestId?: string) => Promise<UnknownModFilterGuess>;
     detectUnknownModCacheBulk: (requests: Array<{ modId: string; requestId?: string }>) => Promise<UnknownModFilterGuess[]>;
+    matchUnknownMods: (requests: Array<{ modId: string; requestId?: string }>) => Promise<Record<string, UnknownModFilterGuess>>;
     cancelUnknownModDetection: (modId: string) => Promise<void>;
     onUnknownModDetectionProgress: (callback: (progress: UnknownModDetectionProgress) => void) => () => void;
     applyUnknownModMatch: (modId: string, args: ApplyUnknownModMatchArgs) => Promise<Mod>;
@@ -538,6 +742,8 @@ export interface ElectronAPI {
     associateUnknownMod: (modId: string, args: AssociateUnknownModArgs) => Promise<Mod>;
     listUnknownModFiles: (modId: string) => Promise<UnknownModFileList>;
     editLocalMod: (modId: string, args: EditLocalModArgs) => Promise<Mod>;
+    resetModMetadata: (modId: string) => Promise<Mod>;
+    repairMetadata: () => Promise<MetadataRepair[]>;
     setVariantLabel: (modId: string, label: string) => Promise<Mod>;
     setModLockerHero: (modId: string, heroName: string | null) => Promise<Mod>;
     getHeroPortraits: (heroName: string) => Promise<HeroPortrait[]>;
@@ -637,11 +843,19 @@ export interface ElectronAPI {
     clearLockerOverrides: (scope: LockerClearScope) => Promise<void>;
     setModGlobalType: (modId: string, globalType: GlobalModType | null) => Promise<Mod>;
     setModIgnoreUpdates: (modId: string, ignore: boolean) => Promise<Mod>;
+    setModsCategory: (modIds: string[], categoryId: number, categoryName: string) => Promise<Mod[]>;
+    addTagToMods: (modIds: string[], tag: string) => Promise<Mod[]>;
+    removeTagFromMods: (modIds: string[], tag: string) => Promise<Mod[]>;
     backfillGameBananaFileId: (
       modId: string,
       payload: { gameBananaFileId: number; fileDescription?: string; sourceFileName?: string }
     ) => Promise<Mod>;
     setModPriority: (modId: string, priority: number) => Promise<Mod>;
+    enableModAt: (modId: string, priority: number) => Promise<Mod>;
+    setModRelativePosition: (modId: string, fraction: number) => Promise<Mod>;
+    findFreeSlots: (min: number, max: number, count: number) => Promise<number[]>;
+    refreshModNames: () => Promise<import('./mod').ModNameResolution[]>;
+    refreshModMetadata: (modIds: string[]) => Promise<Mod[]>;
     reorderMods: (orderedIds: string[]) => Promise<Mod[]>;
     applyModToggleBatch: (
         enableIds: string[],
@@ -649,6 +863,7 @@ export interface ElectronAPI {
     ) => Promise<{ mods: Mod[]; failures: string[] }>;
     swapModPriority: (modIdA: string, modIdB: string) => Promise<Mod[]>;
     importCustomMod: (args: ImportCustomModArgs) => Promise<Mod[]>;
+    importVpkFolder: (folderPath: string, move?: boolean) => Promise<VpkFolderImportResult[]>;
     importSoulContainerGlb: (args: ImportSoulContainerGlbArgs) => Promise<Mod[]>;
     exportSoulContainerGlb: (
         args: ImportSoulContainerGlbArgs
@@ -747,22 +962,42 @@ export interface ElectronAPI {
 
     // GameBanana
     browseMods: (args: BrowseModsArgs) => Promise<GameBananaModsResponse>;
+    getLastBrowseResult: (args: Omit<BrowseModsArgs, 'page'>) => Promise<LastBrowseResult | null>;
+    clearBrowseCache: () => Promise<void>;
+    getFeaturedMods: (limit?: number) => Promise<GameBananaModsResponse>;
+    fetchNewModsSince: (args: { since?: number; section?: string }) => Promise<GameBananaNewModsResponse>;
     getModDetails: (args: GetModDetailsArgs) => Promise<GameBananaModDetails>;
     getModFileList: (args: GetModDetailsArgs) => Promise<GameBananaModFileList>;
     getModComments: (args: GetModCommentsArgs) => Promise<GameBananaCommentsResponse>;
     getModUpdates: (args: GetModUpdatesArgs) => Promise<GameBananaModUpdatesResponse>;
+    checkModUpdates: () => Promise<InstalledModUpdate[]>;
     getSubmitterLinks: (memberId: number) => Promise<GameBananaArtistLink[]>;
-    downloadMod: (args: DownloadModArgs) => Promise<void>;
+    fetchSubmitterProfile: (args: { submitterId: number; section?: string }) => Promise<GameBananaSubmitterProfile>;
+    downloadMod: (args: DownloadModArgs) => Promise<DownloadModResult>;
+    retryDownload: (args: { modId: number; fileId: number }) => Promise<DownloadModResult>;
+    getDownloadSize: (args: { modId: number; fileId: number; section?: string }) => Promise<DownloadSizeInfo>;
+    estimateDownloadTime: (fileSize: number) => Promise<DownloadEstimate>;
     getGameBananaSections: () => Promise<GameBananaSection[]>;
     getGameBananaCategories: (args: GetCategoriesArgs) => Promise<GameBananaCategoryNode[]>;
+    getTopCategories: (args: GetCategoriesArgs & { limit?: number }) => Promise<GameBananaCategoryNode[]>;
+    getCategoryIcon: (url: string) => Promise<CachedImage>;
     getCollection: (args: { collectionId: number }) => Promise<GameBananaCollection>;
     getCollectionItems: (args: { collectionId: number; page?: number }) => Promise<GameBananaCollectionItemsResponse>;
 
     // Maintenance
     copyImageToClipboard: (source: string) => Promise<void>;
     cleanupAddons: () => Promise<CleanupResult>;
+    findIncompleteInstalls: () => Promise<IncompleteInstall[]>;
+    checkModsEffective: () => Promise<ModEffectivenessReport>;
+    getGameinfoCoverage: () => Promise<GameinfoCoverageReport>;
+    fixGameinfoCoverage: () => Promise<GameinfoCoverageReport>;
+    cleanupIncompleteInstalls: (paths: string[]) => Promise<number>;
     getGameinfoStatus: () => Promise<GameinfoStatus>;
     fixGameinfo: () => Promise<GameinfoStatus>;
+    /** Unified diff of what fixGameinfo would write; empty when nothing changes. */
+    previewGameinfoFix: () => Promise<string>;
+    /** Copy the newest gameinfo.gi backup back over the live file. */
+    restoreGameinfo: () => Promise<GameinfoStatus>;
     getPerformanceConfigStatus: () => Promise<PerformanceConfigStatus>;
     applyPerformanceConfig: () => Promise<PerformanceConfigStatus>;
     removePerformanceConfig: () => Promise<PerformanceConfigStatus>;
@@ -791,13 +1026,17 @@ export interface ElectronAPI {
     onDownloadExtracting: (callback: (data: DownloadEventData) => void) => () => void;
     onDownloadComplete: (callback: (data: DownloadEventData) => void) => () => void;
     onDownloadError: (callback: (data: DownloadErrorData) => void) => () => void;
+    onInstallPhase: (callback: (data: InstallPhaseData) => void) => () => void;
     onModsAutoDisabled: (callback: (data: ModsAutoDisabledData) => void) => () => void;
+    onDownloadDuplicate: (callback: (data: DownloadDuplicateData) => void) => () => void;
+    onDownloadCancelled: (callback: (data: DownloadCancelledData) => void) => () => void;
 
     // Download Queue
     getDownloadQueue: () => Promise<DownloadQueueItem[]>;
     getCurrentDownload: () => Promise<DownloadQueueItem | null>;
     removeFromQueue: (modId: number) => Promise<boolean>;
     cancelActiveDownload: () => Promise<boolean>;
+    cancelDownload: (modId: number, fileId: number) => Promise<boolean>;
     onDownloadQueueUpdated: (callback: (data: DownloadQueueData) => void) => () => void;
 
     // GameBanana 1-Click protocol handler
@@ -816,7 +1055,11 @@ export interface ElectronAPI {
     ) => Promise<void>;
 
     // Conflicts
-    getConflicts: () => Promise<ModConflict[]>;
+    getConflicts: (forceRescan?: boolean) => Promise<ModConflict[]>;
+    findModsContainingPath: (query: string) => Promise<ModPathMatch[]>;
+    findShadowedMods: () => Promise<ShadowedMod[]>;
+    rankModsByOverrideWins: () => Promise<ModOverrideStats[]>;
+    reconcileSharedTextures: () => Promise<SharedTextureReport>;
     getIgnoredConflicts: () => Promise<string[]>;
     ignoreConflict: (modA: string, modB: string) => Promise<string[]>;
     unignoreConflict: (modA: string, modB: string) => Promise<string[]>;
@@ -826,6 +1069,8 @@ export interface ElectronAPI {
     getIgnoredConflictFilesGlobal: () => Promise<string[]>;
     ignoreConflictFileGlobal: (filePath: string) => Promise<string[]>;
     unignoreConflictFileGlobal: (filePath: string) => Promise<string[]>;
+    markKnownConflict: (modIdA: string, modIdB: string) => Promise<void>;
+    clearKnownConflict: (modIdA: string, modIdB: string) => Promise<void>;
     getIgnoredConflictMods: () => Promise<string[]>;
     ignoreConflictMod: (identity: string) => Promise<string[]>;
     unignoreConflictMod: (identity: string) => Promise<string[]>;
@@ -836,6 +1081,8 @@ export interface ElectronAPI {
     createProfileFromGameBananaIds: (args: { name: string; gameBananaIds: number[] }) => Promise<Profile>;
     updateProfile: (profileId: string, crosshairSettings?: ProfileCrosshairSettings) => Promise<Profile>;
     applyProfile: (profileId: string) => Promise<ApplyProfileResult>;
+    validateProfile: (profileId: string) => Promise<ProfileValidationResult>;
+    diffProfile: (profileId: string) => Promise<ProfileDiff>;
     deleteProfile: (profileId: string) => Promise<void>;
     renameProfile: (profileId: string, newName: string) => Promise<Profile>;
     exportPortableProfile: (profileId: string) => Promise<import('./portableProfile').PortableExportResult>;
@@ -905,6 +1152,8 @@ export interface ElectronAPI {
     // Diagnostics
     diagnostics: {
         buildReport: (description: string, options?: { includeFullLog?: boolean }) => Promise<string>;
+        getAppPaths: () => Promise<AppPaths>;
+        exportSupportBundle: (destPath: string, description: string) => Promise<string>;
     };
 
     // Grimoire Social
@@ -1089,6 +1338,43 @@ export interface ElectronAPI {
     onDeadworksDownloadProgress: (callback: (p: DeadworksConnectProgress) => void) => () => void;
 }
 
+/** manifest.json of a config bundle (settings, metadata, profiles and
+ *  snapshots backed up by export-config-bundle). */
+export interface ConfigBundleManifest {
+    format: 'grimoire-config-bundle';
+    version: number;
+    createdAt: string;
+    appVersion: string;
+    /** userData-relative paths of the files in the bundle. */
+    files: string[];
+}
+
+/** grimoire-backup.json of an addons backup (every addon folder plus mod
+ *  metadata, written by backup-addons). */
+export interface AddonsBackupManifest {
+    format: 'grimoire-addons-backup';
+    version: number;
+    createdAt: string;
+    appVersion: string;
+    /** Addon folder names in the backup: addons, then any overflow addonsN. */
+    folders: string[];
+    /** Number of mod metadata entries saved alongside the folders. */
+    metadataEntries: number;
+}
+
+/** Resolved on-disk locations of the app's data files, for bug reports. */
+export interface AppPaths {
+    /** userData root (honours GRIMOIRE_DATA_DIR and portable mode). */
+    dataDir: string;
+    settings: string;
+    metadata: string;
+    profiles: string;
+    /** SQLite cache backing the Browse tab. */
+    modCache: string;
+    /** Current rolling main-process log. */
+    logFile: string;
+}
+
 export interface ProfileMod {
     /** Filename when the profile was saved. NOT stable across reorders or
      *  collision-renames; use `gameBananaId` + `gameBananaFileId` +
@@ -1120,6 +1406,9 @@ export interface Profile {
     autoexecCommands?: string[];
     createdAt: string;
     updatedAt: string;
+    /** Set once migrateProfileIdentities has backfilled stable ids onto the
+     *  profile's fileName-only mods. Absent on profiles from older builds. */
+    identityVersion?: number;
 }
 
 /** Result of applying a profile. `failures` holds per-mod enable/disable ops
@@ -1129,6 +1418,74 @@ export interface Profile {
 export interface ApplyProfileResult {
     profile: Profile;
     failures: string[];
+    /** File names of the installed mods the profile matched and set up
+     *  (enabled or disabled as stored) without an error. */
+    applied: string[];
+    /** Enabled profile entries with no installed match (deleted since the
+     *  profile was saved), skipped by the apply. Same shape as
+     *  ProfileValidationResult.missing. */
+    missing: Array<ProfileMod & { refused: boolean }>;
+}
+
+/** An installed mod as seen by a profile validation. */
+export interface ProfileValidationMod {
+    modId: string;
+    fileName: string;
+    name: string;
+    /** Current load-order priority; null when the mod is currently disabled. */
+    currentPriority: number | null;
+}
+
+/** Dry-run of applyProfile against the current install. Uses the same
+ *  stable-id-first resolver as apply, so what is reported here is what an
+ *  apply would act on. Locker-managed VPKs are never listed. */
+export interface ProfileValidationResult {
+    profileId: string;
+    /** Enabled profile entries with no installed match. The stable ids (when
+     *  present) are enough to fetch the file from GameBanana. `refused` is set
+     *  when a fileName match existed but belonged to a different mod. */
+    missing: Array<ProfileMod & { refused: boolean }>;
+    /** Enabled mods the profile does not reference; apply would disable them. */
+    notInProfile: ProfileValidationMod[];
+    /** Matched mods whose priority in the profile differs from the current
+     *  one, including disabled mods that apply would enable. */
+    priorityChanges: Array<ProfileValidationMod & { profilePriority: number }>;
+}
+
+/** What applyProfile would do to one mod or profile entry:
+ *  - `enable` / `disable`: an installed mod changes state.
+ *  - `priority`: a mod that stays enabled moves in the load order.
+ *  - `download`: an enabled entry isn't installed but has GameBanana ids.
+ *  - `unavailable`: an enabled entry isn't installed and can't be fetched.
+ *  Apply never deletes files, so there is no remove action. */
+export type ProfileDiffActionKind = 'enable' | 'disable' | 'priority' | 'download' | 'unavailable';
+
+export interface ProfileDiffAction {
+    kind: ProfileDiffActionKind;
+    /** Installed mod acted on; absent for download/unavailable entries. */
+    modId?: string;
+    fileName: string;
+    name: string;
+    /** Current load-order priority; null when disabled or not installed. */
+    currentPriority: number | null;
+    /** Priority recorded in the profile; null for mods it doesn't reference.
+     *  Apply lays the profile order out densely, so the final pakNN can differ
+     *  while the relative order matches. */
+    profilePriority: number | null;
+    gameBananaId?: number;
+    gameBananaFileId?: number;
+    /** Set on a disable when the profile doesn't reference the mod at all. */
+    notInProfile?: boolean;
+    /** Set on unavailable when a fileName match existed but belonged to a
+     *  different mod (see ProfileValidationResult.missing). */
+    refused?: boolean;
+}
+
+/** Per-mod action plan for applying a profile, for a confirm-before-apply
+ *  screen. Same resolver as applyProfile; Locker-managed VPKs never appear. */
+export interface ProfileDiff {
+    profileId: string;
+    actions: ProfileDiffAction[];
 }
 
 declare global {
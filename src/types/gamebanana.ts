@@ -52,6 +52,23 @@ export interface GameBananaArtistLink {
   url: string;
 }
 
+/** An author's member profile plus their submissions in one section, for the
+ *  author page (fetch-submitter-profile). */
+export interface GameBananaSubmitterProfile {
+  submitter: GameBananaSubmitter;
+  /** Member title shown under the name on GameBanana, when set. */
+  title?: string;
+  /** Unix seconds. */
+  joinedAt?: number;
+  /** Private profiles hide contact links; the mod list still loads. */
+  isPrivate: boolean;
+  links: GameBananaArtistLink[];
+  /** Their total submissions in the requested section. */
+  modCount: number;
+  /** First page of those submissions, newest first. */
+  mods: GameBananaMod[];
+}
+
 export interface GameBananaPreviewMedia {
   images?: GameBananaImage[];
   metadata?: GameBananaPreviewMetadata;
@@ -82,6 +99,18 @@ export interface GameBananaModsResponse {
   perPage: number;
 }
 
+/** Submissions added after a cutoff, newest first (fetch-new-mods-since). */
+export interface GameBananaNewModsResponse {
+  records: GameBananaMod[];
+  /** The cutoff used, unix seconds. */
+  since: number;
+  /** When this check ran, unix seconds; the next default cutoff. */
+  checkedAt: number;
+  /** The page cap was hit before reaching the cutoff, so older new mods
+   *  were not fetched. */
+  truncated: boolean;
+}
+
 export interface GameBananaFile {
   id: number;
   fileName: string;
@@ -92,6 +121,8 @@ export interface GameBananaFile {
   isArchived: boolean;
   /** Unix timestamp (seconds) of when this file was uploaded to GameBanana. */
   dateAdded?: number;
+  /** Hex MD5 of the file as GameBanana reports it; checked after download. */
+  md5?: string;
 }
 
 export interface GameBananaModDetails {
@@ -103,6 +134,8 @@ export interface GameBananaModDetails {
   files?: GameBananaFile[];
   previewMedia?: GameBananaPreviewMedia;
   submitter?: GameBananaSubmitter;
+  /** Unix timestamp (seconds) of the submission's last edit. */
+  dateModified?: number;
 }
 
 export interface GameBananaModFileListEntry {
@@ -213,13 +246,28 @@ export function parseCollectionId(input: string): number | null {
   }
 }
 
-export function getModThumbnail(mod: GameBananaMod): string | undefined {
-  const images = mod.previewMedia?.images;
-  if (!images || images.length === 0) return undefined;
+/** The subset of a list or detail record that thumbnail resolution reads. */
+export interface GameBananaThumbnailSource {
+  previewMedia?: GameBananaPreviewMedia;
+  rootCategory?: GameBananaCategory;
+  category?: GameBananaCategory;
+  submitter?: GameBananaSubmitter;
+}
+
+/**
+ * Best image for a mod card. Shared by Browse and the main-process installer so
+ * both pick the same one. Falls back from the first preview image to the
+ * category icon, then the submitter's avatar, so a mod uploaded without
+ * screenshots still gets some image.
+ */
+export function getModThumbnail(mod: GameBananaThumbnailSource): string | undefined {
+  const image = mod.previewMedia?.images?.find((img) => img.baseUrl && (img.file530 || img.file || img.file220));
+  if (image) {
+    const file = image.file530 || image.file || image.file220;
+    return `${image.baseUrl}/${file}`;
+  }
 
-  const image = images[0];
-  const file = image.file530 || image.file || image.file220;
-  return `${image.baseUrl}/${file}`;
+  return mod.rootCategory?.iconUrl || mod.category?.iconUrl || mod.submitter?.avatarUrl || undefined;
 }
 
 export function getSoundPreviewUrl(mod: GameBananaMod): string | undefined {
@@ -656,6 +656,17 @@ export interface Mod {
   /** User opted out of the "update available" flag for this mod. Persisted
    *  in metadata; toggled from the mod details modal. */
   ignoreUpdates?: boolean;
+  /** Conflict identities of mods the user marked as clashing with this one. */
+  knownConflicts?: string[];
+  /** User-defined library tags, from metadata. */
+  tags?: string[];
+  /** Heroes this mod's files change, by display name ("Abrams", "Haze"),
+   *  from the VPK tree. Empty for mods that touch no hero; missing when the
+   *  VPK couldn't be read. */
+  affectedHeroes?: string[];
+  /** Only on the result of enable-mod: names of currently enabled mods marked
+   *  as conflicting with this one. The enable still happened; show a warning. */
+  enabledKnownConflicts?: string[];
 }
 
 export interface MergeModsArgs {
@@ -786,6 +797,17 @@ export interface UnknownModFileList {
   fileCount: number;
 }
 
+/** Which rule produced a mod's display name (see resolveModDisplayName). */
+export type ModNameSource = 'metadata' | 'vpk' | 'filename';
+
+/** One row of refresh-mod-names: the name a mod resolves to right now. */
+export interface ModNameResolution {
+  modId: string;
+  fileName: string;
+  name: string;
+  source: ModNameSource;
+}
+
 export interface EditLocalModArgs {
   name: string;
   thumbnailDataUrl?: string;
@@ -806,6 +828,261 @@ export interface ModConflict {
    *  (after subtracting any individually ignored files). Drives the per-file
    *  ignore UI. Undefined for `priority` conflicts. */
   files?: string[];
+  /** pakNN slot and addon folder ('addons', 'addons1', ...) of each side. */
+  modAPriority: number;
+  modAFolder: string;
+  modBPriority: number;
+  modBFolder: string;
+  /** For `file` conflicts: id of the mod whose copy the game loads (earlier
+   *  folder, then lower pakNN). Undefined for `priority` conflicts, where the
+   *  two share a slot and the order between them is undefined. */
+  winner?: string;
+}
+
+/** An installed mod whose VPK contains files matching a content-path search. */
+export interface ModPathMatch {
+  modId: string;
+  modName: string;
+  fileName: string;
+  enabled: boolean;
+  priority: number;
+  /** Matching in-VPK paths, capped; see totalMatches for the full count. */
+  paths: string[];
+  totalMatches: number;
+}
+
+/** An enabled mod every one of whose files is overridden by mods that load
+ *  before it, so it has no visible effect in game. */
+export interface ShadowedMod {
+  modId: string;
+  modName: string;
+  fileName: string;
+  priority: number;
+  /** Game files the mod ships (metadata files excluded). */
+  fileCount: number;
+  /** The earlier-loading mods that win its files, in load order. */
+  shadowedBy: Array<{ modId: string; modName: string }>;
+}
+
+/** One on-disk file belonging to a mod: its directory VPK or a numbered
+ *  data chunk (`pak12_000.vpk`) that shares its name stem. */
+export interface ModFile {
+  fileName: string;
+  path: string;
+  size: number;
+  kind: 'dir' | 'chunk';
+  /** The chunk's NNN index; absent for the directory VPK. */
+  chunkIndex?: number;
+}
+
+/** One file inside a VPK, from its directory tree. */
+export interface VpkEntry {
+  /** Game path, e.g. `sounds/abilities/haze/a1_dagger.vsnd_c`. */
+  path: string;
+  /** Preload bytes plus archive data length. */
+  size: number;
+  /** Data chunk (`_NNN.vpk`) holding the bytes; 0x7fff = the dir VPK itself. */
+  archiveIndex: number;
+}
+
+/** A folder or file in the list-vpk-contents tree. Folders carry the summed
+ *  size and file count of everything under them. */
+export interface VpkTreeNode {
+  name: string;
+  path: string;
+  size: number;
+  fileCount: number;
+  /** Present on folders only, folders first then files, each by name. */
+  children?: VpkTreeNode[];
+}
+
+/** What a mod's VPK contains, as returned by list-vpk-contents. */
+export interface VpkContents {
+  version: number;
+  fileCount: number;
+  totalSize: number;
+  tree: VpkTreeNode[];
+}
+
+/** Why and when a mod was quarantined (stored in its metadata). */
+export interface QuarantineInfo {
+  reason: string;
+  quarantinedAt: string;
+  /** Whether it was enabled when quarantined. Release always lands it in the
+   *  disabled list regardless, so a crashing mod is never re-enabled by accident. */
+  wasEnabled: boolean;
+}
+
+/** A VPK in the quarantine folder, as listed by list-quarantined-mods. */
+export interface QuarantinedMod {
+  /** File name inside the quarantine folder; the id used to release it. */
+  fileName: string;
+  name: string;
+  size: number;
+  gameBananaId?: number;
+  thumbnailUrl?: string;
+  reason: string;
+  quarantinedAt: string;
+  wasEnabled: boolean;
+}
+
+/** Disabled mods that metadata still knew about but were gone from disk at
+ *  scan time, kept until the user dismisses the warning. */
+export interface MissingDisabledModsReport {
+  /** .disabled itself had to be recreated, the signature of a Steam
+   *  "verify integrity" run rather than a file deleted by hand. */
+  folderMissing: boolean;
+  detectedAt: string;
+  mods: Array<{
+    fileName: string;
+    name?: string;
+    gameBananaId?: number;
+    gameBananaFileId?: number;
+    sourceSection?: string;
+  }>;
+}
+
+/** A VPK in the external mod library, as listed by list-library-mods. */
+export interface LibraryMod {
+  /** File name inside the library folder; the id used to import it. */
+  fileName: string;
+  name: string;
+  /** Directory VPK plus its numbered chunks. */
+  size: number;
+  chunkCount: number;
+  gameBananaId?: number;
+  thumbnailUrl?: string;
+}
+
+/** One legacy `textures-pak21_dir.vpk`-style file handled by
+ *  normalize-legacy-pak-names. */
+export interface LegacyPakRename {
+  from: string;
+  /** The `pakNN_<label>` name it was (or would have been) renamed to. */
+  to: string;
+  folder: string;
+  renamed: boolean;
+  error?: string;
+}
+
+/** Two or more enabled mods sharing one pakNN slot in the same addon folder,
+ *  as found by find-pak-collisions. The game's load order between them is
+ *  undefined. */
+export interface PakCollision {
+  /** The addon folder (absolute path) the slot belongs to. */
+  folder: string;
+  priority: number;
+  mods: Array<{ id: string; name: string; fileName: string }>;
+}
+
+/** Result of check-addon-limits: how many paks the game will load against
+ *  the configured warning threshold. */
+export interface AddonLimitCheck {
+  enabledCount: number;
+  threshold: number;
+  exceeded: boolean;
+}
+
+/** One mod moved off a shared slot by fix-pak-collisions. */
+export interface PakRenumber {
+  from: string;
+  to: string;
+  folder: string;
+  renamed: boolean;
+  error?: string;
+}
+
+/** Per-file outcome of import-vpk-folder. */
+export interface VpkFolderImportResult {
+  /** The `_dir.vpk` name in the source folder. */
+  sourceFileName: string;
+  status: 'imported' | 'failed';
+  /** Where it landed (`pak07_dir.vpk`, or `addons2/pak01_dir.vpk` for overflow). */
+  installedAs?: string;
+  /** Numbered chunks carried along with the directory VPK. */
+  chunkCount?: number;
+  error?: string;
+}
+
+/** Output format of export-mod-list. */
+export type ModListExportFormat = 'csv' | 'markdown';
+
+/** What enable-mods / disable-mods / delete-mods do to each id. */
+export type ModBatchAction = 'enable' | 'disable' | 'delete';
+
+/** A mod a batch call could not process; the rest of the batch still ran. */
+export interface ModBatchFailure {
+  modId: string;
+  error: string;
+}
+
+/** Result of enable-mods / disable-mods / delete-mods. */
+export interface ModBatchResult {
+  /** The full mod list after the batch. */
+  mods: Mod[];
+  failures: ModBatchFailure[];
+}
+
+/** One metadata entry moved or dropped by repair-metadata. */
+export interface MetadataRepair {
+  /** The orphaned key (no VPK on disk under that name). */
+  fromKey: string;
+  /** The installed VPK's key the entry was moved onto; absent when dropped. */
+  toKey?: string;
+  /** reassociated: the VPK had no entry; merged: its entry was filled in from
+   *  the orphan; dropped-duplicate: another VPK already holds the same
+   *  GameBanana file, so the orphan was a stale copy. */
+  action: 'reassociated' | 'merged' | 'dropped-duplicate';
+  matchedBy: 'sha256' | 'gameBananaId';
+}
+
+/** An installed GameBanana mod with a newer version upstream (check-mod-updates). */
+export interface InstalledModUpdate {
+  modId: string;
+  modName: string;
+  fileName: string;
+  gameBananaId: number;
+  /** The submission's _tsDateModified when the installed file was downloaded. */
+  installedDateModified: number;
+  /** The submission's _tsDateModified now. */
+  remoteDateModified: number;
+  /** The installed file is gone from the live (non-archived) file list. */
+  fileRemoved: boolean;
+}
+
+/** How much of an enabled mod's content actually reaches the game. */
+export interface ModOverrideStats {
+  modId: string;
+  modName: string;
+  fileName: string;
+  priority: number;
+  /** Game files the mod ships (metadata files excluded). */
+  fileCount: number;
+  /** Paths where this mod's copy is the one the game loads. */
+  wins: number;
+  /** Paths an earlier-loading mod overrides. */
+  losses: number;
+}
+
+/** Two enabled mods that ship some of the same texture files. */
+export interface SharedTexturePair {
+  /** The mod that loads first, whose copy of each texture the game uses. */
+  winnerId: string;
+  winnerName: string;
+  loserId: string;
+  loserName: string;
+  /** The shared texture paths (materials/...vtex_c). */
+  files: string[];
+}
+
+export interface SharedTextureReport {
+  /** Pairs whose shared textures are byte-identical. Only one copy is ever
+   *  loaded either way, so their overlap was added to the pair's per-file
+   *  conflict ignores. */
+  reconciled: SharedTexturePair[];
+  /** Pairs where at least one shared texture differs; `files` lists only the
+   *  differing paths. The loser's version of those textures never shows. */
+  conflicting: SharedTexturePair[];
 }
 
 /** The customizable launcher/sidebar art surfaces (issue: unify launcher
@@ -828,7 +1105,14 @@ export interface AppearanceBg {
   hero?: string | null;
 }
 
+/** See AppSettings.installPriorityPolicy. */
+export type InstallPriorityPolicy = 'next-free' | 'highest' | 'lowest';
+
 export interface AppSettings {
+  /** Schema version of settings.json (SETTINGS_VERSION in settings.ts).
+   *  Missing on files written before versioning, which load as version 0 and
+   *  are migrated forward on first read. Stamped by the main process on save. */
+  settingsVersion?: number;
   deadlockPath: string | null;
   devMode: boolean;
   devDeadlockPath: string | null;
@@ -838,6 +1122,34 @@ export interface AppSettings {
    *  versa) can be traced in the diagnostic report. Off by default; meant to
    *  be flipped on temporarily to capture a repro. */
   verboseModTrace?: boolean;
+  /** Freeze the load order: set/swap/reorder priority operations refuse to run
+   *  until unlocked. Enable/disable and installs still work and never renumber
+   *  other mods. Guards a hand-tuned order against stray drags. */
+  loadOrderLocked?: boolean;
+  /** Observe-only mode: every operation that writes to the game folder
+   *  (enable/disable/delete/priority, installs, cleanup, gameinfo repair,
+   *  autoexec) refuses with READ_ONLY_MODE_MESSAGE. Scans, conflict checks and
+   *  Browse keep working. For inspecting an install that must not change. */
+  readOnlyMode?: boolean;
+  /** Refuse every mod change while Deadlock is running, instead of only
+   *  blocking moves of the mods the running game has loaded. */
+  blockModChangesWhileGameRunning?: boolean;
+  /** Local-only mode: every GameBanana request (browse, details, downloads,
+   *  sections, categories, update checks, cache sync) refuses with
+   *  OFFLINE_MODE_MESSAGE instead of running into a timeout. Installed-mod
+   *  management is unaffected. */
+  offlineMode?: boolean;
+  /** Folder outside the game holding mods that aren't installed right now.
+   *  import-from-library copies one into the game; move-mod-to-library sends
+   *  an installed mod back. null = no library configured. */
+  modLibraryPath?: string | null;
+  /** check-addon-limits warns once more enabled paks than this are loaded.
+   *  Not an engine constant; see DEFAULT_ADDON_LIMIT in settings.ts. */
+  addonLimit?: number;
+  /** Non-VPK file extensions (lowercase, no dot, e.g. "cfg") to keep when
+   *  extracting a downloaded archive. Kept files land in the app's
+   *  mod-extras folder under the mod's GameBanana id. Empty = VPKs only. */
+  extraExtractExtensions?: string[];
   /** Shared/legacy NSFW thumbnail blur preference for non-Installed surfaces. */
   hideNsfwPreviews: boolean;
   /** Browser-specific handling for GameBanana mods marked as NSFW. */
@@ -854,6 +1166,14 @@ export interface AppSettings {
   /** After a successful GameBanana download, immediately enable the installed
    *  VPKs instead of leaving them in the disabled library. Off by default. */
   autoEnableDownloads: boolean;
+  /** Where a download that gets enabled (auto-enable or a sibling-variant
+   *  swap) enters the load order. 'next-free' keeps the lowest free pak slot;
+   *  'highest' moves it to the front so it wins every conflict, 'lowest' to
+   *  the back. Ignored while loadOrderLocked is on. Default 'next-free'. */
+  installPriorityPolicy?: InstallPriorityPolicy;
+  /** Unix seconds of the last "new since last visit" check; the default
+   *  cutoff for the next fetch-new-mods-since. */
+  newModsLastCheckedAt?: number;
   /** Args written to Steam's localconfig.vdf for Deadlock just before launch. */
   steamLaunchOptions: string;
   /** Currently active profile. */